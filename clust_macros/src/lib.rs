@@ -1,6 +1,6 @@
 //! Provides procedural macros for the [clust](https://github.com/mochi-neko/clust).
 
-use crate::tool::impl_tool;
+use crate::tool::{impl_tool, ToolAttributes};
 use proc_macro::TokenStream;
 
 mod parameter_type;
@@ -69,13 +69,18 @@ mod tool;
 /// fn function(arg1: i32) -> i32 { arg1 }
 /// ```
 ///
+/// ## Warnings for missing descriptions
+/// A compile-time warning is emitted for each argument without description
+/// because the description helps Claude to use the tool correctly.
+/// Use `#[clust_tool(allow_missing_descriptions)]` to suppress the warnings.
+///
 /// ## Examples
 ///
 /// Implement a tool by `clust_tool` for a function with documentation:
 ///
 /// ```rust
 /// use clust_macros::clust_tool;
-/// use clust::messages::{ToolUse, Tool};
+/// use clust::messages::{TextContentBlock, Tool, ToolResultContent, ToolUse};
 ///
 /// /// Increments the argument by 1.
 /// ///
@@ -99,13 +104,17 @@ mod tool;
 /// let result = tool.call(tool_use).unwrap();
 /// assert_eq!(result.tool_use_id, "toolu_XXXX");
 /// assert_eq!(result.is_error, None);
-/// assert_eq!(result.content.unwrap().text, "43");
+/// assert_eq!(
+///     result.content,
+///     Some(ToolResultContent::from(TextContentBlock::new("43")))
+/// );
 /// ```
 #[proc_macro_attribute]
 pub fn clust_tool(
-    _attr: TokenStream,
+    attr: TokenStream,
     item: TokenStream,
 ) -> TokenStream {
+    let attributes = ToolAttributes::parse(attr.into());
     let item_func = syn::parse::<syn::ItemFn>(item).unwrap();
-    impl_tool(&item_func)
+    impl_tool(&item_func, attributes)
 }
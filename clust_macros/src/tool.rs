@@ -2,7 +2,9 @@ extern crate proc_macro;
 
 use proc_macro2::{Ident, Span};
 use quote::{quote, ToTokens};
-use syn::{AttrStyle, Expr, ItemFn, Meta};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{AttrStyle, Expr, ItemFn, Meta, Token};
 use valico::json_schema::PrimitiveType;
 
 use proc_macro::TokenStream;
@@ -11,6 +13,32 @@ use std::collections::BTreeMap;
 use crate::parameter_type::ParameterType;
 use crate::return_type::ReturnType;
 
+/// The options of the `clust_tool` attribute.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ToolAttributes {
+    /// Whether to suppress the warnings for parameters without descriptions.
+    allow_missing_descriptions: bool,
+}
+
+impl ToolAttributes {
+    pub(crate) fn parse(attr: proc_macro2::TokenStream) -> Self {
+        let options = Punctuated::<Ident, Token![,]>::parse_terminated
+            .parse2(attr)
+            .expect("Attributes of clust_tool must be comma-separated identifiers");
+
+        let mut attributes = Self::default();
+        for option in options {
+            if option == "allow_missing_descriptions" {
+                attributes.allow_missing_descriptions = true;
+            } else {
+                panic!("Unknown attribute of clust_tool: {}", option);
+            }
+        }
+
+        attributes
+    }
+}
+
 #[derive(Debug, Clone)]
 struct DocComments {
     description: Option<String>,
@@ -261,6 +289,68 @@ fn get_tool_information(func: &ItemFn) -> ToolInformation {
     }
 }
 
+fn get_parameter_span(
+    func: &ItemFn,
+    name: &str,
+) -> Span {
+    func.sig
+        .inputs
+        .iter()
+        .find_map(|input| match input {
+            | syn::FnArg::Typed(pat) => match pat.pat.as_ref() {
+                | syn::Pat::Ident(ident) if ident.ident == name => {
+                    Some(ident.ident.span())
+                },
+                | _ => None,
+            },
+            | _ => None,
+        })
+        .unwrap_or_else(Span::call_site)
+}
+
+// Emits a warning by using a deprecated constant because stable proc macros cannot emit warnings directly.
+fn quote_missing_description_warnings(
+    func: &ItemFn,
+    info: &ToolInformation,
+) -> proc_macro2::TokenStream {
+    let warnings = info
+        .parameters
+        .iter()
+        .filter(|parameter| parameter.description.is_none())
+        .map(|parameter| {
+            let function = Ident::new(
+                &format!(
+                    "_clust_tool_missing_description_{}_{}",
+                    info.name.trim_start_matches("r#"),
+                    parameter.name.trim_start_matches("r#"),
+                ),
+                Span::call_site(),
+            );
+            let constant = Ident::new(
+                "missing_description",
+                get_parameter_span(func, &parameter.name),
+            );
+            let note = format!(
+                "The parameter `{}` of the tool `{}` has no description in the `## Arguments` documentation. Add the description or use `#[clust_tool(allow_missing_descriptions)]` to suppress this warning.",
+                parameter.name, info.name,
+            );
+
+            quote! {
+                #[allow(dead_code, non_snake_case)]
+                fn #function() {
+                    #[deprecated(note = #note)]
+                    #[allow(non_upper_case_globals)]
+                    const #constant: () = ();
+                    let _ = #constant;
+                }
+            }
+        });
+
+    quote! {
+        #(#warnings)*
+    }
+}
+
 fn quote_definition(info: &ToolInformation) -> proc_macro2::TokenStream {
     let name = info.name.clone();
     let description = info.description.clone();
@@ -514,6 +604,7 @@ fn impl_tool_for_function(
     info: ToolInformation,
     return_type: ReturnType,
     is_async: bool,
+    attributes: ToolAttributes,
 ) -> proc_macro2::TokenStream {
     let struct_name = Ident::new(
         &format!("ClustTool_{}", info.name),
@@ -527,11 +618,19 @@ fn impl_tool_for_function(
     };
     let impl_definition = quote_definition(&info);
     let impl_call = quote_call(func, &info, return_type, is_async);
+    let impl_warnings = if !attributes.allow_missing_descriptions {
+        quote_missing_description_warnings(func, &info)
+    } else {
+        quote! {}
+    };
 
     quote! {
         // Original function
         #func
 
+        // Warnings for parameters without descriptions
+        #impl_warnings
+
         // Generated tool struct
         pub struct #struct_name;
 
@@ -543,7 +642,10 @@ fn impl_tool_for_function(
     }
 }
 
-pub(crate) fn impl_tool(func: &ItemFn) -> TokenStream {
+pub(crate) fn impl_tool(
+    func: &ItemFn,
+    attributes: ToolAttributes,
+) -> TokenStream {
     let tool_information = get_tool_information(func);
     let is_async = func.sig.asyncness.is_some();
     let return_type = ReturnType::from_syn(&func.sig.output);
@@ -553,6 +655,7 @@ pub(crate) fn impl_tool(func: &ItemFn) -> TokenStream {
         tool_information,
         return_type,
         is_async,
+        attributes,
     )
     .into()
 }
@@ -904,4 +1007,65 @@ mod test {
 }"#
         );
     }
+
+    #[test]
+    fn test_parse_tool_attributes() {
+        assert_eq!(
+            ToolAttributes::parse(quote! {}),
+            ToolAttributes::default()
+        );
+        assert_eq!(
+            ToolAttributes::parse(quote! { allow_missing_descriptions }),
+            ToolAttributes {
+                allow_missing_descriptions: true,
+            }
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_unknown_tool_attributes() {
+        ToolAttributes::parse(quote! { unknown });
+    }
+
+    #[test]
+    fn test_missing_description_warnings() {
+        let input = quote! {
+            /// A function for testing.
+            ///
+            /// ## Arguments
+            /// - `arg1` - First argument.
+            fn test_function(arg1: i32, arg2: u32) -> i32 {
+                arg1
+            }
+        };
+
+        let item_func = syn::parse_str::<ItemFn>(&input.to_string()).unwrap();
+        let tool_information = get_tool_information(&item_func);
+        let warnings =
+            quote_missing_description_warnings(&item_func, &tool_information)
+                .to_string();
+
+        assert!(warnings.contains("deprecated"));
+        assert!(warnings
+            .contains("_clust_tool_missing_description_test_function_arg2"));
+        assert!(!warnings.contains("arg1"));
+
+        let input = quote! {
+            /// A function for testing.
+            ///
+            /// ## Arguments
+            /// - `arg1` - First argument.
+            fn test_function(arg1: i32) -> i32 {
+                arg1
+            }
+        };
+
+        let item_func = syn::parse_str::<ItemFn>(&input.to_string()).unwrap();
+        let tool_information = get_tool_information(&item_func);
+        assert!(
+            quote_missing_description_warnings(&item_func, &tool_information)
+                .is_empty()
+        );
+    }
 }
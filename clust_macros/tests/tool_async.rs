@@ -1,4 +1,4 @@
-use clust::messages::{AsyncTool, ToolUse};
+use clust::messages::{AsyncTool, TextContentBlock, ToolResultContent, ToolUse};
 
 use clust_macros::clust_tool;
 
@@ -51,5 +51,8 @@ async fn test_call() {
 
     assert_eq!(result.tool_use_id, "toolu_XXXX");
     assert_eq!(result.is_error, None);
-    assert_eq!(result.content.unwrap().text, "43");
+    assert_eq!(
+        result.content,
+        Some(ToolResultContent::from(TextContentBlock::new("43")))
+    );
 }
@@ -0,0 +1,52 @@
+use clust::messages::{Tool, ToolResult, ToolUse};
+
+use clust_macros::clust_tool;
+
+/// A function for testing.
+#[clust_tool(allow_missing_descriptions)]
+fn test_function(arg1: i32) -> i32 {
+    arg1 + 1
+}
+
+#[test]
+fn test_description() {
+    let tool = ClustTool_test_function {};
+
+    assert_eq!(
+        tool.definition().to_string(),
+        r#"{
+  "name": "test_function",
+  "description": "A function for testing.",
+  "input_schema": {
+    "description": "A function for testing.",
+    "properties": {
+      "arg1": {
+        "type": "integer"
+      }
+    },
+    "required": [
+      "arg1"
+    ],
+    "type": "object"
+  }
+}"#
+    );
+}
+
+#[test]
+fn test_call() {
+    let tool = ClustTool_test_function {};
+
+    let tool_use = ToolUse::new(
+        "toolu_XXXX",
+        "test_function",
+        serde_json::json!({"arg1": 42}),
+    );
+
+    let result = tool.call(tool_use).unwrap();
+
+    assert_eq!(
+        result,
+        ToolResult::success("toolu_XXXX", Some("43"))
+    );
+}
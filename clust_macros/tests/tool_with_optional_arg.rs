@@ -1,4 +1,4 @@
-use clust::messages::{Tool, ToolUse};
+use clust::messages::{TextContentBlock, Tool, ToolResultContent, ToolUse};
 
 use clust_macros::clust_tool;
 
@@ -53,7 +53,10 @@ fn test_call() {
 
     assert_eq!(result.tool_use_id, "toolu_XXXX");
     assert_eq!(result.is_error, None);
-    assert_eq!(result.content.unwrap().text, "0");
+    assert_eq!(
+        result.content,
+        Some(ToolResultContent::from(TextContentBlock::new("0")))
+    );
 
     let tool_use = ToolUse::new(
         "toolu_XXXX",
@@ -67,5 +70,8 @@ fn test_call() {
 
     assert_eq!(result.tool_use_id, "toolu_XXXX");
     assert_eq!(result.is_error, None);
-    assert_eq!(result.content.unwrap().text, "42");
+    assert_eq!(
+        result.content,
+        Some(ToolResultContent::from(TextContentBlock::new("42")))
+    );
 }
@@ -1,4 +1,4 @@
-use clust::messages::{Tool, ToolUse};
+use clust::messages::{TextContentBlock, Tool, ToolResultContent, ToolUse};
 
 use clust_macros::clust_tool;
 
@@ -73,7 +73,10 @@ fn test_call() {
 
     assert_eq!(result.tool_use_id, "toolu_XXXX");
     assert_eq!(result.is_error, None);
-    assert_eq!(result.content.unwrap().text, "43");
+    assert_eq!(
+        result.content,
+        Some(ToolResultContent::from(TextContentBlock::new("43")))
+    );
 
     let tool_use = ToolUse::new(
         "toolu_XXXX",
@@ -85,5 +88,8 @@ fn test_call() {
 
     assert_eq!(result.tool_use_id, "toolu_XXXX");
     assert_eq!(result.is_error, Some(true));
-    assert_eq!(result.content.unwrap().text, "arg1 is negative");
+    assert_eq!(
+        result.content,
+        Some(ToolResultContent::from(TextContentBlock::new("arg1 is negative")))
+    );
 }
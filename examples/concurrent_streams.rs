@@ -0,0 +1,100 @@
+//! This example demonstrates how to send multiple streaming requests concurrently with the same client.
+//!
+//! ```shell
+//! $ cargo run --example concurrent_streams -- -m <message> -n <number>
+//! ```
+//!
+//! e.g.
+//! ```shell
+//! $ cargo run --example concurrent_streams -- -m "Tell me a short story." -n 4
+//! ```
+//!
+//! The `Client` holds a connection pool of `reqwest::Client` and clones of it share the same pool.
+//! With HTTP/2, the concurrent streams are multiplexed over a single connection to the API server,
+//! so each request does not need to pay for a new TCP and TLS handshake.
+//! With HTTP/1, each concurrent stream occupies its own connection and the idle connections are reused by later requests.
+
+use clust::messages::ClaudeModel;
+use clust::messages::MaxTokens;
+use clust::messages::Message;
+use clust::messages::MessageChunk;
+use clust::messages::MessagesRequestBody;
+use clust::messages::StreamOption;
+use clust::ClientBuilder;
+
+use clap::Parser;
+use tokio_stream::StreamExt;
+
+#[derive(Parser)]
+struct Arguments {
+    #[arg(short, long)]
+    message: String,
+    #[arg(short, long, default_value_t = 4)]
+    number: usize,
+    #[arg(long)]
+    http1: bool,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // 0. Parse the command-line arguments.
+    let arguments = Arguments::parse();
+
+    // 1. Create a new API client with the explicit HTTP protocol.
+    let builder = ClientBuilder::from_env()?;
+    let client = if arguments.http1 {
+        // Each concurrent stream uses a separate connection.
+        builder.http1_only().build()
+    } else {
+        // All concurrent streams are multiplexed over a single connection.
+        builder
+            .http2_prior_knowledge()
+            .build()
+    };
+
+    // 2. Spawn the streaming requests concurrently with the clones of the client.
+    let model = ClaudeModel::Claude35Sonnet20241022;
+    let mut handles = Vec::with_capacity(arguments.number);
+    for index in 0..arguments.number {
+        // NOTE: The clones share the same connection pool.
+        let client = client.clone();
+        let request_body = MessagesRequestBody {
+            model,
+            messages: vec![Message::user(
+                arguments.message.clone(),
+            )],
+            max_tokens: MaxTokens::new(1024, model)?,
+            stream: Some(StreamOption::ReturnStream),
+            ..Default::default()
+        };
+
+        handles.push(tokio::spawn(async move {
+            // 3. Call the API.
+            let mut stream = client
+                .create_a_message_stream(request_body)
+                .await?;
+
+            // 4. Poll the stream.
+            let mut buffer = String::new();
+            while let Some(chunk) = stream.next().await {
+                if let MessageChunk::ContentBlockDelta(content_block_delta) =
+                    chunk?
+                {
+                    if let Some(text) = content_block_delta.delta.as_text() {
+                        buffer.push_str(text);
+                    }
+                }
+            }
+
+            anyhow::Ok((index, buffer))
+        }));
+    }
+
+    // 5. Wait for all streams.
+    for handle in handles {
+        let (index, result) = handle.await??;
+        println!("Result of stream {}:\n{}\n", index, result);
+    }
+
+    Ok(())
+}
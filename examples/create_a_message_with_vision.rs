@@ -53,7 +53,7 @@ async fn main() -> anyhow::Result<()> {
     );
 
     // 3. Create a request body.
-    let model = ClaudeModel::Claude3Sonnet20240229;
+    let model = ClaudeModel::Claude35Sonnet20241022;
     let messages = vec![Message::user(vec![
         ContentBlock::from(image_source),
         ContentBlock::from(arguments.message),
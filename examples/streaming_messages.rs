@@ -40,7 +40,7 @@ async fn main() -> anyhow::Result<()> {
     // let client = Client::from_api_key(clust::ApiKey::new("your-api-key"));
 
     // 2. Create a request body with stream option.
-    let model = ClaudeModel::Claude3Sonnet20240229;
+    let model = ClaudeModel::Claude35Sonnet20241022;
     let messages = vec![Message::user(
         arguments.message,
     )];
@@ -70,7 +70,11 @@ async fn main() -> anyhow::Result<()> {
                 println!("Chunk:\n{}", chunk);
                 match chunk {
                     | MessageChunk::ContentBlockDelta(content_block_delta) => {
-                        buffer.push_str(&content_block_delta.delta.text);
+                        if let Some(text) =
+                            content_block_delta.delta.as_text()
+                        {
+                            buffer.push_str(text);
+                        }
                     },
                     | _ => {},
                 }
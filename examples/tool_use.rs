@@ -38,7 +38,7 @@ async fn main() -> anyhow::Result<()> {
     )]);
 
     // 3. Create a request body.
-    let model = ClaudeModel::Claude35Sonnet20240620;
+    let model = ClaudeModel::Claude35Sonnet20241022;
     let messages = vec![Message::user(
         "What is the weather like in San Francisco?",
     )];
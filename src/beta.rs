@@ -1,4 +1,6 @@
+use crate::BetaParseError;
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// The beta feature.
 ///
@@ -28,6 +30,49 @@ impl Display for Beta {
     }
 }
 
+impl FromStr for Beta {
+    type Err = BetaParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            | "tools-2024-04-04" => Ok(Beta::Tools2024_04_04),
+            | _ => Err(BetaParseError {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Beta {
+    /// Parses the comma-separated list of beta features, e.g. the value of the `anthropic-beta` header.
+    ///
+    /// The whitespaces around each feature are trimmed and the empty items are ignored.
+    ///
+    /// ## Arguments
+    /// - `s` - The comma-separated list of beta features.
+    ///
+    /// ## Errors
+    /// It returns an error if any item is not a supported beta feature.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::Beta;
+    ///
+    /// assert_eq!(
+    ///     Beta::from_str_list("tools-2024-04-04, "),
+    ///     Ok(vec![Beta::Tools2024_04_04])
+    /// );
+    /// assert!(Beta::from_str_list("tools-2024-04-04,unknown").is_err());
+    /// ```
+    pub fn from_str_list(s: &str) -> Result<Vec<Beta>, BetaParseError> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(Beta::from_str)
+            .collect()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -44,4 +89,36 @@ mod tests {
             "tools-2024-04-04",
         );
     }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Beta::from_str("tools-2024-04-04"),
+            Ok(Beta::Tools2024_04_04)
+        );
+        assert_eq!(
+            Beta::from_str("unknown"),
+            Err(BetaParseError {
+                value: "unknown".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn from_str_list() {
+        assert_eq!(Beta::from_str_list(""), Ok(vec![]));
+        assert_eq!(
+            Beta::from_str_list(" tools-2024-04-04 ,,tools-2024-04-04"),
+            Ok(vec![
+                Beta::Tools2024_04_04,
+                Beta::Tools2024_04_04
+            ])
+        );
+        assert_eq!(
+            Beta::from_str_list("tools-2024-04-04, unknown"),
+            Err(BetaParseError {
+                value: "unknown".to_string(),
+            })
+        );
+    }
 }
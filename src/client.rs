@@ -5,7 +5,17 @@ use crate::messages::{
     MessageChunk, MessagesError, MessagesRequestBody, MessagesResponseBody,
     StreamError,
 };
-use crate::{ApiKey, Beta, Version};
+use crate::{
+    ApiKey, Beta, BetaParseError, RateLimitInfo, Version, VersionFromEnvError,
+    VersionParseError,
+};
+
+#[cfg(feature = "stream-retry")]
+use crate::messages::{RetryableStream, StreamRetryConfig};
+#[cfg(feature = "rate-limit")]
+use crate::rate_limit::RateLimiter;
+#[cfg(feature = "rate-limit")]
+use std::sync::Arc;
 
 /// The API client.
 #[derive(Clone)]
@@ -16,8 +26,14 @@ pub struct Client {
     version: Version,
     /// Internal HTTP client.
     client: reqwest::Client,
-    /// Beta feature.
-    beta: Option<Beta>,
+    /// Beta features.
+    betas: Vec<Beta>,
+    /// Client-side rate limiter shared between clones of this client.
+    #[cfg(feature = "rate-limit")]
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// The configuration of reconnecting message streams.
+    #[cfg(feature = "stream-retry")]
+    stream_retry: StreamRetryConfig,
 }
 
 impl Client {
@@ -38,7 +54,11 @@ impl Client {
             api_key,
             version,
             client,
-            beta: None,
+            betas: Vec::new(),
+            #[cfg(feature = "rate-limit")]
+            rate_limiter: None,
+            #[cfg(feature = "stream-retry")]
+            stream_retry: StreamRetryConfig::default(),
         })
     }
 
@@ -63,10 +83,19 @@ impl Client {
             api_key,
             version,
             client,
-            beta: None,
+            betas: Vec::new(),
+            #[cfg(feature = "rate-limit")]
+            rate_limiter: None,
+            #[cfg(feature = "stream-retry")]
+            stream_retry: StreamRetryConfig::default(),
         }
     }
 
+    /// The API version of this client.
+    pub fn version(&self) -> Version {
+        self.version
+    }
+
     /// Create a request builder for the `POST` method.
     pub(crate) fn post(
         &self,
@@ -81,12 +110,29 @@ impl Client {
                 self.version.to_string(),
             );
 
-        if let Some(beta) = self.beta {
-            builder = builder.header("anthropic-beta", beta.to_string());
+        if !self.betas.is_empty() {
+            let betas = self
+                .betas
+                .iter()
+                .map(Beta::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            builder = builder.header("anthropic-beta", betas);
         }
 
         builder
     }
+
+    /// Waits until the client-side rate limiter allows to send a request.
+    #[cfg(feature = "rate-limit")]
+    pub(crate) async fn acquire_rate_limit(
+        &self
+    ) -> Result<(), crate::RateLimitError> {
+        match &self.rate_limiter {
+            | Some(rate_limiter) => rate_limiter.acquire().await,
+            | None => Ok(()),
+        }
+    }
 }
 
 impl Client {
@@ -112,7 +158,7 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() -> anyhow::Result<()> {
     ///     let client = Client::from_env()?;
-    ///     let model = ClaudeModel::Claude3Sonnet20240229;
+    ///     let model = ClaudeModel::Claude35Sonnet20241022;
     ///     let max_tokens = MaxTokens::new(1024, model)?;
     ///     let request_body = MessagesRequestBody {
     ///         model,
@@ -134,7 +180,77 @@ impl Client {
         &self,
         request_body: MessagesRequestBody,
     ) -> Result<MessagesResponseBody, MessagesError> {
-        crate::messages::api::create_a_message(self, request_body).await
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "create_a_message",
+            model = %request_body.model
+        );
+
+        let future = crate::messages::api::create_a_message(self, request_body);
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+
+        future.await
+    }
+
+    /// Create a Message with the rate limit information of the API server.
+    ///
+    /// The rate limit information is parsed from the `anthropic-ratelimit-*` response headers.
+    ///
+    /// See also [Create a Message](https://docs.anthropic.com/claude/reference/messages_post) and `Client::create_a_message`.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## NOTE
+    /// The `stream` option must be `None` or `StreamOption::ReturnOnce`.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude35Sonnet20241022;
+    ///     let request_body = MessagesRequestBody {
+    ///         model,
+    ///         max_tokens: MaxTokens::new(1024, model)?,
+    ///         messages: vec![
+    ///             Message::user("Hello, Claude!"),
+    ///         ],
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let (response, rate_limit_info) = client
+    ///         .create_a_message_with_info(request_body)
+    ///         .await?;
+    ///     println!("Remaining requests: {:?}", rate_limit_info.requests_remaining);
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_a_message_with_info(
+        &self,
+        request_body: MessagesRequestBody,
+    ) -> Result<(MessagesResponseBody, RateLimitInfo), MessagesError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "create_a_message_with_info",
+            model = %request_body.model
+        );
+
+        let future = crate::messages::api::create_a_message_with_info(
+            self,
+            request_body,
+        );
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+
+        future.await
     }
 
     /// Create a Message with incrementally streaming the response using server-sent events (SSE).
@@ -156,7 +272,7 @@ impl Client {
     /// #[tokio::main]
     /// async fn main() -> anyhow::Result<()> {
     ///     let client = Client::from_env()?;
-    ///     let model = ClaudeModel::Claude3Sonnet20240229;
+    ///     let model = ClaudeModel::Claude35Sonnet20241022;
     ///     let max_tokens = MaxTokens::new(1024, model)?;
     ///     let request_body = MessagesRequestBody {
     ///         model,
@@ -193,7 +309,81 @@ impl Client {
         impl Stream<Item = Result<MessageChunk, StreamError>>,
         MessagesError,
     > {
-        crate::messages::api::create_a_message_stream(self, request_body).await
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "create_a_message_stream",
+            model = %request_body.model
+        );
+
+        let future =
+            crate::messages::api::create_a_message_stream(self, request_body);
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, span);
+
+        future.await
+    }
+
+    /// Create a Message with incrementally streaming the response, reconnecting on transient network errors.
+    ///
+    /// The stream sends the same request again by the configuration of `ClientBuilder::with_stream_retry`
+    /// and skips the chunks already returned. See also `RetryableStream`.
+    ///
+    /// ## Arguments
+    /// - `request_body` - The request body.
+    ///
+    /// ## NOTE
+    /// The `stream` option must be `StreamOption::ReturnStream`.
+    ///
+    /// ## Errors
+    /// It returns an error if the first request fails. The errors of the reconnections are returned in the stream.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use clust::Client;
+    /// use clust::messages::{MessagesRequestBody, ClaudeModel, Message, MaxTokens, StreamOption};
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = Client::from_env()?;
+    ///     let model = ClaudeModel::Claude35Sonnet20241022;
+    ///     let request_body = MessagesRequestBody {
+    ///         model,
+    ///         max_tokens: MaxTokens::new(1024, model)?,
+    ///         messages: vec![
+    ///             Message::user("Hello, Claude!"),
+    ///         ],
+    ///         stream: Some(StreamOption::ReturnStream),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let mut stream = client
+    ///         .create_a_message_stream_with_retry(request_body)
+    ///         .await?;
+    ///
+    ///     while let Some(chunk) = stream.next().await {
+    ///         // Process the chunk.
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    #[cfg(feature = "stream-retry")]
+    pub async fn create_a_message_stream_with_retry(
+        &self,
+        request_body: MessagesRequestBody,
+    ) -> Result<RetryableStream, MessagesError> {
+        let stream = self
+            .create_a_message_stream(request_body.clone())
+            .await?;
+
+        Ok(RetryableStream::new(
+            self.clone(),
+            request_body,
+            self.stream_retry,
+            stream,
+        ))
     }
 }
 
@@ -220,8 +410,25 @@ pub struct ClientBuilder {
     version: Option<Version>,
     /// Internal HTTP client.
     client: Option<reqwest::Client>,
-    /// Beta feature.
-    beta: Option<Beta>,
+    /// Beta features.
+    betas: Vec<Beta>,
+    /// The HTTP protocol of the default HTTP client.
+    http_protocol: Option<HttpProtocol>,
+    /// The number of requests allowed per minute by the client-side rate limiter.
+    #[cfg(feature = "rate-limit")]
+    rate_limit: Option<u32>,
+    /// The configuration of reconnecting message streams.
+    #[cfg(feature = "stream-retry")]
+    stream_retry: Option<StreamRetryConfig>,
+}
+
+/// The HTTP protocol of the default HTTP client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HttpProtocol {
+    /// Only HTTP/1.
+    Http1Only,
+    /// HTTP/2 with prior knowledge.
+    Http2PriorKnowledge,
 }
 
 impl ClientBuilder {
@@ -231,7 +438,12 @@ impl ClientBuilder {
             api_key,
             version: None,
             client: None,
-            beta: None,
+            betas: Vec::new(),
+            http_protocol: None,
+            #[cfg(feature = "rate-limit")]
+            rate_limit: None,
+            #[cfg(feature = "stream-retry")]
+            stream_retry: None,
         }
     }
 
@@ -251,6 +463,22 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the API version loaded from the environment variable: `ANTHROPIC_VERSION`.
+    ///
+    /// The version is unchanged if the environment variable is not set.
+    ///
+    /// ## Errors
+    /// It returns an error if the value is not a supported API version.
+    pub fn version_from_env(self) -> Result<Self, VersionParseError> {
+        match Version::from_env() {
+            | Ok(version) => Ok(self.version(version)),
+            | Err(VersionFromEnvError::Missing) => Ok(self),
+            | Err(VersionFromEnvError::Invalid {
+                source, ..
+            }) => Err(source),
+        }
+    }
+
     /// Sets the HTTP client.
     pub fn client(
         mut self,
@@ -260,12 +488,144 @@ impl ClientBuilder {
         self
     }
 
-    /// Sets the beta feature.
+    /// Sets the HTTP client configured from the default `reqwest::ClientBuilder`.
+    ///
+    /// Unlike `ClientBuilder::client`, only the specified options are changed from the defaults.
+    /// This overrides the client set by `ClientBuilder::client` or the previous call.
+    ///
+    /// ## Arguments
+    /// - `configure` - The function to configure the default `reqwest::ClientBuilder`.
+    ///
+    /// ## Panics
+    /// It panics if the configured HTTP client cannot be built, as `reqwest::Client::new` does.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::ClientBuilder;
+    /// use clust::ApiKey;
+    ///
+    /// let client = ClientBuilder::new(ApiKey::new("api-key"))
+    ///     .configure_client(|builder| {
+    ///         builder
+    ///             .tcp_nodelay(true)
+    ///             .redirect(reqwest::redirect::Policy::none())
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn configure_client(
+        mut self,
+        configure: impl FnOnce(reqwest::ClientBuilder) -> reqwest::ClientBuilder,
+    ) -> Self {
+        let client = configure(reqwest::ClientBuilder::new())
+            .build()
+            .expect("Failed to build the configured HTTP client");
+
+        self.client = Some(client);
+        self
+    }
+
+    /// Uses only HTTP/2 with prior knowledge for the default HTTP client.
+    ///
+    /// Concurrent requests from the same client, including message streams, are multiplexed over a single connection.
+    /// This is ignored when the HTTP client is set by `ClientBuilder::client` or `ClientBuilder::configure_client`.
+    ///
+    /// ## Panics
+    /// It panics in `ClientBuilder::build` if the HTTP client cannot be built, as `reqwest::Client::new` does.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::ClientBuilder;
+    /// use clust::ApiKey;
+    ///
+    /// let client = ClientBuilder::new(ApiKey::new("api-key"))
+    ///     .http2_prior_knowledge()
+    ///     .build();
+    /// ```
+    pub fn http2_prior_knowledge(mut self) -> Self {
+        self.http_protocol = Some(HttpProtocol::Http2PriorKnowledge);
+        self
+    }
+
+    /// Uses only HTTP/1 for the default HTTP client.
+    ///
+    /// Concurrent requests from the same client open separate connections from the connection pool.
+    /// This is ignored when the HTTP client is set by `ClientBuilder::client` or `ClientBuilder::configure_client`.
+    ///
+    /// ## Panics
+    /// It panics in `ClientBuilder::build` if the HTTP client cannot be built, as `reqwest::Client::new` does.
+    pub fn http1_only(mut self) -> Self {
+        self.http_protocol = Some(HttpProtocol::Http1Only);
+        self
+    }
+
+    /// Adds the beta feature.
+    ///
+    /// Multiple beta features are sent as a comma-separated list.
     pub fn beta(
         mut self,
         beta: Beta,
     ) -> Self {
-        self.beta = Some(beta);
+        if !self.betas.contains(&beta) {
+            self.betas.push(beta);
+        }
+        self
+    }
+
+    /// Adds the beta features loaded from the environment variable: `ANTHROPIC_BETAS` as a comma-separated list.
+    ///
+    /// The beta features are unchanged if the environment variable is not set.
+    ///
+    /// ## Errors
+    /// It returns an error if any item is not a supported beta feature.
+    pub fn betas_from_env(self) -> Result<Self, BetaParseError> {
+        let value = match std::env::var("ANTHROPIC_BETAS") {
+            | Ok(value) => value,
+            | Err(_) => return Ok(self),
+        };
+
+        Ok(Beta::from_str_list(&value)?
+            .into_iter()
+            .fold(self, Self::beta))
+    }
+
+    /// Sets the client-side rate limit with the token bucket algorithm.
+    ///
+    /// When the bucket is empty, `Client::create_a_message` and `Client::create_a_message_stream` wait until a token is available before sending the request.
+    /// Clones of the built client share the same bucket.
+    ///
+    /// ## Arguments
+    /// - `requests_per_minute` - The number of requests allowed per minute.
+    ///
+    /// ## Example
+    /// ```
+    /// use clust::ClientBuilder;
+    /// use clust::ApiKey;
+    ///
+    /// let client = ClientBuilder::new(ApiKey::new("api-key"))
+    ///     .with_rate_limit(50)
+    ///     .build();
+    /// ```
+    #[cfg(feature = "rate-limit")]
+    pub fn with_rate_limit(
+        mut self,
+        requests_per_minute: u32,
+    ) -> Self {
+        self.rate_limit = Some(requests_per_minute);
+        self
+    }
+
+    /// Sets the configuration of reconnecting message streams by `Client::create_a_message_stream_with_retry`.
+    ///
+    /// The default is `StreamRetryConfig::default`.
+    ///
+    /// ## Arguments
+    /// - `config` - The configuration of reconnecting message streams.
+    #[cfg(feature = "stream-retry")]
+    pub fn with_stream_retry(
+        mut self,
+        config: StreamRetryConfig,
+    ) -> Self {
+        self.stream_retry = Some(config);
         self
     }
 
@@ -274,15 +634,40 @@ impl ClientBuilder {
         let version = self
             .version
             .unwrap_or_default();
+        let http_protocol = self.http_protocol;
         let client = self
             .client
-            .unwrap_or_else(|| reqwest::Client::new());
+            .unwrap_or_else(|| match http_protocol {
+                | Some(HttpProtocol::Http1Only) => {
+                    reqwest::ClientBuilder::new()
+                        .http1_only()
+                        .build()
+                        .expect("Failed to build the HTTP/1 client")
+                },
+                | Some(HttpProtocol::Http2PriorKnowledge) => {
+                    reqwest::ClientBuilder::new()
+                        .http2_prior_knowledge()
+                        .build()
+                        .expect("Failed to build the HTTP/2 client")
+                },
+                | None => reqwest::Client::new(),
+            });
 
         Client {
             api_key: self.api_key,
             version,
             client,
-            beta: self.beta,
+            betas: self.betas,
+            #[cfg(feature = "rate-limit")]
+            rate_limiter: self
+                .rate_limit
+                .map(|requests_per_minute| {
+                    Arc::new(RateLimiter::new(requests_per_minute))
+                }),
+            #[cfg(feature = "stream-retry")]
+            stream_retry: self
+                .stream_retry
+                .unwrap_or_default(),
         }
     }
 }
@@ -314,10 +699,114 @@ mod tests {
         assert_eq!(client.api_key.value(), "api-key");
         assert_eq!(client.version, Version::default());
 
+        let client = ClientBuilder::new(ApiKey::new("api-key"))
+            .configure_client(|builder| {
+                builder.timeout(std::time::Duration::from_secs(10))
+            })
+            .build();
+        assert_eq!(client.api_key.value(), "api-key");
+        assert_eq!(client.version, Version::default());
+
         let client = ClientBuilder::new(ApiKey::new("api-key"))
             .beta(Beta::Tools2024_04_04)
             .build();
         assert_eq!(client.api_key.value(), "api-key");
-        assert_eq!(client.beta, Some(Beta::Tools2024_04_04));
+        assert_eq!(client.betas, vec![Beta::Tools2024_04_04]);
+
+        let client = ClientBuilder::new(ApiKey::new("api-key"))
+            .beta(Beta::Tools2024_04_04)
+            .beta(Beta::Tools2024_04_04)
+            .build();
+        assert_eq!(client.betas, vec![Beta::Tools2024_04_04]);
+    }
+
+    #[test]
+    fn builder_from_env() {
+        std::env::remove_var("ANTHROPIC_VERSION");
+        std::env::remove_var("ANTHROPIC_BETAS");
+        let client = ClientBuilder::new(ApiKey::new("api-key"))
+            .version(Version::V2023_01_01)
+            .version_from_env()
+            .unwrap()
+            .betas_from_env()
+            .unwrap()
+            .build();
+        assert_eq!(client.version, Version::V2023_01_01);
+        assert_eq!(client.betas, vec![]);
+        assert_eq!(
+            Version::from_env(),
+            Err(VersionFromEnvError::Missing)
+        );
+
+        std::env::set_var("ANTHROPIC_VERSION", "2023-06-01");
+        std::env::set_var("ANTHROPIC_BETAS", "tools-2024-04-04");
+        let client = ClientBuilder::new(ApiKey::new("api-key"))
+            .version(Version::V2023_01_01)
+            .version_from_env()
+            .unwrap()
+            .betas_from_env()
+            .unwrap()
+            .build();
+        assert_eq!(client.version, Version::V2023_06_01);
+        assert_eq!(client.betas, vec![Beta::Tools2024_04_04]);
+
+        std::env::set_var("ANTHROPIC_VERSION", "invalid");
+        std::env::set_var("ANTHROPIC_BETAS", "tools-2024-04-04,invalid");
+        assert_eq!(
+            Version::from_env(),
+            Err(VersionFromEnvError::Invalid {
+                value: "invalid".to_string(),
+                source: VersionParseError {
+                    value: "invalid".to_string(),
+                },
+            })
+        );
+        assert!(ClientBuilder::new(ApiKey::new("api-key"))
+            .version_from_env()
+            .is_err());
+        assert!(ClientBuilder::new(ApiKey::new("api-key"))
+            .betas_from_env()
+            .is_err());
+
+        std::env::remove_var("ANTHROPIC_VERSION");
+        std::env::remove_var("ANTHROPIC_BETAS");
+    }
+
+    #[test]
+    fn builder_with_http_protocol() {
+        let builder = ClientBuilder::new(ApiKey::new("api-key"));
+        assert_eq!(builder.http_protocol, None);
+
+        let builder = builder.http2_prior_knowledge();
+        assert_eq!(
+            builder.http_protocol,
+            Some(HttpProtocol::Http2PriorKnowledge)
+        );
+        let client = builder.build();
+        assert_eq!(client.api_key.value(), "api-key");
+
+        let builder = ClientBuilder::new(ApiKey::new("api-key")).http1_only();
+        assert_eq!(
+            builder.http_protocol,
+            Some(HttpProtocol::Http1Only)
+        );
+        let client = builder.build();
+        assert_eq!(client.api_key.value(), "api-key");
+    }
+
+    #[cfg(feature = "rate-limit")]
+    #[test]
+    fn builder_with_rate_limit() {
+        let client = ClientBuilder::new(ApiKey::new("api-key")).build();
+        assert!(client.rate_limiter.is_none());
+
+        let client = ClientBuilder::new(ApiKey::new("api-key"))
+            .with_rate_limit(50)
+            .build();
+        let cloned = client.clone();
+        assert!(Arc::ptr_eq(
+            client.rate_limiter.as_ref().unwrap(),
+            cloned.rate_limiter.as_ref().unwrap(),
+        ));
     }
 }
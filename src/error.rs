@@ -2,10 +2,14 @@
 //!
 //! - Validation error of the request -> [`crate::ValidationError`])
 //! - Error of the client API calling -> [`crate::ClientError`]
+//! - Error of the client-side rate limiting -> `crate::RateLimitError` (`rate-limit` feature)
+//! - Error of the conversion from the OpenAI format -> `crate::OpenAiConversionError` (`openai-compat` feature)
+//! - Error of parsing the API version or beta features -> [`crate::VersionParseError`], [`crate::VersionFromEnvError`] and [`crate::BetaParseError`]
 //! - Error of the API server -> [`crate::ApiError`]
 //! - A unique error for the API -> Each API error.
 
 use crate::macros::impl_display_for_serialize;
+use crate::RateLimitInfo;
 use reqwest::StatusCode;
 use std::fmt::Display;
 
@@ -62,6 +66,154 @@ pub enum ClientError {
         error: serde_json::Error,
         text: String,
     },
+    /// Error of consuming a stream of message chunks.
+    #[error("Stream error: {0:?}")]
+    Stream(#[from] crate::messages::StreamError),
+    /// Error body returned from the API server.
+    #[error("API error: {0}")]
+    ApiErrorBody(#[from] ApiErrorBody),
+}
+
+impl ClientError {
+    /// Returns a human-friendly description of the error for end users of an application.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::StreamError;
+    /// use clust::ClientError;
+    ///
+    /// let error = ClientError::from(StreamError::ParseChunkStringError(
+    ///     "invalid chunk".to_string(),
+    /// ));
+    /// assert_eq!(error.to_user_message(), "The AI service returned an error");
+    /// ```
+    pub fn to_user_message(&self) -> String {
+        match self {
+            | ClientError::HttpRequestError(_)
+            | ClientError::ReadResponseTextFailed(_)
+            | ClientError::Stream(crate::messages::StreamError::ReqwestError(
+                _,
+            )) => "Could not reach the AI service".to_string(),
+            | ClientError::ApiErrorBody(body)
+            | ClientError::Stream(crate::messages::StreamError::ApiError(
+                body,
+            )) => user_message_of_api_error_type(&body._type),
+            | ClientError::Stream(
+                crate::messages::StreamError::ReconnectionError(error),
+            ) => error.to_user_message(),
+            | ClientError::ResponseDeserializationFailed {
+                ..
+            }
+            | ClientError::ErrorResponseDeserializationFailed {
+                ..
+            }
+            | ClientError::Stream(_) => {
+                "The AI service returned an error".to_string()
+            },
+        }
+    }
+
+    /// Whether the raw error message is suitable to show end users.
+    ///
+    /// It is `true` only for an API error body with a message, whose `Display` is the message written by the API server.
+    /// Other raw messages contain internal details such as response texts and debug representations,
+    /// use `ClientError::to_user_message` for end users instead.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::{ApiErrorBody, ClientError};
+    ///
+    /// let error = ClientError::from(ApiErrorBody {
+    ///     _type: "invalid_request_error".to_string(),
+    ///     message: "max_tokens: Field required".to_string(),
+    /// });
+    /// assert!(error.is_user_facing_error());
+    /// assert_eq!(error.to_string(), "API error: max_tokens: Field required");
+    /// ```
+    pub fn is_user_facing_error(&self) -> bool {
+        match self {
+            | ClientError::ApiErrorBody(body) => !body.message.is_empty(),
+            | ClientError::HttpRequestError(_)
+            | ClientError::ReadResponseTextFailed(_)
+            | ClientError::ResponseDeserializationFailed {
+                ..
+            }
+            | ClientError::ErrorResponseDeserializationFailed {
+                ..
+            }
+            | ClientError::Stream(_) => false,
+        }
+    }
+}
+
+/// Returns a human-friendly description of the API error type.
+fn user_message_of_api_error_type(_type: &str) -> String {
+    match _type {
+        | "rate_limit_error" | "overloaded_error" => {
+            "Service temporarily unavailable, please try again later"
+                .to_string()
+        },
+        | _ => "The AI service returned an error".to_string(),
+    }
+}
+
+/// The error of the client-side rate limiting.
+#[cfg(feature = "rate-limit")]
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum RateLimitError {
+    /// The request was throttled by the client-side rate limiter and can never be sent.
+    #[error("Request was throttled by the client-side rate limiter")]
+    ClientSideThrottled,
+}
+
+/// The error of the conversion from the OpenAI Chat Completions format.
+#[cfg(feature = "openai-compat")]
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum OpenAiConversionError {
+    /// The role of the message is not supported.
+    #[error("Unsupported role: {0}")]
+    UnsupportedRole(String),
+    /// The model cannot be mapped into a Claude model.
+    #[error("Unsupported model: {0}")]
+    UnsupportedModel(String),
+    /// The validation error of an integer parameter.
+    #[error(transparent)]
+    IntegerValidationError(#[from] ValidationError<u32>),
+    /// The validation error of a float parameter.
+    #[error(transparent)]
+    FloatValidationError(#[from] ValidationError<f32>),
+}
+
+/// The error of parsing the API version from a string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Not supported API version: {value}")]
+pub struct VersionParseError {
+    /// The string that is not a supported API version.
+    pub value: String,
+}
+
+/// The error of loading the API version from the environment variable.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum VersionFromEnvError {
+    /// `ANTHROPIC_VERSION` is not set.
+    #[error("API version environment variable is not set")]
+    Missing,
+    /// The value of the environment variable is not a supported API version.
+    #[error("Invalid API version in environment variable: {value}")]
+    Invalid {
+        /// The value of the environment variable.
+        value: String,
+        /// The parse error.
+        source: VersionParseError,
+    },
+}
+
+/// The error of parsing the beta feature from a string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Not supported beta feature: {value}")]
+pub struct BetaParseError {
+    /// The string that is not a supported beta feature.
+    pub value: String,
 }
 
 /// The error of the API server.
@@ -73,6 +225,8 @@ pub struct ApiError {
     pub _type: ApiErrorType,
     /// The response body of the error.
     pub response: ApiErrorResponse,
+    /// The rate limit information in the response headers.
+    pub rate_limit_info: RateLimitInfo,
 }
 
 impl Display for ApiError {
@@ -93,14 +247,34 @@ impl ApiError {
     pub(crate) fn new(
         status: StatusCode,
         response: ApiErrorResponse,
+        rate_limit_info: RateLimitInfo,
     ) -> Self {
         let _type = ApiErrorType::from(status);
         Self {
             status,
             _type,
             response,
+            rate_limit_info,
         }
     }
+
+    /// The details of the rate limit error with the rate limit information in the response headers.
+    ///
+    /// It returns `None` if this is not a rate limit error.
+    pub fn rate_limit_error(&self) -> Option<ApiRateLimitError> {
+        if self._type != ApiErrorType::RateLimitError {
+            return None;
+        }
+
+        Some(ApiRateLimitError {
+            message: self
+                .response
+                .error
+                .message
+                .clone(),
+            rate_limit_info: self.rate_limit_info,
+        })
+    }
 }
 
 /// The response body of the API error defined at [the errors](https://docs.anthropic.com/claude/reference/errors).
@@ -116,7 +290,15 @@ pub struct ApiErrorResponse {
 impl_display_for_serialize!(ApiErrorResponse);
 
 /// The API error body defined at [the errors](https://docs.anthropic.com/claude/reference/errors).
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    thiserror::Error,
+)]
+#[error("{message}")]
 pub struct ApiErrorBody {
     /// The type of the error.
     #[serde(rename = "type")]
@@ -125,7 +307,45 @@ pub struct ApiErrorBody {
     pub message: String,
 }
 
-impl_display_for_serialize!(ApiErrorBody);
+impl ApiErrorBody {
+    /// The details of the rate limit error parsed from the error body.
+    ///
+    /// The error body has no rate limit information, use `ApiError::rate_limit_error` to get it from the response headers.
+    /// It returns `None` if the type is not `rate_limit_error`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::ApiErrorBody;
+    ///
+    /// let body: ApiErrorBody = serde_json::from_str(
+    ///     r#"{"type":"rate_limit_error","message":"Number of request tokens has exceeded your rate limit."}"#,
+    /// )
+    /// .unwrap();
+    /// let error = body.as_rate_limit_error().unwrap();
+    /// assert_eq!(error.message, "Number of request tokens has exceeded your rate limit.");
+    /// assert_eq!(error.rate_limit_info.retry_after, None);
+    /// ```
+    pub fn as_rate_limit_error(&self) -> Option<ApiRateLimitError> {
+        if self._type != "rate_limit_error" {
+            return None;
+        }
+
+        Some(ApiRateLimitError {
+            message: self.message.clone(),
+            rate_limit_info: RateLimitInfo::default(),
+        })
+    }
+}
+
+/// The details of the rate limit error (429) of the API server.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("Rate limit error: {message}")]
+pub struct ApiRateLimitError {
+    /// The error message.
+    pub message: String,
+    /// The rate limit information in the response headers.
+    pub rate_limit_info: RateLimitInfo,
+}
 
 /// The API error type defined at [the errors](https://docs.anthropic.com/claude/reference/errors).
 #[derive(Debug, Clone, PartialEq)]
@@ -199,3 +419,176 @@ impl From<StatusCode> for ApiErrorType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::StreamError;
+
+    #[test]
+    fn to_user_message() {
+        let error = ClientError::ResponseDeserializationFailed {
+            error: serde_json::from_str::<u32>("invalid").unwrap_err(),
+            text: "invalid".to_string(),
+        };
+        assert_eq!(
+            error.to_user_message(),
+            "The AI service returned an error"
+        );
+
+        let error = ClientError::from(StreamError::ParseChunkStringError(
+            "invalid".to_string(),
+        ));
+        assert_eq!(
+            error.to_user_message(),
+            "The AI service returned an error"
+        );
+    }
+
+    #[test]
+    fn is_user_facing_error() {
+        let error = ClientError::from(ApiErrorBody {
+            _type: "invalid_request_error".to_string(),
+            message: "Invalid request".to_string(),
+        });
+        assert!(error.is_user_facing_error());
+
+        let error = ClientError::from(ApiErrorBody {
+            _type: "api_error".to_string(),
+            message: String::new(),
+        });
+        assert!(!error.is_user_facing_error());
+
+        let error = ClientError::ResponseDeserializationFailed {
+            error: serde_json::from_str::<u32>("invalid").unwrap_err(),
+            text: "invalid".to_string(),
+        };
+        assert!(!error.is_user_facing_error());
+
+        let error = ClientError::from(StreamError::ApiError(ApiErrorBody {
+            _type: "overloaded_error".to_string(),
+            message: "Overloaded".to_string(),
+        }));
+        assert!(!error.is_user_facing_error());
+    }
+
+    #[test]
+    fn api_error_body() {
+        let body = ApiErrorBody {
+            _type: "overloaded_error".to_string(),
+            message: "Overloaded".to_string(),
+        };
+        assert_eq!(body.to_string(), "Overloaded");
+        assert!(std::error::Error::source(&body).is_none());
+
+        let error = ClientError::from(body.clone());
+        assert!(matches!(
+            error,
+            ClientError::ApiErrorBody(ref inner) if inner == &body
+        ));
+        assert_eq!(
+            error.to_user_message(),
+            "Service temporarily unavailable, please try again later"
+        );
+
+        let error = ClientError::from(StreamError::ApiError(ApiErrorBody {
+            _type: "rate_limit_error".to_string(),
+            message: "Rate limited".to_string(),
+        }));
+        assert_eq!(
+            error.to_user_message(),
+            "Service temporarily unavailable, please try again later"
+        );
+
+        let error = ClientError::from(ApiErrorBody {
+            _type: "invalid_request_error".to_string(),
+            message: "Invalid request".to_string(),
+        });
+        assert_eq!(
+            error.to_user_message(),
+            "The AI service returned an error"
+        );
+    }
+
+    #[test]
+    fn rate_limit_error() {
+        let response: ApiErrorResponse = serde_json::from_str(
+            r#"{"type":"error","error":{"type":"rate_limit_error","message":"Rate limited"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response
+                .error
+                .as_rate_limit_error(),
+            Some(ApiRateLimitError {
+                message: "Rate limited".to_string(),
+                rate_limit_info: RateLimitInfo::default(),
+            })
+        );
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            "anthropic-ratelimit-requests-limit",
+            reqwest::header::HeaderValue::from_static("50"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-requests-remaining",
+            reqwest::header::HeaderValue::from_static("0"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-tokens-limit",
+            reqwest::header::HeaderValue::from_static("40000"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-tokens-remaining",
+            reqwest::header::HeaderValue::from_static("12000"),
+        );
+        headers.insert(
+            "retry-after",
+            reqwest::header::HeaderValue::from_static("15"),
+        );
+        let rate_limit_info = RateLimitInfo::from_headers(&headers);
+
+        let error = ApiError::new(
+            StatusCode::TOO_MANY_REQUESTS,
+            response.clone(),
+            rate_limit_info,
+        );
+        let rate_limit_error = error
+            .rate_limit_error()
+            .unwrap();
+        assert_eq!(rate_limit_error.message, "Rate limited");
+        assert_eq!(
+            rate_limit_error.rate_limit_info,
+            RateLimitInfo {
+                requests_limit: Some(50),
+                requests_remaining: Some(0),
+                tokens_limit: Some(40000),
+                tokens_remaining: Some(12000),
+                retry_after: Some(std::time::Duration::from_secs(15)),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            rate_limit_error.to_string(),
+            "Rate limit error: Rate limited"
+        );
+
+        let response: ApiErrorResponse = serde_json::from_str(
+            r#"{"type":"error","error":{"type":"overloaded_error","message":"Overloaded"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            response
+                .error
+                .as_rate_limit_error(),
+            None
+        );
+        let error = ApiError::new(
+            StatusCode::from_u16(529).unwrap(),
+            response,
+            rate_limit_info,
+        );
+        assert_eq!(error.rate_limit_error(), None);
+    }
+}
@@ -8,6 +8,13 @@
 //! ## Feature flags
 //! - `macros`: Enable the [`attributes::clust_tool`] attribute macro for generating [`messages::ToolDefinition`]
 //!   or [`messages::AsyncTool`] from a Rust function.
+//! - `rate-limit`: Enable the client-side rate limiting by `ClientBuilder::with_rate_limit` with `tokio` timer.
+//! - `stream-retry`: Enable the reconnection of message streams on transient network errors by `Client::create_a_message_stream_with_retry` with `tokio` timer.
+//! - `parallel-tools`: Enable the parallel tool calls by `messages::AsyncToolList::call_all_parallel` with `tokio` tasks.
+//! - `tracing`: Enable the `tracing` spans and events in API calls of `Client`.
+//! - `openai-compat`: Enable the conversion between the OpenAI Chat Completions format and the Messages API format in `openai_compat`.
+//! - `testing`: Enable the test utilities in [`messages::testing`] for streaming-aware code and content assertions.
+//! - `regex`: Enable the regular expression replacement by `messages::Content::replace_all_regex`.
 //!
 //! ## Usages
 //!
@@ -48,7 +55,7 @@
 //! use clust::messages::ClaudeModel;
 //! use clust::messages::MessagesRequestBody;
 //!
-//! let model = ClaudeModel::Claude3Sonnet20240229;
+//! let model = ClaudeModel::Claude35Sonnet20241022;
 //!
 //! let request_body = MessagesRequestBody {
 //!     model,
@@ -64,7 +71,7 @@
 //! use clust::messages::MaxTokens;
 //! use clust::messages::MessagesRequestBody;
 //!
-//! let model = ClaudeModel::Claude3Sonnet20240229;
+//! let model = ClaudeModel::Claude35Sonnet20241022;
 //! let max_tokens = MaxTokens::new(1024, model).unwrap();
 //!
 //! let request_body = MessagesRequestBody {
@@ -188,9 +195,9 @@
 //! use clust::messages::SystemPrompt;
 //!
 //! let request_body = MessagesRequestBody {
-//!     model: ClaudeModel::Claude3Sonnet20240229,
+//!     model: ClaudeModel::Claude35Sonnet20241022,
 //!     messages: vec![Message::user("Hello, Claude!")],
-//!     max_tokens: MaxTokens::new(1024, ClaudeModel::Claude3Sonnet20240229).unwrap(),
+//!     max_tokens: MaxTokens::new(1024, ClaudeModel::Claude35Sonnet20241022).unwrap(),
 //!     system: Some(SystemPrompt::new("You are an excellent AI assistant.")),
 //!     ..Default::default()
 //! };
@@ -205,7 +212,7 @@
 //! use clust::messages::SystemPrompt;
 //!
 //! let request_body = MessagesRequestBuilder::new_with_max_tokens(
-//!     ClaudeModel::Claude3Sonnet20240229,
+//!     ClaudeModel::Claude35Sonnet20241022,
 //!     1024,
 //! ).unwrap()
 //! .messages(vec![Message::user("Hello, Claude!")])
@@ -350,7 +357,7 @@
 //!     let client = Client::from_env()?;
 //!
 //!     // 2. Create a request body.
-//!     let model = ClaudeModel::Claude3Sonnet20240229;
+//!     let model = ClaudeModel::Claude35Sonnet20241022;
 //!     let messages = vec![Message::user(
 //!         "Where is the capital of France?",
 //!     )];
@@ -402,7 +409,7 @@
 //!     let client = Client::from_env()?;
 //!
 //!     // 2. Create a request body with `stream` option.
-//!     let model = ClaudeModel::Claude3Sonnet20240229;
+//!     let model = ClaudeModel::Claude35Sonnet20241022;
 //!     let messages = vec![Message::user(
 //!         "Where is the capital of France?",
 //!     )];
@@ -433,7 +440,9 @@
 //!                 match chunk {
 //!                     | MessageChunk::ContentBlockDelta(content_block_delta) => {
 //!                         // Buffer message delta.
-//!                         buffer.push_str(&content_block_delta.delta.text);
+//!                         if let Some(text) = content_block_delta.delta.as_text() {
+//!                             buffer.push_str(text);
+//!                         }
 //!                     }
 //!                     | _ => {}
 //!                 }
@@ -470,6 +479,9 @@ mod api_key;
 mod beta;
 mod client;
 mod error;
+#[cfg(feature = "rate-limit")]
+mod rate_limit;
+mod rate_limit_info;
 mod version;
 
 pub(crate) mod macros;
@@ -479,6 +491,9 @@ pub mod messages;
 #[cfg(feature = "macros")]
 pub mod attributes;
 
+#[cfg(feature = "openai-compat")]
+pub mod openai_compat;
+
 pub use api_key::ApiKey;
 pub use beta::Beta;
 pub use client::Client;
@@ -487,8 +502,17 @@ pub use error::ApiError;
 pub use error::ApiErrorBody;
 pub use error::ApiErrorResponse;
 pub use error::ApiErrorType;
+pub use error::ApiRateLimitError;
+pub use error::BetaParseError;
 pub use error::ClientError;
+#[cfg(feature = "openai-compat")]
+pub use error::OpenAiConversionError;
+#[cfg(feature = "rate-limit")]
+pub use error::RateLimitError;
 pub use error::ValidationError;
+pub use error::VersionFromEnvError;
+pub use error::VersionParseError;
+pub use rate_limit_info::RateLimitInfo;
 pub use version::Version;
 
 pub use futures_core;
@@ -3,49 +3,84 @@
 mod chunk_stream;
 mod claude_model;
 mod content;
+mod conversation;
 mod error;
+mod input_json_accumulator;
 mod max_tokens;
 mod message;
 mod message_chunk;
+mod message_stream_ext;
 mod messages_request_body;
 mod messages_response_body;
 mod metadata;
 mod role;
 mod stop_reason;
 mod stop_sequence;
+mod stream_collector;
 mod stream_option;
 mod system_prompt;
 mod temperature;
+mod token_budget;
 mod top_k;
 mod top_p;
 mod usage;
 
 pub(crate) mod api;
+#[cfg(feature = "stream-retry")]
+mod retryable_stream;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod tool;
+mod tool_choice;
 
 pub use claude_model::ClaudeModel;
 pub use content::Content;
 pub use content::ContentBlock;
+pub use content::ContentPartition;
 pub use content::ContentType;
+pub use content::DocumentContentBlock;
+pub use content::DocumentContentSource;
+pub use content::DocumentMediaType;
+pub use content::DocumentSourceType;
 pub use content::ImageContentBlock;
 pub use content::ImageContentSource;
 pub use content::ImageMediaType;
+pub use content::ImageSourceData;
 pub use content::ImageSourceType;
+pub use content::OwnedContentPartition;
 pub use content::TextContentBlock;
 pub use content::ToolResultContentBlock;
 pub use content::ToolUseContentBlock;
+pub use conversation::Conversation;
 pub use error::ContentFlatteningError;
+pub use error::ContentIndexError;
+pub use error::ConversationError;
+pub use error::ImageLoadError;
 pub use error::ImageMediaTypeParseError;
+pub use error::MessageChunkConversionError;
 pub use error::MessageChunkTypeError;
 pub use error::MessagesError;
+pub use error::ModelFromEnvError;
+pub use error::ModelParseError;
+pub use error::RequestValidationError;
 pub use error::StreamError;
 pub use error::ToolCallError;
+pub use error::ToolMergeError;
+pub use input_json_accumulator::InputJsonAccumulator;
+pub use input_json_accumulator::StreamToolAccumulator;
+pub use input_json_accumulator::ToolInputAccumulator;
 pub use max_tokens::MaxTokens;
+pub use message::single_tool_result_message;
+pub use message::to_tool_result_message;
+pub use message::tool_result_message;
 pub use message::Message;
+pub use message::MessageSliceExt;
 pub use message_chunk::ContentBlockDeltaChunk;
 pub use message_chunk::ContentBlockStartChunk;
 pub use message_chunk::ContentBlockStopChunk;
+pub use message_chunk::DeltaContentBlock;
 pub use message_chunk::DeltaUsage;
+pub use message_chunk::InputJsonDeltaContentBlock;
 pub use message_chunk::MessageChunk;
 pub use message_chunk::MessageChunkType;
 pub use message_chunk::MessageDeltaChunk;
@@ -54,24 +89,48 @@ pub use message_chunk::MessageStopChunk;
 pub use message_chunk::PingChunk;
 pub use message_chunk::StreamStop;
 pub use message_chunk::TextDeltaContentBlock;
+pub use message_stream_ext::text_delta_stream;
+pub use message_stream_ext::FilterPings;
+pub use message_stream_ext::FullText;
+pub use message_stream_ext::MessageStreamExt;
+pub use message_stream_ext::TextDeltas;
+pub use message_stream_ext::TryTextDeltas;
+pub use message_stream_ext::UntilStop;
 pub use messages_request_body::MessagesRequestBody;
+pub use messages_request_body::MessagesRequestBodyOverrides;
 pub use messages_request_body::MessagesRequestBuilder;
 pub use messages_response_body::MessageObjectType;
 pub use messages_response_body::MessagesResponseBody;
+pub use messages_response_body::ResponseParts;
 pub use metadata::Metadata;
 pub use metadata::UserId;
+#[cfg(feature = "stream-retry")]
+pub use retryable_stream::RetryableStream;
+#[cfg(feature = "stream-retry")]
+pub use retryable_stream::StreamRetryConfig;
 pub use role::Role;
 pub use stop_reason::StopReason;
 pub use stop_sequence::StopSequence;
+pub use stream_collector::collect_stream;
+pub use stream_collector::ContentBlockAccumulator;
+pub use stream_collector::StreamCollector;
 pub use stream_option::StreamOption;
 pub use system_prompt::SystemPrompt;
 pub use temperature::Temperature;
+pub use temperature::TemperaturePreset;
+pub use token_budget::TokenBudget;
+pub use tool::AsyncClosureTool;
 pub use tool::AsyncTool;
+pub use tool::AsyncToolList;
+pub use tool::ClosureTool;
 pub use tool::Tool;
 pub use tool::ToolDefinition;
+pub use tool::ToolFn;
 pub use tool::ToolList;
 pub use tool::ToolResult;
+pub use tool::ToolResultContent;
 pub use tool::ToolUse;
+pub use tool_choice::ToolChoice;
 pub use top_k::TopK;
 pub use top_p::TopP;
 pub use usage::Usage;
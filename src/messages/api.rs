@@ -6,6 +6,7 @@ use crate::messages::{
 use crate::ApiError;
 use crate::Client;
 use crate::ClientError;
+use crate::RateLimitInfo;
 
 use futures_core::Stream;
 
@@ -13,6 +14,15 @@ pub(crate) async fn create_a_message(
     client: &Client,
     request_body: MessagesRequestBody,
 ) -> Result<MessagesResponseBody, MessagesError> {
+    create_a_message_with_info(client, request_body)
+        .await
+        .map(|(response_body, _)| response_body)
+}
+
+pub(crate) async fn create_a_message_with_info(
+    client: &Client,
+    request_body: MessagesRequestBody,
+) -> Result<(MessagesResponseBody, RateLimitInfo), MessagesError> {
     // Validate stream option.
     if let Some(stream) = &request_body.stream {
         if *stream != StreamOption::ReturnOnce {
@@ -20,6 +30,18 @@ pub(crate) async fn create_a_message(
         }
     }
 
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        request_body = %request_body,
+        "Sending a request to the Messages API"
+    );
+
+    // Wait for the client-side rate limit.
+    #[cfg(feature = "rate-limit")]
+    client
+        .acquire_rate_limit()
+        .await?;
+
     // Send the request.
     let response = client
         .post("https://api.anthropic.com/v1/messages")
@@ -31,6 +53,9 @@ pub(crate) async fn create_a_message(
     // Check the response status code.
     let status_code = response.status();
 
+    // Parse the rate limit headers.
+    let rate_limit_info = RateLimitInfo::from_headers(response.headers());
+
     // Read the response text.
     let response_text = response
         .text()
@@ -40,15 +65,24 @@ pub(crate) async fn create_a_message(
     // Ok
     if status_code.is_success() {
         // Deserialize the response.
-        serde_json::from_str(&response_text).map_err(|error| {
-            {
+        let response_body: MessagesResponseBody =
+            serde_json::from_str(&response_text).map_err(|error| {
                 ClientError::ResponseDeserializationFailed {
                     error,
                     text: response_text,
                 }
-            }
-            .into()
-        })
+            })?;
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            id = %response_body.id,
+            stop_reason = ?response_body.stop_reason,
+            input_tokens = response_body.usage.input_tokens,
+            output_tokens = response_body.usage.output_tokens,
+            "Received a response from the Messages API"
+        );
+
+        Ok((response_body, rate_limit_info))
     }
     // Error
     else {
@@ -61,7 +95,19 @@ pub(crate) async fn create_a_message(
                 }
             })?;
 
-        Err(ApiError::new(status_code, error_response).into())
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            status = %status_code,
+            error = %error_response,
+            "Received an error response from the Messages API"
+        );
+
+        Err(ApiError::new(
+            status_code,
+            error_response,
+            rate_limit_info,
+        )
+        .into())
     }
 }
 
@@ -80,6 +126,18 @@ pub(crate) async fn create_a_message_stream(
         }
     }
 
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        request_body = %request_body,
+        "Sending a request to the Messages API"
+    );
+
+    // Wait for the client-side rate limit.
+    #[cfg(feature = "rate-limit")]
+    client
+        .acquire_rate_limit()
+        .await?;
+
     // Send the request.
     let response = client
         .post("https://api.anthropic.com/v1/messages")
@@ -91,8 +149,14 @@ pub(crate) async fn create_a_message_stream(
     // Check the response status code.
     let status_code = response.status();
 
+    // Parse the rate limit headers.
+    let rate_limit_info = RateLimitInfo::from_headers(response.headers());
+
     // Ok
     if status_code.is_success() {
+        #[cfg(feature = "tracing")]
+        tracing::info!("Started streaming a response from the Messages API");
+
         // Create a chunk stream from response bytes stream.
         let byte_stream = response.bytes_stream();
         let chunk_stream = ChunkStream::new(byte_stream);
@@ -115,6 +179,18 @@ pub(crate) async fn create_a_message_stream(
                 }
             })?;
 
-        Err(ApiError::new(status_code, error_response).into())
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            status = %status_code,
+            error = %error_response,
+            "Received an error response from the Messages API"
+        );
+
+        Err(ApiError::new(
+            status_code,
+            error_response,
+            rate_limit_info,
+        )
+        .into())
     }
 }
@@ -71,7 +71,7 @@ where
                             .map_err(StreamError::StringDecodingError)?;
 
                         let chunk = MessageChunk::parse(&chunk)?;
-                        return Poll::Ready(Some(Ok(chunk)));
+                        return Poll::Ready(Some(into_result(chunk)));
                     }
                 }
             }
@@ -102,7 +102,7 @@ where
                             String::from_utf8(remaining.to_vec())
                                 .map_err(StreamError::StringDecodingError)?;
                         let chunk = MessageChunk::parse(&remaining)?;
-                        Poll::Ready(Some(Ok(chunk)))
+                        Poll::Ready(Some(into_result(chunk)))
                     };
                 },
                 // The stream has no more data for now.
@@ -112,6 +112,14 @@ where
     }
 }
 
+/// Converts the error event chunk into the error of the stream.
+fn into_result(chunk: MessageChunk) -> Result<MessageChunk, StreamError> {
+    match chunk {
+        | MessageChunk::Error(error) => Err(StreamError::ApiError(error)),
+        | chunk => Ok(chunk),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::messages::*;
@@ -171,6 +179,7 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
                         usage: Usage {
                             input_tokens: 25,
                             output_tokens: 1,
+                            ..Default::default()
                         },
                     }),
                 );
@@ -333,6 +342,7 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
                         usage: Usage {
                             input_tokens: 25,
                             output_tokens: 1,
+                            ..Default::default()
                         },
                     }),
                 );
@@ -440,4 +450,48 @@ data: {"type": "message_delta", "delta": {"stop_reason": "end_turn", "stop_seque
             .await
             .is_none());
     }
+
+    #[tokio::test]
+    async fn next_error_event() {
+        use futures_util::StreamExt;
+
+        let source = r#"event: ping
+data: {"type": "ping"}
+
+event: error
+data: {"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}
+
+"#;
+
+        let input_stream = futures_util::stream::iter(vec![Ok(
+            bytes::Bytes::from(source),
+        )]);
+
+        let mut chunk_stream = ChunkStream::new(input_stream);
+
+        let chunk = chunk_stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(chunk, MessageChunk::Ping(PingChunk::new()));
+
+        let error = chunk_stream
+            .next()
+            .await
+            .unwrap()
+            .unwrap_err();
+        match error {
+            | StreamError::ApiError(body) => {
+                assert_eq!(body._type, "overloaded_error");
+                assert_eq!(body.message, "Overloaded");
+            },
+            | _ => panic!("unexpected error: {}", error),
+        }
+
+        assert!(chunk_stream
+            .next()
+            .await
+            .is_none());
+    }
 }
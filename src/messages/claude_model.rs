@@ -1,5 +1,10 @@
+// Deprecated models are still supported in this module.
+#![allow(deprecated)]
+
 use crate::macros::impl_enum_string_serialization;
+use crate::messages::{ModelFromEnvError, ModelParseError};
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// The model that will complete your prompt.
 ///
@@ -11,18 +16,28 @@ pub enum ClaudeModel {
     Claude3Opus20240229,
     // Claude 3 Sonnet
     /// Claude 3 Sonnet at 2024/02/29.
+    #[deprecated(
+        since = "0.10.0",
+        note = "Use Claude35Sonnet20241022"
+    )]
     Claude3Sonnet20240229,
     // Claude 3 Haiku
     /// Claude 3 Haiku at 2024/03/07.
     Claude3Haiku20240307,
     // Claude 3.5 Sonnet
     /// Claude 3.5 Sonnet at 2024/06/20
+    #[deprecated(
+        since = "0.10.0",
+        note = "Use Claude35Sonnet20241022"
+    )]
     Claude35Sonnet20240620,
+    /// Claude 3.5 Sonnet at 2024/10/22
+    Claude35Sonnet20241022,
 }
 
 impl Default for ClaudeModel {
     fn default() -> Self {
-        Self::Claude3Sonnet20240229
+        Self::Claude35Sonnet20241022
     }
 }
 
@@ -44,19 +59,112 @@ impl Display for ClaudeModel {
             | ClaudeModel::Claude35Sonnet20240620 => {
                 write!(f, "claude-3-5-sonnet-20240620")
             },
+            | ClaudeModel::Claude35Sonnet20241022 => {
+                write!(f, "claude-3-5-sonnet-20241022")
+            },
+        }
+    }
+}
+
+impl FromStr for ClaudeModel {
+    type Err = ModelParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            | "claude-3-opus-20240229" => Ok(ClaudeModel::Claude3Opus20240229),
+            | "claude-3-sonnet-20240229" => {
+                Ok(ClaudeModel::Claude3Sonnet20240229)
+            },
+            | "claude-3-haiku-20240307" => {
+                Ok(ClaudeModel::Claude3Haiku20240307)
+            },
+            | "claude-3-5-sonnet-20240620" => {
+                Ok(ClaudeModel::Claude35Sonnet20240620)
+            },
+            | "claude-3-5-sonnet-20241022" => {
+                Ok(ClaudeModel::Claude35Sonnet20241022)
+            },
+            | _ => Err(ModelParseError {
+                value: s.to_string(),
+            }),
         }
     }
 }
 
 impl ClaudeModel {
+    /// Loads the model from the environment variable: `ANTHROPIC_MODEL` or `CLAUDE_MODEL`.
+    ///
+    /// `ANTHROPIC_MODEL` takes precedence over `CLAUDE_MODEL` if both are set.
+    ///
+    /// ## Errors
+    /// - `ModelFromEnvError::Missing` if neither environment variable is set.
+    /// - `ModelFromEnvError::Invalid` if the value is not a supported model.
+    pub fn from_env() -> Result<Self, ModelFromEnvError> {
+        let value = std::env::var("ANTHROPIC_MODEL")
+            .or_else(|_| std::env::var("CLAUDE_MODEL"))
+            .map_err(|_| ModelFromEnvError::Missing)?;
+
+        Self::from_str(&value).map_err(|source| {
+            ModelFromEnvError::Invalid {
+                value,
+                source,
+            }
+        })
+    }
+
+    /// Loads the model from the environment variable as `ClaudeModel::from_env` or returns the default.
+    ///
+    /// ## Arguments
+    /// - `default` - The model used if the environment variable is not set or invalid.
+    pub fn from_env_or(default: ClaudeModel) -> Self {
+        Self::from_env().unwrap_or(default)
+    }
+
     pub(crate) fn max_tokens(&self) -> u32 {
         match self {
             | ClaudeModel::Claude3Opus20240229 => 4096,
             | ClaudeModel::Claude3Sonnet20240229 => 4096,
             | ClaudeModel::Claude3Haiku20240307 => 4096,
             | ClaudeModel::Claude35Sonnet20240620 => 4096,
+            | ClaudeModel::Claude35Sonnet20241022 => 8192,
+        }
+    }
+
+    /// The recommended replacement of this model if it is deprecated.
+    ///
+    /// This information is approximate since the deprecation schedule of Anthropic changes,
+    /// see [model deprecations](https://docs.anthropic.com/en/docs/resources/model-deprecations).
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ClaudeModel;
+    ///
+    /// assert_eq!(
+    ///     ClaudeModel::Claude3Haiku20240307.recommended_successor(),
+    ///     None
+    /// );
+    /// ```
+    pub fn recommended_successor(&self) -> Option<ClaudeModel> {
+        match self {
+            | ClaudeModel::Claude3Sonnet20240229 => {
+                Some(ClaudeModel::Claude35Sonnet20241022)
+            },
+            | ClaudeModel::Claude35Sonnet20240620 => {
+                Some(ClaudeModel::Claude35Sonnet20241022)
+            },
+            | ClaudeModel::Claude3Opus20240229
+            | ClaudeModel::Claude3Haiku20240307
+            | ClaudeModel::Claude35Sonnet20241022 => None,
         }
     }
+
+    /// Whether this model is deprecated.
+    ///
+    /// This information is approximate as `ClaudeModel::recommended_successor`.
+    pub fn is_deprecated(&self) -> bool {
+        self.recommended_successor()
+            .is_some()
+    }
 }
 
 impl_enum_string_serialization!(
@@ -64,7 +172,8 @@ impl_enum_string_serialization!(
     Claude3Opus20240229 => "claude-3-opus-20240229",
     Claude3Sonnet20240229 => "claude-3-sonnet-20240229",
     Claude3Haiku20240307 => "claude-3-haiku-20240307",
-    Claude35Sonnet20240620 => "claude-3-5-sonnet-20240620"
+    Claude35Sonnet20240620 => "claude-3-5-sonnet-20240620",
+    Claude35Sonnet20241022 => "claude-3-5-sonnet-20241022"
 );
 
 #[cfg(test)]
@@ -75,7 +184,7 @@ mod tests {
     fn default() {
         assert_eq!(
             ClaudeModel::default(),
-            ClaudeModel::Claude3Sonnet20240229
+            ClaudeModel::Claude35Sonnet20241022
         );
     }
 
@@ -97,6 +206,10 @@ mod tests {
             ClaudeModel::Claude35Sonnet20240620.to_string(),
             "claude-3-5-sonnet-20240620"
         );
+        assert_eq!(
+            ClaudeModel::Claude35Sonnet20241022.to_string(),
+            "claude-3-5-sonnet-20241022"
+        );
     }
 
     #[test]
@@ -117,6 +230,10 @@ mod tests {
             ClaudeModel::Claude35Sonnet20240620.max_tokens(),
             4096
         );
+        assert_eq!(
+            ClaudeModel::Claude35Sonnet20241022.max_tokens(),
+            8192
+        );
     }
 
     #[test]
@@ -165,4 +282,89 @@ mod tests {
             "\"claude-3-5-sonnet-20240620\""
         );
     }
+
+    #[test]
+    fn recommended_successor() {
+        assert_eq!(
+            ClaudeModel::Claude3Sonnet20240229.recommended_successor(),
+            Some(ClaudeModel::Claude35Sonnet20241022)
+        );
+        assert_eq!(
+            ClaudeModel::Claude35Sonnet20240620.recommended_successor(),
+            Some(ClaudeModel::Claude35Sonnet20241022)
+        );
+        assert_eq!(
+            ClaudeModel::Claude35Sonnet20241022.recommended_successor(),
+            None
+        );
+        assert!(ClaudeModel::Claude3Sonnet20240229.is_deprecated());
+        assert!(!ClaudeModel::Claude3Haiku20240307.is_deprecated());
+    }
+
+    #[test]
+    fn from_str() {
+        for model in [
+            ClaudeModel::Claude3Opus20240229,
+            ClaudeModel::Claude3Sonnet20240229,
+            ClaudeModel::Claude3Haiku20240307,
+            ClaudeModel::Claude35Sonnet20240620,
+            ClaudeModel::Claude35Sonnet20241022,
+        ] {
+            assert_eq!(
+                ClaudeModel::from_str(&model.to_string()),
+                Ok(model)
+            );
+        }
+
+        assert_eq!(
+            ClaudeModel::from_str("gpt-4"),
+            Err(ModelParseError {
+                value: "gpt-4".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn from_env() {
+        std::env::remove_var("ANTHROPIC_MODEL");
+        std::env::remove_var("CLAUDE_MODEL");
+        assert_eq!(
+            ClaudeModel::from_env(),
+            Err(ModelFromEnvError::Missing)
+        );
+        assert_eq!(
+            ClaudeModel::from_env_or(ClaudeModel::Claude3Haiku20240307),
+            ClaudeModel::Claude3Haiku20240307
+        );
+
+        std::env::set_var("CLAUDE_MODEL", "claude-3-opus-20240229");
+        assert_eq!(
+            ClaudeModel::from_env(),
+            Ok(ClaudeModel::Claude3Opus20240229)
+        );
+
+        std::env::set_var("ANTHROPIC_MODEL", "claude-3-5-sonnet-20241022");
+        assert_eq!(
+            ClaudeModel::from_env(),
+            Ok(ClaudeModel::Claude35Sonnet20241022)
+        );
+
+        std::env::set_var("ANTHROPIC_MODEL", "invalid");
+        assert_eq!(
+            ClaudeModel::from_env(),
+            Err(ModelFromEnvError::Invalid {
+                value: "invalid".to_string(),
+                source: ModelParseError {
+                    value: "invalid".to_string(),
+                },
+            })
+        );
+        assert_eq!(
+            ClaudeModel::from_env_or(ClaudeModel::Claude3Haiku20240307),
+            ClaudeModel::Claude3Haiku20240307
+        );
+
+        std::env::remove_var("ANTHROPIC_MODEL");
+        std::env::remove_var("CLAUDE_MODEL");
+    }
 }
@@ -1,5 +1,8 @@
+use std::borrow::Cow;
 use std::fmt::Display;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+use base64::Engine;
 
 use crate::macros::{
     impl_display_for_serialize, impl_enum_string_serialization,
@@ -7,7 +10,8 @@ use crate::macros::{
     impl_enum_with_string_or_array_serialization,
 };
 use crate::messages::{
-    ContentFlatteningError, ImageMediaTypeParseError, ToolResult, ToolUse,
+    ContentFlatteningError, ContentIndexError, ImageLoadError,
+    ImageMediaTypeParseError, ToolResult, ToolUse,
 };
 
 /// The content of the message.
@@ -133,6 +137,67 @@ impl Content {
         }
     }
 
+    /// Collects the texts of all text blocks joined by new lines, skipping the other blocks.
+    ///
+    /// Unlike `Content::flatten_into_text`, the texts after the first one or after non-text blocks are also collected.
+    ///
+    /// ## Errors
+    /// - `ContentFlatteningError::Empty` if `Content::MultipleBlocks` has no blocks.
+    /// - `ContentFlatteningError::NotFoundTargetBlock` if there is no text block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ToolUse};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("Let me check the weather."),
+    ///     ContentBlock::from(ToolUse::new("id", "get_weather", serde_json::Value::Null)),
+    ///     ContentBlock::from("It is sunny."),
+    /// ]);
+    /// assert_eq!(
+    ///     content.collect_all_text().unwrap(),
+    ///     "Let me check the weather.\nIt is sunny."
+    /// );
+    /// ```
+    pub fn collect_all_text(&self) -> Result<String, ContentFlatteningError> {
+        self.collect_all_text_with_separator("\n")
+    }
+
+    /// Collects the texts of all text blocks joined by the separator, skipping the other blocks.
+    ///
+    /// ## Arguments
+    /// - `separator` - The separator between the texts.
+    ///
+    /// ## Errors
+    /// - `ContentFlatteningError::Empty` if `Content::MultipleBlocks` has no blocks.
+    /// - `ContentFlatteningError::NotFoundTargetBlock` if there is no text block.
+    pub fn collect_all_text_with_separator(
+        &self,
+        separator: &str,
+    ) -> Result<String, ContentFlatteningError> {
+        match self {
+            | Content::SingleText(text) => Ok(text.clone()),
+            | Content::MultipleBlocks(blocks) if blocks.is_empty() => {
+                Err(ContentFlatteningError::Empty)
+            },
+            | Content::MultipleBlocks(blocks) => {
+                let texts = blocks
+                    .iter()
+                    .filter_map(|block| match block {
+                        | ContentBlock::Text(text) => Some(text.text.as_str()),
+                        | _ => None,
+                    })
+                    .collect::<Vec<_>>();
+
+                if texts.is_empty() {
+                    Err(ContentFlatteningError::NotFoundTargetBlock)
+                } else {
+                    Ok(texts.join(separator))
+                }
+            },
+        }
+    }
+
     /// Flattens the content into a single image source.
     /// - `Content::SingleText` => Returns "`Err(NotFoundTargetBlock)`"
     /// - `Content::MultipleBlock` =>
@@ -202,1562 +267,4572 @@ impl Content {
             },
         }
     }
-}
-
-/// The content block of the message.
-#[derive(Debug, Clone, PartialEq)]
-pub enum ContentBlock {
-    /// The text content block.
-    Text(TextContentBlock),
-    /// The image content block.
-    Image(ImageContentBlock),
-    /// The tool use content block.
-    ToolUse(ToolUseContentBlock),
-    /// The tool result content block.
-    ToolResult(ToolResultContentBlock),
-}
-
-impl Default for ContentBlock {
-    fn default() -> Self {
-        Self::Text(TextContentBlock::default())
-    }
-}
 
-impl From<String> for ContentBlock {
-    fn from(text: String) -> Self {
-        Self::Text(TextContentBlock::new(text))
+    /// Gets the only tool use in the content.
+    ///
+    /// ## Errors
+    /// - `ContentFlatteningError::Empty` if the multiple content blocks is empty.
+    /// - `ContentFlatteningError::NotFoundTargetBlock` if there is no tool use block.
+    /// - `ContentFlatteningError::MultipleBlocks` if there are multiple tool use blocks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ContentFlatteningError, ToolUse};
+    ///
+    /// let tool_use = ToolUse::new("id", "name", serde_json::Value::Null);
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("text"),
+    ///     ContentBlock::from(tool_use.clone()),
+    /// ]);
+    /// assert_eq!(content.try_single_tool_use(), Ok(&tool_use));
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from(tool_use.clone()),
+    ///     ContentBlock::from(tool_use.clone()),
+    /// ]);
+    /// assert_eq!(content.try_single_tool_use(), Err(ContentFlatteningError::MultipleBlocks));
+    /// ```
+    pub fn try_single_tool_use(
+        &self
+    ) -> Result<&ToolUse, ContentFlatteningError> {
+        match self.try_single_of_any_type(|block| {
+            matches!(block, ContentBlock::ToolUse(_))
+        })? {
+            | ContentBlock::ToolUse(tool_use) => Ok(&tool_use.tool_use),
+            | _ => Err(ContentFlatteningError::NotFoundTargetBlock),
+        }
     }
-}
 
-impl From<&str> for ContentBlock {
-    fn from(text: &str) -> Self {
-        Self::Text(TextContentBlock::new(text))
+    /// Gets the only content block that satisfies the predicate.
+    ///
+    /// ## Arguments
+    /// - `predicate` - The predicate that returns `true` for the target block.
+    ///
+    /// ## Errors
+    /// - `ContentFlatteningError::Empty` if the multiple content blocks is empty.
+    /// - `ContentFlatteningError::NotFoundTargetBlock` if there is no target block or the content is `Content::SingleText`.
+    /// - `ContentFlatteningError::MultipleBlocks` if there are multiple target blocks.
+    pub fn try_single_of_any_type<F>(
+        &self,
+        predicate: F,
+    ) -> Result<&ContentBlock, ContentFlatteningError>
+    where
+        F: Fn(&ContentBlock) -> bool,
+    {
+        match self {
+            | Content::SingleText(_) => {
+                Err(ContentFlatteningError::NotFoundTargetBlock)
+            },
+            | Content::MultipleBlocks(blocks) if blocks.is_empty() => {
+                Err(ContentFlatteningError::Empty)
+            },
+            | Content::MultipleBlocks(blocks) => {
+                let mut targets = blocks
+                    .iter()
+                    .filter(|block| predicate(block));
+
+                match (targets.next(), targets.next()) {
+                    | (Some(target), None) => Ok(target),
+                    | (Some(_), Some(_)) => {
+                        Err(ContentFlatteningError::MultipleBlocks)
+                    },
+                    | (None, _) => {
+                        Err(ContentFlatteningError::NotFoundTargetBlock)
+                    },
+                }
+            },
+        }
     }
-}
 
-impl From<ImageContentSource> for ContentBlock {
-    fn from(image: ImageContentSource) -> Self {
-        Self::Image(ImageContentBlock::new(image))
+    /// Converts the content into a single text.
+    /// - `Content::SingleText` => Returns "`Some(text)`"
+    /// - `Content::MultipleBlocks` =>
+    ///     - Has only one `ContentBlock::Text` => Returns "`Some(text)`"
+    ///     - Otherwise => Returns "`None`".
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// assert_eq!(Content::from("text").into_single_text(), Some("text".to_string()));
+    /// assert_eq!(Content::from(vec![ContentBlock::from("text")]).into_single_text(), Some("text".to_string()));
+    /// assert_eq!(Content::from(vec![ContentBlock::from("first"), ContentBlock::from("second")]).into_single_text(), None);
+    /// ```
+    pub fn into_single_text(self) -> Option<String> {
+        match self {
+            | Content::SingleText(text) => Some(text),
+            | Content::MultipleBlocks(mut blocks) if blocks.len() == 1 => {
+                match blocks.remove(0) {
+                    | ContentBlock::Text(text) => Some(text.text),
+                    | _ => None,
+                }
+            },
+            | Content::MultipleBlocks(_) => None,
+        }
     }
-}
 
-impl From<ToolUse> for ContentBlock {
-    fn from(tool_use: ToolUse) -> Self {
-        Self::ToolUse(tool_use.into())
+    /// Borrows the content as a single text.
+    /// - `Content::SingleText` => Returns "`Some(text)`"
+    /// - `Content::MultipleBlocks` =>
+    ///     - Has only one `ContentBlock::Text` => Returns "`Some(text)`"
+    ///     - Otherwise => Returns "`None`".
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ImageContentSource, ImageMediaType};
+    ///
+    /// assert_eq!(Content::from("text").as_single_text(), Some("text"));
+    /// assert_eq!(Content::from(ImageContentSource::base64(ImageMediaType::Png, "data")).as_single_text(), None);
+    /// ```
+    pub fn as_single_text(&self) -> Option<&str> {
+        match self {
+            | Content::SingleText(text) => Some(text),
+            | Content::MultipleBlocks(blocks) => match blocks.as_slice() {
+                | [ContentBlock::Text(text)] => Some(&text.text),
+                | _ => None,
+            },
+        }
     }
-}
 
-impl From<ToolResult> for ContentBlock {
-    fn from(tool_result: ToolResult) -> Self {
-        Self::ToolResult(tool_result.into())
+    /// The text of the first text block regardless of its position among the blocks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ToolUse};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from(ToolUse::new("id", "name", serde_json::Value::Null)),
+    ///     ContentBlock::from("first"),
+    ///     ContentBlock::from("last"),
+    /// ]);
+    /// assert_eq!(content.first_text(), Some("first"));
+    /// assert_eq!(content.last_text(), Some("last"));
+    /// ```
+    pub fn first_text(&self) -> Option<&str> {
+        match self {
+            | Content::SingleText(text) => Some(text),
+            | Content::MultipleBlocks(blocks) => blocks
+                .iter()
+                .find_map(text_of_block),
+        }
     }
-}
-
-impl_enum_struct_serialization!(
-    ContentBlock,
-    type,
-    Text(TextContentBlock, "text"),
-    Image(ImageContentBlock, "image"),
-    ToolUse(ToolUseContentBlock, "tool_use"),
-    ToolResult(ToolResultContentBlock, "tool_result")
-);
-
-impl_display_for_serialize!(ContentBlock);
-
-/// The text content block.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct TextContentBlock {
-    /// The content type. It is always `text`.
-    #[serde(rename = "type")]
-    pub _type: ContentType,
-    /// The text content.
-    pub text: String,
-}
 
-impl Default for TextContentBlock {
-    fn default() -> Self {
-        Self {
-            _type: ContentType::Text,
-            text: String::new(),
+    /// The text of the last text block regardless of its position among the blocks.
+    pub fn last_text(&self) -> Option<&str> {
+        match self {
+            | Content::SingleText(text) => Some(text),
+            | Content::MultipleBlocks(blocks) => blocks
+                .iter()
+                .rev()
+                .find_map(text_of_block),
         }
     }
-}
 
-impl_display_for_serialize!(TextContentBlock);
+    /// Replaces the content block at the index and returns the replaced block.
+    ///
+    /// `Content::SingleText` is treated as a single text block and converted into `Content::MultipleBlocks`.
+    ///
+    /// ## Arguments
+    /// - `index` - The index of the block to replace.
+    /// - `block` - The new block.
+    ///
+    /// ## Errors
+    /// It returns `ContentIndexError::OutOfBounds` if the index is out of bounds.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let mut content = Content::from(vec![ContentBlock::from("first"), ContentBlock::from("second")]);
+    /// let replaced = content.replace_block_at(1, ContentBlock::from("replaced")).unwrap();
+    ///
+    /// assert_eq!(replaced, ContentBlock::from("second"));
+    /// assert_eq!(content, Content::from(vec![ContentBlock::from("first"), ContentBlock::from("replaced")]));
+    /// ```
+    pub fn replace_block_at(
+        &mut self,
+        index: usize,
+        block: ContentBlock,
+    ) -> Result<ContentBlock, ContentIndexError> {
+        let len = self.blocks_len();
+        if index >= len {
+            return Err(ContentIndexError::OutOfBounds {
+                index,
+                len,
+            });
+        }
 
-impl From<String> for TextContentBlock {
-    fn from(text: String) -> Self {
-        Self::new(text)
+        Ok(std::mem::replace(
+            &mut self.blocks_mut()[index],
+            block,
+        ))
     }
-}
 
-impl From<&str> for TextContentBlock {
-    fn from(text: &str) -> Self {
-        Self::new(text)
+    /// Removes the content block at the index and returns it.
+    ///
+    /// `Content::SingleText` is treated as a single text block and converted into `Content::MultipleBlocks`.
+    ///
+    /// ## Arguments
+    /// - `index` - The index of the block to remove.
+    pub fn remove_block_at(
+        &mut self,
+        index: usize,
+    ) -> Option<ContentBlock> {
+        if index >= self.blocks_len() {
+            return None;
+        }
+
+        Some(self.blocks_mut().remove(index))
     }
-}
 
-impl TextContentBlock {
-    /// Creates a new text content block.
-    pub fn new<S>(text: S) -> Self
-    where
-        S: Into<String>,
-    {
-        Self {
-            _type: ContentType::Text,
-            text: text.into(),
+    /// Retains only the content blocks specified by the predicate.
+    ///
+    /// `Content::SingleText` is treated as a single text block and stays `Content::SingleText` if the text block is retained.
+    ///
+    /// ## Arguments
+    /// - `predicate` - The predicate that returns `true` for the blocks to retain.
+    pub fn retain_blocks(
+        &mut self,
+        predicate: impl Fn(&ContentBlock) -> bool,
+    ) {
+        let is_single_text = matches!(self, Content::SingleText(_));
+
+        let blocks = self.blocks_mut();
+        blocks.retain(predicate);
+
+        if is_single_text {
+            if let [ContentBlock::Text(text)] = blocks.as_mut_slice() {
+                let text = std::mem::take(&mut text.text);
+                *self = Content::SingleText(text);
+            }
         }
     }
-}
 
-/// The image content block.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct ImageContentBlock {
-    /// The content type. It is always `image`.
-    #[serde(rename = "type")]
-    pub _type: ContentType,
-    /// The image content source.
-    pub source: ImageContentSource,
-}
+    /// Splits this content into two at the block index.
+    ///
+    /// The first content has the blocks before the index and the second one has the rest.
+    /// A part that has only one text block becomes `Content::SingleText`, and an empty part becomes `Content::MultipleBlocks` without blocks.
+    /// `Content::SingleText` is treated as a single text block, and an index out of bounds is clamped so that the second part becomes empty.
+    ///
+    /// ## Arguments
+    /// - `index` - The block index to split at.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ImageContentSource, ImageMediaType};
+    ///
+    /// let image = ContentBlock::from(ImageContentSource::base64(ImageMediaType::Png, "base64"));
+    /// let content = Content::from(vec![ContentBlock::from("text"), image.clone()]);
+    ///
+    /// let (first, second) = content.split_at(1);
+    /// assert_eq!(first, Content::from("text"));
+    /// assert_eq!(second, Content::from(vec![image]));
+    /// ```
+    pub fn split_at(
+        &self,
+        index: usize,
+    ) -> (Content, Content) {
+        let empty = || Content::MultipleBlocks(Vec::new());
 
-impl Default for ImageContentBlock {
-    fn default() -> Self {
-        Self {
-            _type: ContentType::Image,
-            source: ImageContentSource::default(),
+        match self {
+            | Content::SingleText(_) if index == 0 => (empty(), self.clone()),
+            | Content::SingleText(_) => (self.clone(), empty()),
+            | Content::MultipleBlocks(blocks) => {
+                let (first, second) =
+                    blocks.split_at(index.min(blocks.len()));
+                (
+                    Self::from_split_blocks(first),
+                    Self::from_split_blocks(second),
+                )
+            },
         }
     }
-}
 
-impl_display_for_serialize!(ImageContentBlock);
+    /// Creates a copy of this content with all occurrences of the text replaced in the text blocks.
+    ///
+    /// ## Arguments
+    /// - `from` - The text to be replaced. Nothing is replaced if it is empty.
+    /// - `to` - The replacement text.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Content;
+    ///
+    /// let template = Content::from("Hello, {name}! Bye, {name}!");
+    /// assert_eq!(
+    ///     template.replace_all_text("{name}", "Claude"),
+    ///     Content::from("Hello, Claude! Bye, Claude!")
+    /// );
+    /// ```
+    pub fn replace_all_text(
+        &self,
+        from: &str,
+        to: &str,
+    ) -> Content {
+        if from.is_empty() {
+            return self.clone();
+        }
 
-impl From<ImageContentSource> for ImageContentBlock {
-    fn from(source: ImageContentSource) -> Self {
-        Self::new(source)
+        self.replace_texts(|text| text.replace(from, to))
     }
-}
 
-impl ImageContentBlock {
-    /// Creates a new image content block.
-    pub fn new(source: ImageContentSource) -> Self {
-        Self {
-            _type: ContentType::Image,
-            source,
+    /// Creates a copy of this content with all matches of the regular expression replaced in the text blocks.
+    ///
+    /// ## Arguments
+    /// - `pattern` - The regular expression to be replaced.
+    /// - `replacement` - The replacement, which can refer to the capture groups, e.g. `$1`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Content;
+    /// use regex::Regex;
+    ///
+    /// let content = Content::from("2024-10-16");
+    /// let pattern = Regex::new(r"(\d+)-(\d+)-(\d+)").unwrap();
+    /// assert_eq!(
+    ///     content.replace_all_regex(&pattern, "$3/$2/$1"),
+    ///     Content::from("16/10/2024")
+    /// );
+    /// ```
+    #[cfg(feature = "regex")]
+    pub fn replace_all_regex(
+        &self,
+        pattern: &regex::Regex,
+        replacement: &str,
+    ) -> Content {
+        self.replace_texts(|text| {
+            pattern
+                .replace_all(text, replacement)
+                .into_owned()
+        })
+    }
+
+    /// Sorts the content blocks by `ContentBlock::display_priority`.
+    ///
+    /// The sort is stable, so the blocks of the same priority keep their relative order.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ToolUse};
+    ///
+    /// let mut content = Content::from(vec![
+    ///     ContentBlock::from(ToolUse::new("id", "name", serde_json::Value::Null)),
+    ///     ContentBlock::from("first"),
+    ///     ContentBlock::from("second"),
+    /// ]);
+    /// content.sort_by_display_priority();
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     Content::from(vec![
+    ///         ContentBlock::from("first"),
+    ///         ContentBlock::from("second"),
+    ///         ContentBlock::from(ToolUse::new("id", "name", serde_json::Value::Null)),
+    ///     ])
+    /// );
+    /// ```
+    pub fn sort_by_display_priority(&mut self) {
+        if let Content::MultipleBlocks(blocks) = self {
+            blocks.sort_by_key(ContentBlock::display_priority);
         }
     }
-}
 
-/// The content type of the message.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum ContentType {
-    /// text
-    Text,
-    /// image
-    Image,
-    /// text_delta
-    TextDelta,
-    /// tool_use
-    ToolUse,
-    /// tool_result
-    ToolResult,
-}
+    /// Creates a copy of this content sorted by `ContentBlock::display_priority`.
+    pub fn sorted_for_display(&self) -> Content {
+        let mut content = self.clone();
+        content.sort_by_display_priority();
+        content
+    }
 
-impl Default for ContentType {
-    fn default() -> Self {
-        Self::Text
+    /// Creates a copy of this content without image blocks.
+    pub fn without_images(&self) -> Content {
+        let mut content = self.clone();
+        content.retain_blocks(|block| !matches!(block, ContentBlock::Image(_)));
+        content
     }
-}
 
-impl Display for ContentType {
-    fn fmt(
+    /// Creates a copy of this content without tool use and tool result blocks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ToolUse};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("text"),
+    ///     ContentBlock::from(ToolUse::new("id", "name", serde_json::Value::Null)),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     content.without_tool_blocks(),
+    ///     Content::from(vec![ContentBlock::from("text")])
+    /// );
+    /// ```
+    pub fn without_tool_blocks(&self) -> Content {
+        let mut content = self.clone();
+        content.retain_blocks(|block| {
+            !matches!(
+                block,
+                ContentBlock::ToolUse(_) | ContentBlock::ToolResult(_)
+            )
+        });
+        content
+    }
+
+    /// Whether any of the content blocks satisfies the predicate.
+    ///
+    /// `Content::SingleText` is checked as a single text block, which clones the text for the predicate.
+    ///
+    /// ## Arguments
+    /// - `predicate` - The predicate for a content block.
+    pub fn contains_block(
         &self,
-        f: &mut std::fmt::Formatter<'_>,
-    ) -> std::fmt::Result {
+        predicate: impl Fn(&ContentBlock) -> bool,
+    ) -> bool {
         match self {
-            | ContentType::Text => {
-                write!(f, "text")
-            },
-            | ContentType::Image => {
-                write!(f, "image")
-            },
-            | ContentType::TextDelta => {
-                write!(f, "text_delta")
-            },
-            | ContentType::ToolUse => {
-                write!(f, "tool_use")
-            },
-            | ContentType::ToolResult => {
-                write!(f, "tool_result")
+            | Content::SingleText(text) => {
+                predicate(&ContentBlock::from(text.as_str()))
             },
+            | Content::MultipleBlocks(blocks) => blocks.iter().any(predicate),
         }
     }
-}
 
-impl_enum_string_serialization!(
-    ContentType,
-    Text => "text",
-    Image => "image",
-    TextDelta => "text_delta",
-    ToolUse => "tool_use",
-    ToolResult => "tool_result"
-);
-
-/// The image content source.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct ImageContentSource {
-    /// The source type.
-    #[serde(rename = "type")]
-    pub _type: ImageSourceType,
-    /// The media type.
-    pub media_type: ImageMediaType,
-    ///  The data of the image.
-    pub data: String,
-}
+    /// Finds the first content block that satisfies the predicate.
+    ///
+    /// It returns `None` for `Content::SingleText` that has no blocks to borrow as `Content::blocks`,
+    /// use `Content::contains_block` or `Content::nth_block` to check the text as a block.
+    ///
+    /// ## Arguments
+    /// - `predicate` - The predicate for a content block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ToolUse};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("text"),
+    ///     ContentBlock::from(ToolUse::new("id", "get_weather", serde_json::json!({}))),
+    /// ]);
+    ///
+    /// let block = content.find_block(|block| match block {
+    ///     | ContentBlock::ToolUse(tool_use) => tool_use.tool_use.is_named("get_weather"),
+    ///     | _ => false,
+    /// });
+    /// assert!(block.is_some());
+    /// assert!(content.contains_block(|block| matches!(block, ContentBlock::Text(_))));
+    /// ```
+    pub fn find_block(
+        &self,
+        predicate: impl Fn(&ContentBlock) -> bool,
+    ) -> Option<&ContentBlock> {
+        self.blocks()
+            .iter()
+            .find(|block| predicate(block))
+    }
 
-impl Default for ImageContentSource {
-    fn default() -> Self {
-        Self {
-            _type: ImageSourceType::default(),
-            media_type: ImageMediaType::default(),
-            data: String::new(),
+    /// Whether the content has any text.
+    ///
+    /// `Content::SingleText` always has text.
+    pub fn has_text(&self) -> bool {
+        match self {
+            | Content::SingleText(_) => true,
+            | Content::MultipleBlocks(blocks) => blocks
+                .iter()
+                .any(|block| matches!(block, ContentBlock::Text(_))),
         }
     }
-}
 
-impl_display_for_serialize!(ImageContentSource);
+    /// Whether the content has any image blocks.
+    pub fn has_images(&self) -> bool {
+        self.blocks()
+            .iter()
+            .any(|block| matches!(block, ContentBlock::Image(_)))
+    }
 
-impl ImageContentSource {
-    /// Creates a new image content source from Base64 encoded image data.
+    /// Whether the content has any tool use blocks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ToolUse};
+    ///
+    /// let content = Content::from(ToolUse::new("id", "name", serde_json::Value::Null));
+    /// assert!(content.has_tool_uses());
+    /// assert!(!Content::from("text").has_tool_uses());
+    /// ```
+    pub fn has_tool_uses(&self) -> bool {
+        self.blocks()
+            .iter()
+            .any(|block| matches!(block, ContentBlock::ToolUse(_)))
+    }
+
+    /// Whether the content has any tool result blocks.
+    pub fn has_tool_results(&self) -> bool {
+        self.blocks()
+            .iter()
+            .any(|block| matches!(block, ContentBlock::ToolResult(_)))
+    }
+
+    /// The number of characters in all text blocks.
+    ///
+    /// Non-text blocks are not counted.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Content;
+    ///
+    /// let content = Content::from("Hello, world!\nこんにちは");
+    /// assert_eq!(content.count_characters(), 19);
+    /// assert_eq!(content.word_count(), 3);
+    /// assert_eq!(content.line_count(), 2);
+    /// ```
+    pub fn count_characters(&self) -> usize {
+        self.map_texts(|text| text.chars().count())
+    }
+
+    /// The approximate number of tokens of the image blocks, 1,000 tokens per image.
+    ///
+    /// **This is a rough estimate**, the actual number of tokens depends on the resolution of each image.
+    pub fn approximate_image_token_count(&self) -> u32 {
+        let image_count = self
+            .blocks()
+            .iter()
+            .filter(|block| matches!(block, ContentBlock::Image(_)))
+            .count();
+
+        u32::try_from(image_count)
+            .unwrap_or(u32::MAX)
+            .saturating_mul(1000)
+    }
+
+    /// The approximate number of tokens of this content, 1,000 tokens per image and 1 token per 4 characters of the texts.
+    ///
+    /// **This is a rough estimate**, the actual number of tokens can differ significantly by the language and the tokenizer.
+    /// Use the `usage` of the response for the actual number.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ImageContentSource, ImageMediaType};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("Describe this image."),
+    ///     ContentBlock::from(ImageContentSource::base64(ImageMediaType::Png, "base64")),
+    /// ]);
+    /// assert_eq!(content.approximate_image_token_count(), 1000);
+    /// assert_eq!(content.approximate_total_token_count(), 1005);
+    /// ```
+    pub fn approximate_total_token_count(&self) -> u32 {
+        let text_token_count =
+            u32::try_from(self.count_characters() / 4).unwrap_or(u32::MAX);
+
+        self.approximate_image_token_count()
+            .saturating_add(text_token_count)
+    }
+
+    /// The number of whitespace-separated words in all text blocks.
+    ///
+    /// Non-text blocks are not counted.
+    pub fn word_count(&self) -> usize {
+        self.map_texts(|text| {
+            text.split_whitespace()
+                .count()
+        })
+    }
+
+    /// The number of lines in all text blocks.
+    ///
+    /// Non-text blocks are not counted.
+    pub fn line_count(&self) -> usize {
+        self.map_texts(|text| text.lines().count())
+    }
+
+    /// Whether the concatenated text of all text blocks contains the substring.
     ///
     /// ## Arguments
-    /// - `media_type` - The media type of the image.
-    /// - `data` - The data of the image.
-    pub fn base64<S>(
-        media_type: ImageMediaType,
-        data: S,
-    ) -> Self
-    where
-        S: Into<String>,
-    {
-        Self {
-            _type: ImageSourceType::Base64,
-            media_type,
-            data: data.into(),
-        }
+    /// - `needle` - The substring to search.
+    pub fn contains_str(
+        &self,
+        needle: &str,
+    ) -> bool {
+        !self
+            .search_text(needle)
+            .is_empty()
     }
-}
 
-/// The source type of the image.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum ImageSourceType {
-    /// base64
-    Base64,
-}
+    /// Searches the pattern in the concatenated text of all text blocks.
+    ///
+    /// It returns the byte offset ranges `(start, end)` of non-overlapping matches.
+    ///
+    /// ## Arguments
+    /// - `pattern` - The pattern to search.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("Hello, "),
+    ///     ContentBlock::from("world! Hello!"),
+    /// ]);
+    ///
+    /// assert_eq!(content.search_text("Hello"), vec![(0, 5), (14, 19)]);
+    /// assert!(content.contains_str("world"));
+    /// assert!(content.starts_with_text("Hello, world"));
+    /// assert!(content.ends_with_text("Hello!"));
+    /// ```
+    pub fn search_text(
+        &self,
+        pattern: &str,
+    ) -> Vec<(usize, usize)> {
+        self.concatenated_text()
+            .match_indices(pattern)
+            .map(|(start, matched)| (start, start + matched.len()))
+            .collect()
+    }
 
-impl Default for ImageSourceType {
-    fn default() -> Self {
-        Self::Base64
+    /// Whether the concatenated text of all text blocks starts with the prefix.
+    ///
+    /// ## Arguments
+    /// - `prefix` - The prefix.
+    pub fn starts_with_text(
+        &self,
+        prefix: &str,
+    ) -> bool {
+        self.concatenated_text()
+            .starts_with(prefix)
     }
-}
 
-impl Display for ImageSourceType {
-    fn fmt(
+    /// Whether the concatenated text of all text blocks ends with the suffix.
+    ///
+    /// ## Arguments
+    /// - `suffix` - The suffix.
+    pub fn ends_with_text(
         &self,
-        f: &mut std::fmt::Formatter<'_>,
-    ) -> std::fmt::Result {
+        suffix: &str,
+    ) -> bool {
+        self.concatenated_text()
+            .ends_with(suffix)
+    }
+
+    /// The content block at the index.
+    ///
+    /// `Content::SingleText` is treated as a single owned text block at the index `0`, which clones the text.
+    /// Use `Content::blocks` to borrow the blocks of `Content::MultipleBlocks` without cloning.
+    ///
+    /// ## Arguments
+    /// - `index` - The index of the block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let content = Content::from(vec![ContentBlock::from("first"), ContentBlock::from("second")]);
+    /// assert_eq!(content.nth_block(1).as_deref(), Some(&ContentBlock::from("second")));
+    /// assert_eq!(content.nth_block(2), None);
+    ///
+    /// let content = Content::from("text");
+    /// assert_eq!(content.nth_block(0).as_deref(), Some(&ContentBlock::from("text")));
+    /// ```
+    pub fn nth_block(
+        &self,
+        index: usize,
+    ) -> Option<Cow<'_, ContentBlock>> {
         match self {
-            | ImageSourceType::Base64 => {
-                write!(f, "base64")
+            | Content::SingleText(text) if index == 0 => {
+                Some(Cow::Owned(ContentBlock::from(text.as_str())))
             },
+            | Content::SingleText(_) => None,
+            | Content::MultipleBlocks(blocks) => blocks
+                .get(index)
+                .map(Cow::Borrowed),
         }
     }
-}
 
-impl_enum_string_serialization!(
-    ImageSourceType,
-    Base64 => "base64"
-);
+    /// The content blocks.
+    ///
+    /// It returns an empty slice for `Content::SingleText`, use `Content::nth_block` or `Content::get_text_at` to access the text.
+    pub fn blocks(&self) -> &[ContentBlock] {
+        match self {
+            | Content::SingleText(_) => &[],
+            | Content::MultipleBlocks(blocks) => blocks,
+        }
+    }
 
-/// The media type of the image.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum ImageMediaType {
-    /// image/jpeg
-    Jpeg,
-    /// image/png
-    Png,
-    /// image/gif
-    Gif,
-    /// image/webp
-    Webp,
-}
+    /// Iterates over the content blocks with their indices, e.g. to match the `index` of the stream chunks.
+    ///
+    /// `Content::SingleText` yields a single text block at the index `0` as `Content::nth_block`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let content = Content::from(vec![ContentBlock::from("first"), ContentBlock::from("second")]);
+    /// let indexed = content
+    ///     .iter_indexed()
+    ///     .map(|(index, block)| (index, block.into_owned()))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     indexed,
+    ///     vec![(0, ContentBlock::from("first")), (1, ContentBlock::from("second"))]
+    /// );
+    /// ```
+    pub fn iter_indexed(
+        &self
+    ) -> impl Iterator<Item = (usize, Cow<'_, ContentBlock>)> + '_ {
+        (0..self.blocks_len()).filter_map(move |index| {
+            self.nth_block(index)
+                .map(|block| (index, block))
+        })
+    }
 
-impl Default for ImageMediaType {
-    fn default() -> Self {
-        Self::Jpeg
+    /// Iterates over the text blocks.
+    ///
+    /// `Content::SingleText` yields a synthetic text block, so the blocks are `Cow` as `Content::nth_block`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ImageContentSource, ImageMediaType};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("first"),
+    ///     ContentBlock::from(ImageContentSource::base64(ImageMediaType::Png, "base64")),
+    ///     ContentBlock::from("second"),
+    /// ]);
+    /// let texts = content
+    ///     .iter_text_blocks()
+    ///     .map(|block| block.text.clone())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(texts, vec!["first", "second"]);
+    /// ```
+    pub fn iter_text_blocks(
+        &self
+    ) -> impl Iterator<Item = Cow<'_, TextContentBlock>> + '_ {
+        let single_text = match self {
+            | Content::SingleText(text) => {
+                Some(Cow::Owned(TextContentBlock::new(text.as_str())))
+            },
+            | Content::MultipleBlocks(_) => None,
+        };
+
+        single_text
+            .into_iter()
+            .chain(
+                self.blocks()
+                    .iter()
+                    .filter_map(|block| match block {
+                        | ContentBlock::Text(text) => Some(Cow::Borrowed(text)),
+                        | _ => None,
+                    }),
+            )
     }
-}
 
-impl Display for ImageMediaType {
-    fn fmt(
+    /// Iterates over the tool uses of the tool use blocks.
+    pub fn iter_tool_uses(&self) -> impl Iterator<Item = &ToolUse> {
+        self.blocks()
+            .iter()
+            .filter_map(|block| match block {
+                | ContentBlock::ToolUse(tool_use) => Some(&tool_use.tool_use),
+                | _ => None,
+            })
+    }
+
+    /// Iterates over the tool results of the tool result blocks.
+    pub fn iter_tool_results(&self) -> impl Iterator<Item = &ToolResult> {
+        self.blocks()
+            .iter()
+            .filter_map(|block| match block {
+                | ContentBlock::ToolResult(tool_result) => {
+                    Some(&tool_result.tool_result)
+                },
+                | _ => None,
+            })
+    }
+
+    /// The content block at the index.
+    ///
+    /// It borrows only from `Content::MultipleBlocks` as `Content::blocks`,
+    /// so it returns `None` for `Content::SingleText` even at the index `0` that `Content::iter_indexed` yields.
+    /// Use `Content::nth_block` to access the text as a block.
+    ///
+    /// ## Arguments
+    /// - `index` - The index of the block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let content = Content::from(vec![ContentBlock::from("first"), ContentBlock::from("second")]);
+    /// assert_eq!(content.block_at_index(1), Some(&ContentBlock::from("second")));
+    /// assert_eq!(content.block_at_index(2), None);
+    ///
+    /// assert_eq!(Content::from("text").block_at_index(0), None);
+    /// ```
+    pub fn block_at_index(
         &self,
-        f: &mut std::fmt::Formatter<'_>,
-    ) -> std::fmt::Result {
+        index: usize,
+    ) -> Option<&ContentBlock> {
+        self.blocks().get(index)
+    }
+
+    /// The text of the block at the index if it is a text block.
+    ///
+    /// `Content::SingleText` is treated as a single text block at the index `0`.
+    ///
+    /// ## Arguments
+    /// - `index` - The index of the block.
+    pub fn get_text_at(
+        &self,
+        index: usize,
+    ) -> Option<&str> {
         match self {
-            | ImageMediaType::Jpeg => {
-                write!(f, "image/jpeg")
-            },
-            | ImageMediaType::Png => {
-                write!(f, "image/png")
-            },
-            | ImageMediaType::Gif => {
-                write!(f, "image/gif")
-            },
-            | ImageMediaType::Webp => {
-                write!(f, "image/webp")
-            },
+            | Content::SingleText(text) if index == 0 => Some(text),
+            | Content::SingleText(_) => None,
+            | Content::MultipleBlocks(blocks) => blocks
+                .get(index)
+                .and_then(text_of_block),
         }
     }
-}
 
-impl_enum_string_serialization!(
-    ImageMediaType,
-    Jpeg => "image/jpeg",
-    Png => "image/png",
-    Gif => "image/gif",
-    Webp => "image/webp"
-);
+    /// Partitions the content blocks by their types in a single pass.
+    ///
+    /// `Content::SingleText` is partitioned into a single owned text block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ToolUse};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("text"),
+    ///     ContentBlock::from(ToolUse::new("id", "name", serde_json::Value::Null)),
+    /// ]);
+    /// let partition = content.partition_by_type();
+    ///
+    /// assert_eq!(partition.texts.len(), 1);
+    /// assert_eq!(partition.tool_uses.len(), 1);
+    /// ```
+    pub fn partition_by_type(&self) -> ContentPartition<'_> {
+        let mut partition = ContentPartition::default();
 
-impl ImageMediaType {
-    /// Creates the media type from the extension of the path.
-    pub fn from_path(path: &PathBuf) -> Result<Self, ImageMediaTypeParseError> {
-        match path
-            .extension()
-            .and_then(|ext| ext.to_str())
-        {
-            | Some("jpeg") | Some("jpg") => Ok(Self::Jpeg),
-            | Some("png") => Ok(Self::Png),
-            | Some("gif") => Ok(Self::Gif),
-            | Some("webp") => Ok(Self::Webp),
-            | Some(extension) => Err(ImageMediaTypeParseError::NotSupported(
-                extension.to_string(),
-            )),
-            | None => Err(ImageMediaTypeParseError::NotFound),
+        match self {
+            | Content::SingleText(text) => {
+                partition
+                    .texts
+                    .push(Cow::Owned(TextContentBlock::new(text.as_str())));
+            },
+            | Content::MultipleBlocks(blocks) => {
+                for block in blocks {
+                    match block {
+                        | ContentBlock::Text(text) => partition
+                            .texts
+                            .push(Cow::Borrowed(text)),
+                        | ContentBlock::Image(image) => partition
+                            .images
+                            .push(image),
+                        | ContentBlock::ToolUse(tool_use) => partition
+                            .tool_uses
+                            .push(tool_use),
+                        | ContentBlock::ToolResult(tool_result) => partition
+                            .tool_results
+                            .push(tool_result),
+                        | ContentBlock::Document(document) => partition
+                            .documents
+                            .push(document),
+                    }
+                }
+            },
         }
+
+        partition
     }
-}
 
-/// The tool use content block.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct ToolUseContentBlock {
-    /// The content type. It is always `tool_use`.
-    #[serde(rename = "type")]
-    pub _type: ContentType,
-    /// The tool use.
-    #[serde(flatten)]
-    pub tool_use: ToolUse,
-}
+    /// Partitions the content blocks by their types in a single pass with consuming the content.
+    pub fn into_partition(self) -> OwnedContentPartition {
+        let mut partition = OwnedContentPartition::default();
 
-impl Default for ToolUseContentBlock {
-    fn default() -> Self {
-        Self {
-            _type: ContentType::ToolUse,
-            tool_use: ToolUse::default(),
+        match self {
+            | Content::SingleText(text) => {
+                partition
+                    .texts
+                    .push(TextContentBlock::new(text));
+            },
+            | Content::MultipleBlocks(blocks) => {
+                for block in blocks {
+                    match block {
+                        | ContentBlock::Text(text) => partition
+                            .texts
+                            .push(text),
+                        | ContentBlock::Image(image) => partition
+                            .images
+                            .push(image),
+                        | ContentBlock::ToolUse(tool_use) => partition
+                            .tool_uses
+                            .push(tool_use),
+                        | ContentBlock::ToolResult(tool_result) => partition
+                            .tool_results
+                            .push(tool_result),
+                        | ContentBlock::Document(document) => partition
+                            .documents
+                            .push(document),
+                    }
+                }
+            },
         }
-    }
-}
-
-impl_display_for_serialize!(ToolUseContentBlock);
 
-impl From<ToolUse> for ToolUseContentBlock {
-    fn from(tool_use: ToolUse) -> Self {
-        Self::new(tool_use)
+        partition
     }
-}
 
-impl ToolUseContentBlock {
-    /// Creates a new tool use content block.
-    pub fn new(tool_use: ToolUse) -> Self {
-        Self {
-            _type: ContentType::ToolUse,
-            tool_use,
+    /// Creates a displayable JSON of this content with truncating Base64 data for readability.
+    ///
+    /// The `"data"` strings longer than 64 characters are replaced with `"<BASE64 N bytes>"`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ImageContentSource, ImageMediaType};
+    ///
+    /// let content = Content::from(ImageContentSource::base64(ImageMediaType::Png, "A".repeat(100)));
+    /// assert!(content.debug_display().to_string().contains("<BASE64 100 bytes>"));
+    /// ```
+    pub fn debug_display(&self) -> impl Display + '_ {
+        ContentDebugDisplay {
+            content: self,
         }
     }
-}
 
-/// The tool result content block.
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
-pub struct ToolResultContentBlock {
-    /// The content type. It is always `tool_result`.
+    /// Creates a human-readable display of this content for logging and terminal output.
+    ///
+    /// Text blocks are shown as they are, and the other blocks are shown as placeholders:
+    /// `[IMAGE]`, `[DOCUMENT]`, `[TOOL_USE]` and `[TOOL_RESULT]`, joined by new lines.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ImageContentSource, ImageMediaType};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("Describe this image."),
+    ///     ContentBlock::from(ImageContentSource::base64(ImageMediaType::Png, "base64")),
+    /// ]);
+    /// assert_eq!(
+    ///     content.display_readable().to_string(),
+    ///     "Describe this image.\n[IMAGE]"
+    /// );
+    /// ```
+    pub fn display_readable(&self) -> impl Display + '_ {
+        ContentReadableDisplay {
+            content: self,
+        }
+    }
+
+    /// A compact summary of the block types of this content for logging.
+    ///
+    /// It is `"SingleText"` for `Content::SingleText`,
+    /// or the block types in brackets for `Content::MultipleBlocks` with the tool name of the tool use blocks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, ImageContentSource, ImageMediaType, ToolUse};
+    ///
+    /// let content = Content::from(vec![
+    ///     ContentBlock::from("text"),
+    ///     ContentBlock::from(ImageContentSource::base64(ImageMediaType::Png, "base64")),
+    ///     ContentBlock::from(ToolUse::new("id", "get_weather", serde_json::Value::Null)),
+    /// ]);
+    /// assert_eq!(content.type_summary(), "[Text, Image, ToolUse(get_weather)]");
+    /// assert_eq!(Content::from("text").type_summary(), "SingleText");
+    /// ```
+    pub fn type_summary(&self) -> String {
+        match self {
+            | Content::SingleText(_) => "SingleText".to_string(),
+            | Content::MultipleBlocks(blocks) => format!(
+                "[{}]",
+                blocks
+                    .iter()
+                    .map(|block| match block {
+                        | ContentBlock::Text(_) => "Text".to_string(),
+                        | ContentBlock::Image(_) => "Image".to_string(),
+                        | ContentBlock::Document(_) => "Document".to_string(),
+                        | ContentBlock::ToolUse(tool_use) => {
+                            format!("ToolUse({})", tool_use.tool_use.name)
+                        },
+                        | ContentBlock::ToolResult(_) => {
+                            "ToolResult".to_string()
+                        },
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    /// Parses the content from the raw JSON string.
+    ///
+    /// ## Arguments
+    /// - `json` - The JSON string.
+    ///
+    /// ## Errors
+    /// It returns an error if the JSON is invalid as `Content`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// assert_eq!(Content::from_json(r#""text""#).unwrap(), Content::from("text"));
+    /// assert_eq!(
+    ///     Content::from_json(r#"[{"type":"text","text":"text"}]"#).unwrap(),
+    ///     Content::from(vec![ContentBlock::from("text")])
+    /// );
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Creates a content with multiple blocks from the raw JSON values of the blocks.
+    ///
+    /// ## Arguments
+    /// - `array` - The JSON values of the content blocks.
+    ///
+    /// ## Errors
+    /// It returns an error if any value is not a valid content block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let content = Content::from_json_array(vec![
+    ///     serde_json::json!({"type": "text", "text": "text"}),
+    /// ])
+    /// .unwrap();
+    /// assert_eq!(content, Content::from(vec![ContentBlock::from("text")]));
+    /// ```
+    pub fn from_json_array(
+        array: Vec<serde_json::Value>
+    ) -> Result<Self, serde_json::Error> {
+        array
+            .into_iter()
+            .map(ContentBlock::from_json_value)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Content::MultipleBlocks)
+    }
+
+    /// Creates a content with multiple text blocks, each from a part.
+    ///
+    /// Unlike `Content::from("text")` that creates `Content::SingleText`, this always creates `Content::MultipleBlocks`.
+    ///
+    /// ## Arguments
+    /// - `parts` - The text parts.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock};
+    ///
+    /// let content = Content::from_text_parts(["first", "second"]);
+    /// assert_eq!(
+    ///     content,
+    ///     Content::from(vec![ContentBlock::from("first"), ContentBlock::from("second")])
+    /// );
+    /// ```
+    pub fn from_text_parts<I, S>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Content::MultipleBlocks(
+            parts
+                .into_iter()
+                .map(|part| ContentBlock::Text(TextContentBlock::new(part)))
+                .collect(),
+        )
+    }
+
+    /// Creates a content with text and image blocks alternately, starting from a text.
+    ///
+    /// The rest of the longer one is appended at the end.
+    ///
+    /// ## Arguments
+    /// - `texts` - The texts.
+    /// - `images` - The image sources.
+    pub fn from_text_and_image_alternating(
+        texts: &[&str],
+        images: Vec<ImageContentSource>,
+    ) -> Self {
+        let mut texts = texts.iter();
+        let mut images = images.into_iter();
+        let mut blocks = Vec::new();
+
+        loop {
+            let text = texts.next();
+            let image = images.next();
+            if text.is_none() && image.is_none() {
+                break;
+            }
+
+            if let Some(text) = text {
+                blocks.push(ContentBlock::from(*text));
+            }
+            if let Some(image) = image {
+                blocks.push(ContentBlock::from(image));
+            }
+        }
+
+        Content::MultipleBlocks(blocks)
+    }
+
+    /// Creates a content with an image block loaded from a file.
+    ///
+    /// The media type is detected from the extension of the path and the data is encoded in Base64.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of the image file.
+    ///
+    /// ## Errors
+    /// It returns an error if the extension is not supported or reading the file fails.
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use clust::messages::Content;
+    /// use std::path::Path;
+    ///
+    /// let content = Content::from_image_file(Path::new("image.png")).unwrap();
+    /// ```
+    pub fn from_image_file(path: &Path) -> Result<Self, ImageLoadError> {
+        Self::from_image_files(&[path])
+    }
+
+    /// Creates a content with image blocks loaded from files.
+    ///
+    /// ## Arguments
+    /// - `paths` - The paths of the image files.
+    ///
+    /// ## Errors
+    /// It returns the first error of the files if the extension is not supported or reading the file fails.
+    pub fn from_image_files(paths: &[&Path]) -> Result<Self, ImageLoadError> {
+        let blocks = paths
+            .iter()
+            .map(|path| load_image_block(path))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Content::MultipleBlocks(blocks))
+    }
+
+    /// Concatenates all text blocks without separators.
+    pub(crate) fn concatenated_text(&self) -> Cow<'_, str> {
+        match self {
+            | Content::SingleText(text) => Cow::Borrowed(text),
+            | Content::MultipleBlocks(blocks) => Cow::Owned(
+                blocks
+                    .iter()
+                    .filter_map(|block| match block {
+                        | ContentBlock::Text(text) => Some(text.text.as_str()),
+                        | _ => None,
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Sums the mapped values of all texts.
+    fn map_texts(
+        &self,
+        map: impl Fn(&str) -> usize,
+    ) -> usize {
+        match self {
+            | Content::SingleText(text) => map(text),
+            | Content::MultipleBlocks(blocks) => blocks
+                .iter()
+                .map(|block| match block {
+                    | ContentBlock::Text(text) => map(&text.text),
+                    | _ => 0,
+                })
+                .sum(),
+        }
+    }
+
+    /// The number of content blocks.
+    pub(crate) fn blocks_len(&self) -> usize {
+        match self {
+            | Content::SingleText(_) => 1,
+            | Content::MultipleBlocks(blocks) => blocks.len(),
+        }
+    }
+
+    /// Creates a copy of this content with the texts of the text blocks replaced.
+    fn replace_texts(
+        &self,
+        replace: impl Fn(&str) -> String,
+    ) -> Content {
+        match self {
+            | Content::SingleText(text) => Content::SingleText(replace(text)),
+            | Content::MultipleBlocks(blocks) => Content::MultipleBlocks(
+                blocks
+                    .iter()
+                    .map(|block| match block {
+                        | ContentBlock::Text(text) => {
+                            ContentBlock::Text(TextContentBlock::new(replace(
+                                &text.text,
+                            )))
+                        },
+                        | _ => block.clone(),
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Creates a content from the blocks of a part split by `Content::split_at`.
+    fn from_split_blocks(blocks: &[ContentBlock]) -> Content {
+        match blocks {
+            | [ContentBlock::Text(text)] => {
+                Content::SingleText(text.text.clone())
+            },
+            | _ => Content::MultipleBlocks(blocks.to_vec()),
+        }
+    }
+
+    /// Converts into `Content::MultipleBlocks` and returns the mutable blocks.
+    pub(crate) fn blocks_mut(&mut self) -> &mut Vec<ContentBlock> {
+        if let Content::SingleText(text) = self {
+            *self = Content::MultipleBlocks(vec![ContentBlock::Text(
+                TextContentBlock::new(std::mem::take(text)),
+            )]);
+        }
+
+        match self {
+            | Content::MultipleBlocks(blocks) => blocks,
+            | Content::SingleText(_) => unreachable!(),
+        }
+    }
+}
+
+/// The human-readable display of `Content` created by `Content::display_readable`.
+struct ContentReadableDisplay<'a> {
+    content: &'a Content,
+}
+
+impl Display for ContentReadableDisplay<'_> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        for (index, block) in self
+            .content
+            .blocks()
+            .iter()
+            .enumerate()
+        {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            match block {
+                | ContentBlock::Text(text) => write!(f, "{}", text.text)?,
+                | ContentBlock::Image(_) => write!(f, "[IMAGE]")?,
+                | ContentBlock::Document(_) => write!(f, "[DOCUMENT]")?,
+                | ContentBlock::ToolUse(_) => write!(f, "[TOOL_USE]")?,
+                | ContentBlock::ToolResult(_) => write!(f, "[TOOL_RESULT]")?,
+            }
+        }
+
+        if let Content::SingleText(text) = self.content {
+            write!(f, "{}", text)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The displayable JSON of `Content` with truncated Base64 data created by `Content::debug_display`.
+struct ContentDebugDisplay<'a> {
+    content: &'a Content,
+}
+
+impl Display for ContentDebugDisplay<'_> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let mut json =
+            serde_json::to_value(self.content).map_err(|_| std::fmt::Error)?;
+        truncate_base64_fields(&mut json);
+        let json =
+            serde_json::to_string_pretty(&json).map_err(|_| std::fmt::Error)?;
+        write!(f, "{}", json)
+    }
+}
+
+/// Truncates `"data"` strings in the JSON recursively.
+fn truncate_base64_fields(json: &mut serde_json::Value) {
+    match json {
+        | serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    | serde_json::Value::String(data) if key == "data" => {
+                        if let Some(truncated) = truncate_base64_data(data) {
+                            *data = truncated;
+                        }
+                    },
+                    | _ => truncate_base64_fields(value),
+                }
+            }
+        },
+        | serde_json::Value::Array(values) => {
+            for value in values {
+                truncate_base64_fields(value);
+            }
+        },
+        | _ => {},
+    }
+}
+
+/// Creates the truncated representation of Base64 data if it is longer than 64 characters.
+fn truncate_base64_data(data: &str) -> Option<String> {
+    if data.len() > 64 {
+        Some(format!("<BASE64 {} bytes>", data.len()))
+    } else {
+        None
+    }
+}
+
+/// Loads an image file into an image content block.
+fn load_image_block(path: &Path) -> Result<ContentBlock, ImageLoadError> {
+    let media_type = ImageMediaType::from_path(&path.to_path_buf())?;
+    let data = std::fs::read(path)?;
+    let data = base64::prelude::BASE64_STANDARD.encode(data);
+
+    Ok(ContentBlock::from(
+        ImageContentSource::base64(media_type, data),
+    ))
+}
+
+/// The text of the block if it is a text block.
+fn text_of_block(block: &ContentBlock) -> Option<&str> {
+    match block {
+        | ContentBlock::Text(text) => Some(&text.text),
+        | _ => None,
+    }
+}
+
+/// The content blocks partitioned by their types, created by `Content::partition_by_type`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ContentPartition<'a> {
+    /// The text content blocks.
+    ///
+    /// It is owned only for `Content::SingleText`.
+    pub texts: Vec<Cow<'a, TextContentBlock>>,
+    /// The image content blocks.
+    pub images: Vec<&'a ImageContentBlock>,
+    /// The tool use content blocks.
+    pub tool_uses: Vec<&'a ToolUseContentBlock>,
+    /// The tool result content blocks.
+    pub tool_results: Vec<&'a ToolResultContentBlock>,
+    /// The document content blocks.
+    pub documents: Vec<&'a DocumentContentBlock>,
+}
+
+/// The owned content blocks partitioned by their types, created by `Content::into_partition`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OwnedContentPartition {
+    /// The text content blocks.
+    pub texts: Vec<TextContentBlock>,
+    /// The image content blocks.
+    pub images: Vec<ImageContentBlock>,
+    /// The tool use content blocks.
+    pub tool_uses: Vec<ToolUseContentBlock>,
+    /// The tool result content blocks.
+    pub tool_results: Vec<ToolResultContentBlock>,
+    /// The document content blocks.
+    pub documents: Vec<DocumentContentBlock>,
+}
+
+/// The content block of the message.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentBlock {
+    /// The text content block.
+    Text(TextContentBlock),
+    /// The image content block.
+    Image(ImageContentBlock),
+    /// The tool use content block.
+    ToolUse(ToolUseContentBlock),
+    /// The tool result content block.
+    ToolResult(ToolResultContentBlock),
+    /// The document content block.
+    Document(DocumentContentBlock),
+}
+
+impl Default for ContentBlock {
+    fn default() -> Self {
+        Self::Text(TextContentBlock::default())
+    }
+}
+
+impl From<String> for ContentBlock {
+    fn from(text: String) -> Self {
+        Self::Text(TextContentBlock::new(text))
+    }
+}
+
+impl From<&str> for ContentBlock {
+    fn from(text: &str) -> Self {
+        Self::Text(TextContentBlock::new(text))
+    }
+}
+
+impl From<ImageContentSource> for ContentBlock {
+    fn from(image: ImageContentSource) -> Self {
+        Self::Image(ImageContentBlock::new(image))
+    }
+}
+
+impl From<DocumentContentSource> for ContentBlock {
+    fn from(document: DocumentContentSource) -> Self {
+        Self::Document(DocumentContentBlock::new(document))
+    }
+}
+
+impl From<ToolUse> for ContentBlock {
+    fn from(tool_use: ToolUse) -> Self {
+        Self::ToolUse(tool_use.into())
+    }
+}
+
+impl From<ToolResult> for ContentBlock {
+    fn from(tool_result: ToolResult) -> Self {
+        Self::ToolResult(tool_result.into())
+    }
+}
+
+impl_enum_struct_serialization!(
+    ContentBlock,
+    type,
+    Text(TextContentBlock, "text"),
+    Image(ImageContentBlock, "image"),
+    ToolUse(ToolUseContentBlock, "tool_use"),
+    ToolResult(ToolResultContentBlock, "tool_result"),
+    Document(DocumentContentBlock, "document")
+);
+
+impl_display_for_serialize!(ContentBlock);
+
+impl ContentBlock {
+    /// The priority to display the content block to users, smaller is earlier.
+    ///
+    /// - `0` - Text.
+    /// - `1` - Image and document.
+    /// - `2` - Tool use.
+    /// - `3` - Tool result.
+    pub fn display_priority(&self) -> u8 {
+        match self {
+            | ContentBlock::Text(_) => 0,
+            | ContentBlock::Image(_) | ContentBlock::Document(_) => 1,
+            | ContentBlock::ToolUse(_) => 2,
+            | ContentBlock::ToolResult(_) => 3,
+        }
+    }
+
+    /// Creates a PDF document content block from the bytes of the PDF.
+    ///
+    /// ## Arguments
+    /// - `bytes` - The bytes of the PDF.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ContentBlock, DocumentMediaType};
+    ///
+    /// let block = ContentBlock::from_pdf_bytes(b"%PDF-1.7");
+    /// if let ContentBlock::Document(document) = block {
+    ///     assert_eq!(document.source.media_type, DocumentMediaType::Pdf);
+    ///     assert_eq!(document.source.data, "JVBERi0xLjc=");
+    /// }
+    /// ```
+    pub fn from_pdf_bytes(bytes: &[u8]) -> Self {
+        Self::from(DocumentContentSource::base64(
+            DocumentMediaType::Pdf,
+            base64::prelude::BASE64_STANDARD.encode(bytes),
+        ))
+    }
+
+    /// Creates a PDF document content block from the PDF file.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of the PDF file.
+    ///
+    /// ## Errors
+    /// It returns an error if reading the file fails.
+    pub fn from_pdf_file(path: &Path) -> Result<Self, std::io::Error> {
+        Ok(Self::from_pdf_bytes(&std::fs::read(
+            path,
+        )?))
+    }
+
+    /// Creates a content block from the raw JSON value dispatched by the `type` field.
+    ///
+    /// ## Arguments
+    /// - `value` - The JSON value of the content block.
+    ///
+    /// ## Errors
+    /// It returns an error if the `type` is unknown or the value does not match the type.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ContentBlock;
+    ///
+    /// let value = serde_json::json!({"type": "text", "text": "text"});
+    /// let block = ContentBlock::from_json_value(value.clone()).unwrap();
+    /// assert_eq!(block, ContentBlock::from("text"));
+    /// assert_eq!(block.to_json_value(), value);
+    /// ```
+    pub fn from_json_value(
+        value: serde_json::Value
+    ) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+
+    /// Converts into the raw JSON value.
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::to_value(self)
+            .expect("ContentBlock is always serializable into JSON")
+    }
+}
+
+/// The text content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct TextContentBlock {
+    /// The content type. It is always `text`.
     #[serde(rename = "type")]
     pub _type: ContentType,
-    /// The tool result.
-    #[serde(flatten)]
-    pub tool_result: ToolResult,
+    /// The text content.
+    pub text: String,
+}
+
+impl Default for TextContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::Text,
+            text: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(TextContentBlock);
+
+impl From<String> for TextContentBlock {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
 }
 
-impl Default for ToolResultContentBlock {
-    fn default() -> Self {
-        Self {
+impl From<&str> for TextContentBlock {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+impl TextContentBlock {
+    /// Creates a new text content block.
+    pub fn new<S>(text: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::Text,
+            text: text.into(),
+        }
+    }
+}
+
+/// The image content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ImageContentBlock {
+    /// The content type. It is always `image`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The image content source.
+    pub source: ImageContentSource,
+}
+
+impl Default for ImageContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::Image,
+            source: ImageContentSource::default(),
+        }
+    }
+}
+
+impl_display_for_serialize!(ImageContentBlock);
+
+impl From<ImageContentSource> for ImageContentBlock {
+    fn from(source: ImageContentSource) -> Self {
+        Self::new(source)
+    }
+}
+
+impl ImageContentBlock {
+    /// Creates a new image content block.
+    pub fn new(source: ImageContentSource) -> Self {
+        Self {
+            _type: ContentType::Image,
+            source,
+        }
+    }
+}
+
+/// The content type of the message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// text
+    Text,
+    /// image
+    Image,
+    /// text_delta
+    TextDelta,
+    /// input_json_delta
+    InputJsonDelta,
+    /// tool_use
+    ToolUse,
+    /// tool_result
+    ToolResult,
+    /// document
+    Document,
+}
+
+impl Default for ContentType {
+    fn default() -> Self {
+        Self::Text
+    }
+}
+
+impl Display for ContentType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ContentType::Text => {
+                write!(f, "text")
+            },
+            | ContentType::Image => {
+                write!(f, "image")
+            },
+            | ContentType::TextDelta => {
+                write!(f, "text_delta")
+            },
+            | ContentType::InputJsonDelta => {
+                write!(f, "input_json_delta")
+            },
+            | ContentType::ToolUse => {
+                write!(f, "tool_use")
+            },
+            | ContentType::ToolResult => {
+                write!(f, "tool_result")
+            },
+            | ContentType::Document => {
+                write!(f, "document")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ContentType,
+    Text => "text",
+    Image => "image",
+    TextDelta => "text_delta",
+    InputJsonDelta => "input_json_delta",
+    ToolUse => "tool_use",
+    ToolResult => "tool_result",
+    Document => "document"
+);
+
+/// The image content source.
+///
+/// `Debug` truncates the data longer than 64 characters for readability.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{ImageContentSource, ImageMediaType, ImageSourceType};
+///
+/// let source = ImageContentSource::base64(ImageMediaType::Png, "base64");
+/// assert_eq!(source.source_type(), ImageSourceType::Base64);
+/// assert_eq!(source.to_string(), "{\n  \"type\": \"base64\",\n  \"media_type\": \"image/png\",\n  \"data\": \"base64\"\n}");
+///
+/// let source = ImageContentSource::url("https://example.com/image.png");
+/// assert_eq!(source.source_type(), ImageSourceType::Url);
+/// assert_eq!(source.to_string(), "{\n  \"type\": \"url\",\n  \"url\": \"https://example.com/image.png\"\n}");
+/// ```
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ImageContentSource {
+    /// The data of the image source that determines the source type.
+    pub source_data: ImageSourceData,
+}
+
+impl Default for ImageContentSource {
+    fn default() -> Self {
+        Self {
+            source_data: ImageSourceData::Base64 {
+                media_type: ImageMediaType::default(),
+                data: String::new(),
+            },
+        }
+    }
+}
+
+impl_display_for_serialize!(ImageContentSource);
+
+impl std::fmt::Debug for ImageContentSource {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let mut debug = f.debug_struct("ImageContentSource");
+        debug.field("_type", &self.source_type());
+
+        match &self.source_data {
+            | ImageSourceData::Base64 {
+                media_type,
+                data,
+            } => {
+                debug.field("media_type", media_type);
+                match truncate_base64_data(data) {
+                    | Some(truncated) => {
+                        debug.field("data", &format_args!("{}", truncated))
+                    },
+                    | None => debug.field("data", data),
+                };
+            },
+            | ImageSourceData::Url {
+                url,
+            } => {
+                debug.field("url", url);
+            },
+        }
+
+        debug.finish()
+    }
+}
+
+impl ImageContentSource {
+    /// Creates a new image content source from Base64 encoded image data.
+    ///
+    /// ## Arguments
+    /// - `media_type` - The media type of the image.
+    /// - `data` - The data of the image.
+    pub fn base64<S>(
+        media_type: ImageMediaType,
+        data: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            source_data: ImageSourceData::Base64 {
+                media_type,
+                data: data.into(),
+            },
+        }
+    }
+
+    /// Creates a new image content source from the URL of a publicly accessible image.
+    ///
+    /// ## Arguments
+    /// - `url` - The URL of the image.
+    pub fn url<S>(url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            source_data: ImageSourceData::Url {
+                url: url.into(),
+            },
+        }
+    }
+
+    /// The source type of this image source.
+    pub fn source_type(&self) -> ImageSourceType {
+        match self.source_data {
+            | ImageSourceData::Base64 {
+                ..
+            } => ImageSourceType::Base64,
+            | ImageSourceData::Url {
+                ..
+            } => ImageSourceType::Url,
+        }
+    }
+
+    /// The media type of the Base64 encoded image, or `None` for the URL image.
+    pub fn media_type(&self) -> Option<ImageMediaType> {
+        match &self.source_data {
+            | ImageSourceData::Base64 {
+                media_type,
+                ..
+            } => Some(*media_type),
+            | ImageSourceData::Url {
+                ..
+            } => None,
+        }
+    }
+
+    /// The Base64 encoded data of the image, or `None` for the URL image.
+    pub fn base64_data(&self) -> Option<&str> {
+        match &self.source_data {
+            | ImageSourceData::Base64 {
+                data, ..
+            } => Some(data),
+            | ImageSourceData::Url {
+                ..
+            } => None,
+        }
+    }
+
+    /// The URL of the image, or `None` for the Base64 encoded image.
+    pub fn as_url(&self) -> Option<&str> {
+        match &self.source_data {
+            | ImageSourceData::Base64 {
+                ..
+            } => None,
+            | ImageSourceData::Url {
+                url,
+            } => Some(url),
+        }
+    }
+}
+
+/// The data of the image source for each source type.
+///
+/// It is serialized with the `type` field of `ImageSourceType`.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImageSourceData {
+    /// The Base64 encoded image.
+    Base64 {
+        /// The media type.
+        media_type: ImageMediaType,
+        /// The data of the image.
+        data: String,
+    },
+    /// The publicly accessible image URL.
+    Url {
+        /// The URL of the image.
+        url: String,
+    },
+}
+
+/// The source type of the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageSourceType {
+    /// base64
+    Base64,
+    /// url
+    Url,
+}
+
+impl Default for ImageSourceType {
+    fn default() -> Self {
+        Self::Base64
+    }
+}
+
+impl Display for ImageSourceType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageSourceType::Base64 => {
+                write!(f, "base64")
+            },
+            | ImageSourceType::Url => {
+                write!(f, "url")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageSourceType,
+    Base64 => "base64",
+    Url => "url"
+);
+
+/// The media type of the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImageMediaType {
+    /// image/jpeg
+    Jpeg,
+    /// image/png
+    Png,
+    /// image/gif
+    Gif,
+    /// image/webp
+    Webp,
+}
+
+impl Default for ImageMediaType {
+    fn default() -> Self {
+        Self::Jpeg
+    }
+}
+
+impl Display for ImageMediaType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ImageMediaType::Jpeg => {
+                write!(f, "image/jpeg")
+            },
+            | ImageMediaType::Png => {
+                write!(f, "image/png")
+            },
+            | ImageMediaType::Gif => {
+                write!(f, "image/gif")
+            },
+            | ImageMediaType::Webp => {
+                write!(f, "image/webp")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    ImageMediaType,
+    Jpeg => "image/jpeg",
+    Png => "image/png",
+    Gif => "image/gif",
+    Webp => "image/webp"
+);
+
+impl ImageMediaType {
+    /// Creates the media type from the extension of the path.
+    pub fn from_path(path: &PathBuf) -> Result<Self, ImageMediaTypeParseError> {
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+        {
+            | Some("jpeg") | Some("jpg") => Ok(Self::Jpeg),
+            | Some("png") => Ok(Self::Png),
+            | Some("gif") => Ok(Self::Gif),
+            | Some("webp") => Ok(Self::Webp),
+            | Some(extension) => Err(ImageMediaTypeParseError::NotSupported(
+                extension.to_string(),
+            )),
+            | None => Err(ImageMediaTypeParseError::NotFound),
+        }
+    }
+}
+
+/// The document content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DocumentContentBlock {
+    /// The content type. It is always `document`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The document content source.
+    pub source: DocumentContentSource,
+}
+
+impl Default for DocumentContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::Document,
+            source: DocumentContentSource::default(),
+        }
+    }
+}
+
+impl_display_for_serialize!(DocumentContentBlock);
+
+impl From<DocumentContentSource> for DocumentContentBlock {
+    fn from(source: DocumentContentSource) -> Self {
+        Self::new(source)
+    }
+}
+
+impl DocumentContentBlock {
+    /// Creates a new document content block.
+    pub fn new(source: DocumentContentSource) -> Self {
+        Self {
+            _type: ContentType::Document,
+            source,
+        }
+    }
+}
+
+/// The document content source.
+///
+/// `Debug` truncates the data longer than 64 characters for readability.
+#[derive(Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub struct DocumentContentSource {
+    /// The source type.
+    #[serde(rename = "type")]
+    pub _type: DocumentSourceType,
+    /// The media type.
+    pub media_type: DocumentMediaType,
+    /// The data of the document.
+    pub data: String,
+}
+
+impl_display_for_serialize!(DocumentContentSource);
+
+impl std::fmt::Debug for DocumentContentSource {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match truncate_base64_data(&self.data) {
+            | Some(truncated) => f
+                .debug_struct("DocumentContentSource")
+                .field("_type", &self._type)
+                .field("media_type", &self.media_type)
+                .field("data", &format_args!("{}", truncated))
+                .finish(),
+            | None => f
+                .debug_struct("DocumentContentSource")
+                .field("_type", &self._type)
+                .field("media_type", &self.media_type)
+                .field("data", &self.data)
+                .finish(),
+        }
+    }
+}
+
+impl DocumentContentSource {
+    /// Creates a new document content source from Base64 encoded document data.
+    ///
+    /// ## Arguments
+    /// - `media_type` - The media type of the document.
+    /// - `data` - The data of the document.
+    pub fn base64<S>(
+        media_type: DocumentMediaType,
+        data: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: DocumentSourceType::Base64,
+            media_type,
+            data: data.into(),
+        }
+    }
+}
+
+/// The source type of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DocumentSourceType {
+    /// base64
+    #[default]
+    Base64,
+}
+
+impl Display for DocumentSourceType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | DocumentSourceType::Base64 => {
+                write!(f, "base64")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    DocumentSourceType,
+    Base64 => "base64"
+);
+
+/// The media type of the document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DocumentMediaType {
+    /// application/pdf
+    #[default]
+    Pdf,
+}
+
+impl Display for DocumentMediaType {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | DocumentMediaType::Pdf => {
+                write!(f, "application/pdf")
+            },
+        }
+    }
+}
+
+impl_enum_string_serialization!(
+    DocumentMediaType,
+    Pdf => "application/pdf"
+);
+
+/// The tool use content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ToolUseContentBlock {
+    /// The content type. It is always `tool_use`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The tool use.
+    #[serde(flatten)]
+    pub tool_use: ToolUse,
+}
+
+impl Default for ToolUseContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::ToolUse,
+            tool_use: ToolUse::default(),
+        }
+    }
+}
+
+impl_display_for_serialize!(ToolUseContentBlock);
+
+impl From<ToolUse> for ToolUseContentBlock {
+    fn from(tool_use: ToolUse) -> Self {
+        Self::new(tool_use)
+    }
+}
+
+impl ToolUseContentBlock {
+    /// Creates a new tool use content block.
+    pub fn new(tool_use: ToolUse) -> Self {
+        Self {
+            _type: ContentType::ToolUse,
+            tool_use,
+        }
+    }
+}
+
+/// The tool result content block.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ToolResultContentBlock {
+    /// The content type. It is always `tool_result`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The tool result.
+    #[serde(flatten)]
+    pub tool_result: ToolResult,
+}
+
+impl Default for ToolResultContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::ToolResult,
+            tool_result: ToolResult::default(),
+        }
+    }
+}
+
+impl_display_for_serialize!(ToolResultContentBlock);
+
+impl From<ToolResult> for ToolResultContentBlock {
+    fn from(tool_result: ToolResult) -> Self {
+        Self::new(tool_result)
+    }
+}
+
+impl ToolResultContentBlock {
+    /// Creates a new tool result content block.
+    pub fn new(tool_result: ToolResult) -> Self {
+        Self {
+            _type: ContentType::ToolResult,
+            tool_result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Content::from("text"),
+            Content::SingleText("text".to_string())
+        );
+    }
+
+    #[test]
+    fn default_content_type() {
+        assert_eq!(
+            ContentType::default(),
+            ContentType::Text
+        );
+    }
+
+    #[test]
+    fn display_content_type() {
+        assert_eq!(ContentType::Text.to_string(), "text");
+        assert_eq!(ContentType::Image.to_string(), "image");
+        assert_eq!(
+            ContentType::TextDelta.to_string(),
+            "text_delta"
+        );
+        assert_eq!(
+            ContentType::InputJsonDelta.to_string(),
+            "input_json_delta"
+        );
+        assert_eq!(
+            ContentType::ToolUse.to_string(),
+            "tool_use"
+        );
+        assert_eq!(
+            ContentType::ToolResult.to_string(),
+            "tool_result"
+        );
+        assert_eq!(
+            ContentType::Document.to_string(),
+            "document"
+        );
+    }
+
+    #[test]
+    fn serialize_content_type() {
+        assert_eq!(
+            serde_json::to_string(&ContentType::Text).unwrap(),
+            "\"text\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::Image).unwrap(),
+            "\"image\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::TextDelta).unwrap(),
+            "\"text_delta\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::InputJsonDelta).unwrap(),
+            "\"input_json_delta\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::ToolUse).unwrap(),
+            "\"tool_use\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::ToolResult).unwrap(),
+            "\"tool_result\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ContentType::Document).unwrap(),
+            "\"document\""
+        );
+    }
+
+    #[test]
+    fn deserialize_content_type() {
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"text\"").unwrap(),
+            ContentType::Text
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"image\"").unwrap(),
+            ContentType::Image
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"text_delta\"").unwrap(),
+            ContentType::TextDelta
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"input_json_delta\"")
+                .unwrap(),
+            ContentType::InputJsonDelta
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"tool_use\"").unwrap(),
+            ContentType::ToolUse
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"tool_result\"").unwrap(),
+            ContentType::ToolResult
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentType>("\"document\"").unwrap(),
+            ContentType::Document
+        );
+    }
+
+    #[test]
+    fn default_image_source_type() {
+        assert_eq!(
+            ImageSourceType::default(),
+            ImageSourceType::Base64
+        );
+    }
+
+    #[test]
+    fn display_image_source_type() {
+        assert_eq!(
+            ImageSourceType::Base64.to_string(),
+            "base64"
+        );
+    }
+
+    #[test]
+    fn serialize_image_source_type() {
+        assert_eq!(
+            serde_json::to_string(&ImageSourceType::Base64).unwrap(),
+            "\"base64\""
+        );
+    }
+
+    #[test]
+    fn deserialize_image_source_type() {
+        assert_eq!(
+            serde_json::from_str::<ImageSourceType>("\"base64\"").unwrap(),
+            ImageSourceType::Base64
+        );
+    }
+
+    #[test]
+    fn default_image_media_type() {
+        assert_eq!(
+            ImageMediaType::default(),
+            ImageMediaType::Jpeg
+        );
+    }
+
+    #[test]
+    fn display_image_media_type() {
+        assert_eq!(
+            ImageMediaType::Jpeg.to_string(),
+            "image/jpeg"
+        );
+        assert_eq!(
+            ImageMediaType::Png.to_string(),
+            "image/png"
+        );
+        assert_eq!(
+            ImageMediaType::Gif.to_string(),
+            "image/gif"
+        );
+        assert_eq!(
+            ImageMediaType::Webp.to_string(),
+            "image/webp"
+        );
+    }
+
+    #[test]
+    fn serialize_image_media_type() {
+        assert_eq!(
+            serde_json::to_string(&ImageMediaType::Jpeg).unwrap(),
+            "\"image/jpeg\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ImageMediaType::Png).unwrap(),
+            "\"image/png\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ImageMediaType::Gif).unwrap(),
+            "\"image/gif\""
+        );
+        assert_eq!(
+            serde_json::to_string(&ImageMediaType::Webp).unwrap(),
+            "\"image/webp\""
+        );
+    }
+
+    #[test]
+    fn deserialize_image_media_type() {
+        assert_eq!(
+            serde_json::from_str::<ImageMediaType>("\"image/jpeg\"").unwrap(),
+            ImageMediaType::Jpeg
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageMediaType>("\"image/png\"").unwrap(),
+            ImageMediaType::Png
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageMediaType>("\"image/gif\"").unwrap(),
+            ImageMediaType::Gif
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageMediaType>("\"image/webp\"").unwrap(),
+            ImageMediaType::Webp
+        );
+    }
+
+    #[test]
+    fn from_path_image_media_type() {
+        let path = PathBuf::from("image.jpeg");
+        assert_eq!(
+            ImageMediaType::from_path(&path).unwrap(),
+            ImageMediaType::Jpeg
+        );
+
+        let path = PathBuf::from("image.png");
+        assert_eq!(
+            ImageMediaType::from_path(&path).unwrap(),
+            ImageMediaType::Png
+        );
+
+        let path = PathBuf::from("image.gif");
+        assert_eq!(
+            ImageMediaType::from_path(&path).unwrap(),
+            ImageMediaType::Gif
+        );
+
+        let path = PathBuf::from("image.webp");
+        assert_eq!(
+            ImageMediaType::from_path(&path).unwrap(),
+            ImageMediaType::Webp
+        );
+
+        let path = PathBuf::from("image.bmp");
+        assert_eq!(
+            ImageMediaType::from_path(&path),
+            Err(ImageMediaTypeParseError::NotSupported(
+                "bmp".to_string()
+            ))
+        );
+
+        let path = PathBuf::from("image");
+        assert!(ImageMediaType::from_path(&path).is_err());
+    }
+
+    #[test]
+    fn new_image_content_source() {
+        let image_content_source =
+            ImageContentSource::base64(ImageMediaType::Jpeg, "data");
+        assert_eq!(
+            image_content_source,
+            ImageContentSource {
+                source_data: ImageSourceData::Base64 {
+                    media_type: ImageMediaType::Jpeg,
+                    data: "data".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            image_content_source.source_type(),
+            ImageSourceType::Base64
+        );
+        assert_eq!(
+            image_content_source.media_type(),
+            Some(ImageMediaType::Jpeg)
+        );
+        assert_eq!(
+            image_content_source.base64_data(),
+            Some("data")
+        );
+        assert_eq!(image_content_source.as_url(), None);
+
+        let image_content_source =
+            ImageContentSource::url("https://example.com/image.png");
+        assert_eq!(
+            image_content_source,
+            ImageContentSource {
+                source_data: ImageSourceData::Url {
+                    url: "https://example.com/image.png".to_string(),
+                },
+            }
+        );
+        assert_eq!(
+            image_content_source.source_type(),
+            ImageSourceType::Url
+        );
+        assert_eq!(image_content_source.media_type(), None);
+        assert_eq!(image_content_source.base64_data(), None);
+        assert_eq!(
+            image_content_source.as_url(),
+            Some("https://example.com/image.png")
+        );
+    }
+
+    #[test]
+    fn default_image_content_source() {
+        assert_eq!(
+            ImageContentSource::default(),
+            ImageContentSource {
+                source_data: ImageSourceData::Base64 {
+                    media_type: ImageMediaType::Jpeg,
+                    data: String::new(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn display_image_content_source() {
+        let image_content_source = ImageContentSource::base64(
+            ImageMediaType::Jpeg,
+            "data",
+        );
+        assert_eq!(
+            image_content_source.to_string(),
+            "{\n  \"type\": \"base64\",\n  \"media_type\": \"image/jpeg\",\n  \"data\": \"data\"\n}"
+        );
+    }
+
+    #[test]
+    fn serialize_image_content_source() {
+        let image_content_source = ImageContentSource::base64(
+            ImageMediaType::Jpeg,
+            "data",
+        );
+        assert_eq!(
+            serde_json::to_string(&image_content_source).unwrap(),
+            "{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"data\"}"
+        );
+    }
+
+    #[test]
+    fn deserialize_image_content_source() {
+        let image_content_source = ImageContentSource::base64(
+            ImageMediaType::Jpeg,
+            "data",
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageContentSource>("{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"data\"}").unwrap(),
+            image_content_source
+        );
+    }
+
+    #[test]
+    fn url_image_content_source() {
+        let image_content_source =
+            ImageContentSource::url("https://example.com/image.png");
+        let json =
+            "{\"type\":\"url\",\"url\":\"https://example.com/image.png\"}";
+        assert_eq!(
+            serde_json::to_string(&image_content_source).unwrap(),
+            json
+        );
+        assert_eq!(
+            serde_json::from_str::<ImageContentSource>(json).unwrap(),
+            image_content_source
+        );
+        assert_eq!(
+            format!("{:?}", image_content_source),
+            "ImageContentSource { _type: Url, url: \"https://example.com/image.png\" }"
+        );
+
+        assert_eq!(
+            serde_json::to_string(&ContentBlock::from(image_content_source))
+                .unwrap(),
+            "{\"type\":\"image\",\"source\":{\"type\":\"url\",\"url\":\"https://example.com/image.png\"}}"
+        );
+        assert!(serde_json::from_str::<ImageContentSource>(
+            "{\"type\":\"url\",\"media_type\":\"image/png\"}"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn new_text_content_block() {
+        let text_content_block = TextContentBlock::new("text".to_string());
+        assert_eq!(
+            text_content_block,
+            TextContentBlock {
+                _type: ContentType::Text,
+                text: "text".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn default_text_content_block() {
+        assert_eq!(
+            TextContentBlock::default(),
+            TextContentBlock {
+                _type: ContentType::Text,
+                text: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn display_text_content_block() {
+        let text_content_block = TextContentBlock::new("text".to_string());
+        assert_eq!(
+            text_content_block.to_string(),
+            "{\n  \"type\": \"text\",\n  \"text\": \"text\"\n}"
+        );
+    }
+
+    #[test]
+    fn serialize_text_content_block() {
+        let text_content_block = TextContentBlock::new("text".to_string());
+        assert_eq!(
+            serde_json::to_string(&text_content_block).unwrap(),
+            "{\"type\":\"text\",\"text\":\"text\"}"
+        );
+    }
+
+    #[test]
+    fn deserialize_text_content_block() {
+        let text_content_block = TextContentBlock::new("text".to_string());
+        assert_eq!(
+            serde_json::from_str::<TextContentBlock>(
+                "{\"type\":\"text\",\"text\":\"text\"}"
+            )
+            .unwrap(),
+            text_content_block
+        );
+    }
+
+    #[test]
+    fn new_image_content_block() {
+        let image_content_block =
+            ImageContentBlock::new(ImageContentSource::default());
+        assert_eq!(
+            image_content_block,
+            ImageContentBlock {
+                _type: ContentType::Image,
+                source: ImageContentSource::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn default_image_content_block() {
+        assert_eq!(
+            ImageContentBlock::default(),
+            ImageContentBlock {
+                _type: ContentType::Image,
+                source: ImageContentSource::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn display_image_content_block() {
+        let image_content_block =
+            ImageContentBlock::new(ImageContentSource::default());
+        assert_eq!(
+            image_content_block.to_string(),
+            "{\n  \"type\": \"image\",\n  \"source\": {\n    \"type\": \"base64\",\n    \"media_type\": \"image/jpeg\",\n    \"data\": \"\"\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn serialize_image_content_block() {
+        let image_content_block =
+            ImageContentBlock::new(ImageContentSource::default());
+        assert_eq!(
+            serde_json::to_string(&image_content_block).unwrap(),
+            "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}"
+        );
+    }
+
+    #[test]
+    fn deserialize_image_content_block() {
+        let image_content_block =
+            ImageContentBlock::new(ImageContentSource::default());
+        assert_eq!(
+            serde_json::from_str::<ImageContentBlock>(
+                "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}"
+            )
+            .unwrap(),
+            image_content_block
+        );
+    }
+
+    #[test]
+    fn new_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock::new(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
+        ));
+        assert_eq!(
+            tool_use_content_block,
+            ToolUseContentBlock {
+                _type: ContentType::ToolUse,
+                tool_use: ToolUse::new("id", "name", serde_json::Value::Null,),
+            }
+        );
+    }
+
+    #[test]
+    fn default_tool_use_content_block() {
+        assert_eq!(
+            ToolUseContentBlock::default(),
+            ToolUseContentBlock {
+                _type: ContentType::ToolUse,
+                tool_use: ToolUse::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn display_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock {
+            _type: ContentType::ToolUse,
+            tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
+        };
+        assert_eq!(
+            tool_use_content_block.to_string(),
+            "{\n  \"type\": \"tool_use\",\n  \"id\": \"id\",\n  \"name\": \"name\",\n  \"input\": null\n}"
+        );
+    }
+
+    #[test]
+    fn serialize_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock {
+            _type: ContentType::ToolUse,
+            tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
+        };
+        assert_eq!(
+            serde_json::to_string(&tool_use_content_block).unwrap(),
+            "{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}"
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_use_content_block() {
+        let tool_use_content_block = ToolUseContentBlock {
+            _type: ContentType::ToolUse,
+            tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
+        };
+        assert_eq!(
+            serde_json::from_str::<ToolUseContentBlock>("{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}").unwrap(),
+            tool_use_content_block
+        );
+    }
+
+    #[test]
+    fn new_tool_result_content_block() {
+        let tool_result_content_block = ToolResultContentBlock::new(
+            ToolResult::success("tool_use_id", Some("content")),
+        );
+        assert_eq!(
+            tool_result_content_block,
+            ToolResultContentBlock {
+                _type: ContentType::ToolResult,
+                tool_result: ToolResult::success(
+                    "tool_use_id",
+                    Some("content"),
+                ),
+            }
+        );
+
+        let tool_result_content_block = ToolResultContentBlock::new(
+            ToolResult::error("tool_use_id", Some("content")),
+        );
+        assert_eq!(
+            tool_result_content_block,
+            ToolResultContentBlock {
+                _type: ContentType::ToolResult,
+                tool_result: ToolResult::error("tool_use_id", Some("content"),),
+            }
+        );
+    }
+
+    #[test]
+    fn default_tool_result_content_block() {
+        assert_eq!(
+            ToolResultContentBlock::default(),
+            ToolResultContentBlock {
+                _type: ContentType::ToolResult,
+                tool_result: ToolResult::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn display_tool_result_content_block() {
+        let tool_result_content_block = ToolResultContentBlock {
             _type: ContentType::ToolResult,
-            tool_result: ToolResult::default(),
-        }
+            tool_result: ToolResult::success("tool_use_id", Some("content")),
+        };
+        assert_eq!(
+            tool_result_content_block.to_string(),
+            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  }\n}"
+        );
+
+        let tool_result_content_block = ToolResultContentBlock {
+            _type: ContentType::ToolResult,
+            tool_result: ToolResult::error("tool_use_id", Some("content")),
+        };
+        assert_eq!(
+            tool_result_content_block.to_string(),
+            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  },\n  \"is_error\": true\n}"
+        );
     }
-}
 
-impl_display_for_serialize!(ToolResultContentBlock);
+    #[test]
+    fn serialize_tool_result_content_block() {
+        let tool_result_content_block = ToolResultContentBlock {
+            _type: ContentType::ToolResult,
+            tool_result: ToolResult::success("tool_use_id", Some("content")),
+        };
+        assert_eq!(
+            serde_json::to_string(&tool_result_content_block).unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}"
+        );
 
-impl From<ToolResult> for ToolResultContentBlock {
-    fn from(tool_result: ToolResult) -> Self {
-        Self::new(tool_result)
+        let tool_result_content_block = ToolResultContentBlock {
+            _type: ContentType::ToolResult,
+            tool_result: ToolResult::error("tool_use_id", Some("content")),
+        };
+        assert_eq!(
+            serde_json::to_string(&tool_result_content_block).unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}"
+        );
     }
-}
 
-impl ToolResultContentBlock {
-    /// Creates a new tool result content block.
-    pub fn new(tool_result: ToolResult) -> Self {
-        Self {
+    #[test]
+    fn deserialize_tool_result_content_block() {
+        let tool_result_content_block = ToolResultContentBlock {
             _type: ContentType::ToolResult,
-            tool_result,
-        }
-    }
-}
+            tool_result: ToolResult::success("tool_use_id", Some("content")),
+        };
+        assert_eq!(
+            serde_json::from_str::<ToolResultContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}").unwrap(),
+            tool_result_content_block
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let tool_result_content_block = ToolResultContentBlock {
+            _type: ContentType::ToolResult,
+            tool_result: ToolResult::error("tool_use_id", Some("content")),
+        };
+        assert_eq!(
+            serde_json::from_str::<ToolResultContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}").unwrap(),
+            tool_result_content_block
+        );
+    }
 
     #[test]
-    fn from_str() {
+    fn new_content_block() {
+        let content_block = ContentBlock::Text(TextContentBlock::new(
+            "text".to_string(),
+        ));
         assert_eq!(
-            Content::from("text"),
-            Content::SingleText("text".to_string())
+            content_block,
+            ContentBlock::Text(TextContentBlock {
+                _type: ContentType::Text,
+                text: "text".to_string(),
+            })
+        );
+
+        let content_block = ContentBlock::Image(ImageContentBlock::new(
+            ImageContentSource::default(),
+        ));
+        assert_eq!(
+            content_block,
+            ContentBlock::Image(ImageContentBlock {
+                _type: ContentType::Image,
+                source: ImageContentSource::default(),
+            })
+        );
+
+        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
+            ToolUse::new("id", "name", serde_json::Value::Null),
+        ));
+        assert_eq!(
+            content_block,
+            ContentBlock::ToolUse(ToolUseContentBlock {
+                _type: ContentType::ToolUse,
+                tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
+            })
+        );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::error("tool_use_id", Some("content")),
+            ));
+        assert_eq!(
+            content_block,
+            ContentBlock::ToolResult(ToolResultContentBlock {
+                _type: ContentType::ToolResult,
+                tool_result: ToolResult::error("tool_use_id", Some("content")),
+            })
         );
     }
 
     #[test]
-    fn default_content_type() {
+    fn default_content_block() {
         assert_eq!(
-            ContentType::default(),
-            ContentType::Text
+            ContentBlock::default(),
+            ContentBlock::Text(TextContentBlock::default())
         );
     }
 
     #[test]
-    fn display_content_type() {
-        assert_eq!(ContentType::Text.to_string(), "text");
-        assert_eq!(ContentType::Image.to_string(), "image");
+    fn display_content_block() {
+        let content_block = ContentBlock::Text(TextContentBlock::new(
+            "text".to_string(),
+        ));
         assert_eq!(
-            ContentType::TextDelta.to_string(),
-            "text_delta"
+            content_block.to_string(),
+            "{\n  \"type\": \"text\",\n  \"text\": \"text\"\n}"
         );
+
+        let content_block = ContentBlock::Image(ImageContentBlock::new(
+            ImageContentSource::default(),
+        ));
         assert_eq!(
-            ContentType::ToolUse.to_string(),
-            "tool_use"
+            content_block.to_string(),
+            "{\n  \"type\": \"image\",\n  \"source\": {\n    \"type\": \"base64\",\n    \"media_type\": \"image/jpeg\",\n    \"data\": \"\"\n  }\n}"
         );
+
+        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
+            ToolUse::new("id", "name", serde_json::Value::Null),
+        ));
         assert_eq!(
-            ContentType::ToolResult.to_string(),
-            "tool_result"
+            content_block.to_string(),
+            "{\n  \"type\": \"tool_use\",\n  \"id\": \"id\",\n  \"name\": \"name\",\n  \"input\": null\n}"
+        );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::success("tool_use_id", Some("content")),
+            ));
+        assert_eq!(
+            content_block.to_string(),
+            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  }\n}"
+        );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::error("tool_use_id", Some("content")),
+            ));
+        assert_eq!(
+            content_block.to_string(),
+            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  },\n  \"is_error\": true\n}"
         );
     }
 
     #[test]
-    fn serialize_content_type() {
+    fn serialize_content_block() {
+        let content_block = ContentBlock::Text(TextContentBlock::new(
+            "text".to_string(),
+        ));
         assert_eq!(
-            serde_json::to_string(&ContentType::Text).unwrap(),
-            "\"text\""
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"text\",\"text\":\"text\"}"
         );
+
+        let content_block = ContentBlock::Image(ImageContentBlock::new(
+            ImageContentSource::default(),
+        ));
         assert_eq!(
-            serde_json::to_string(&ContentType::Image).unwrap(),
-            "\"image\""
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}"
         );
+
+        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
+            ToolUse::new("id", "name", serde_json::Value::Null),
+        ));
         assert_eq!(
-            serde_json::to_string(&ContentType::TextDelta).unwrap(),
-            "\"text_delta\""
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}"
         );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::success("tool_use_id", Some("content")),
+            ));
         assert_eq!(
-            serde_json::to_string(&ContentType::ToolUse).unwrap(),
-            "\"tool_use\""
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}"
         );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::error("tool_use_id", Some("content")),
+            ));
         assert_eq!(
-            serde_json::to_string(&ContentType::ToolResult).unwrap(),
-            "\"tool_result\""
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}"
+        );
+
+        let content_block = ContentBlock::Document(DocumentContentBlock::new(
+            DocumentContentSource::base64(DocumentMediaType::Pdf, "data"),
+        ));
+        assert_eq!(
+            serde_json::to_string(&content_block).unwrap(),
+            "{\"type\":\"document\",\"source\":{\"type\":\"base64\",\"media_type\":\"application/pdf\",\"data\":\"data\"}}"
         );
     }
 
     #[test]
-    fn deserialize_content_type() {
+    fn deserialize_content_block() {
+        let content_block = ContentBlock::Text(TextContentBlock::new(
+            "text".to_string(),
+        ));
         assert_eq!(
-            serde_json::from_str::<ContentType>("\"text\"").unwrap(),
-            ContentType::Text
+            serde_json::from_str::<ContentBlock>(
+                "{\"type\":\"text\",\"text\":\"text\"}"
+            )
+            .unwrap(),
+            content_block
         );
+
+        let content_block = ContentBlock::Image(ImageContentBlock::new(
+            ImageContentSource::default(),
+        ));
         assert_eq!(
-            serde_json::from_str::<ContentType>("\"image\"").unwrap(),
-            ContentType::Image
+            serde_json::from_str::<ContentBlock>("{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}").unwrap(),
+            content_block
         );
+
+        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
+            ToolUse::new("id", "name", serde_json::Value::Null),
+        ));
         assert_eq!(
-            serde_json::from_str::<ContentType>("\"text_delta\"").unwrap(),
-            ContentType::TextDelta
+            serde_json::from_str::<ContentBlock>("{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}").unwrap(),
+            content_block
         );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::success("tool_use_id", Some("content")),
+            ));
         assert_eq!(
-            serde_json::from_str::<ContentType>("\"tool_use\"").unwrap(),
-            ContentType::ToolUse
+            serde_json::from_str::<ContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}").unwrap(),
+            content_block
         );
+
+        let content_block =
+            ContentBlock::ToolResult(ToolResultContentBlock::new(
+                ToolResult::error("tool_use_id", Some("content")),
+            ));
         assert_eq!(
-            serde_json::from_str::<ContentType>("\"tool_result\"").unwrap(),
-            ContentType::ToolResult
+            serde_json::from_str::<ContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\": {\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}").unwrap(),
+            content_block
         );
-    }
 
-    #[test]
-    fn default_image_source_type() {
+        let content_block = ContentBlock::Document(DocumentContentBlock::new(
+            DocumentContentSource::base64(DocumentMediaType::Pdf, "data"),
+        ));
         assert_eq!(
-            ImageSourceType::default(),
-            ImageSourceType::Base64
+            serde_json::from_str::<ContentBlock>("{\"type\":\"document\",\"source\":{\"type\":\"base64\",\"media_type\":\"application/pdf\",\"data\":\"data\"}}").unwrap(),
+            content_block
         );
     }
 
     #[test]
-    fn display_image_source_type() {
+    fn pdf_document() {
+        let content_block = ContentBlock::from_pdf_bytes(b"%PDF-1.7");
         assert_eq!(
-            ImageSourceType::Base64.to_string(),
-            "base64"
+            content_block,
+            ContentBlock::Document(DocumentContentBlock::new(
+                DocumentContentSource::base64(
+                    DocumentMediaType::Pdf,
+                    "JVBERi0xLjc=",
+                )
+            ))
         );
-    }
 
-    #[test]
-    fn serialize_image_source_type() {
+        let path = std::env::temp_dir().join("clust_pdf_document.pdf");
+        std::fs::write(&path, b"%PDF-1.7").unwrap();
         assert_eq!(
-            serde_json::to_string(&ImageSourceType::Base64).unwrap(),
-            "\"base64\""
+            ContentBlock::from_pdf_file(&path).unwrap(),
+            content_block
         );
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(ContentBlock::from_pdf_file(&std::env::temp_dir().join(
+            "clust_pdf_document_not_found.pdf"
+        ))
+        .is_err());
+
+        let source = DocumentContentSource::base64(
+            DocumentMediaType::Pdf,
+            "A".repeat(100),
+        );
+        assert!(format!("{:?}", source).contains("<BASE64 100 bytes>"));
     }
 
     #[test]
-    fn deserialize_image_source_type() {
+    fn from_content_block() {
         assert_eq!(
-            serde_json::from_str::<ImageSourceType>("\"base64\"").unwrap(),
-            ImageSourceType::Base64
+            ContentBlock::from("text"),
+            ContentBlock::Text(TextContentBlock::new("text"))
         );
-    }
 
-    #[test]
-    fn default_image_media_type() {
+        let content_block: ContentBlock = "text".into();
         assert_eq!(
-            ImageMediaType::default(),
-            ImageMediaType::Jpeg
+            content_block,
+            ContentBlock::Text(TextContentBlock::new("text"))
         );
-    }
 
-    #[test]
-    fn display_image_media_type() {
         assert_eq!(
-            ImageMediaType::Jpeg.to_string(),
-            "image/jpeg"
+            ContentBlock::from(ImageContentSource::default()),
+            ContentBlock::Image(ImageContentBlock::new(
+                ImageContentSource::default()
+            ))
         );
+
+        let content_block: ContentBlock = ImageContentSource::default().into();
         assert_eq!(
-            ImageMediaType::Png.to_string(),
-            "image/png"
+            content_block,
+            ContentBlock::Image(ImageContentBlock::new(
+                ImageContentSource::default()
+            ))
         );
+
         assert_eq!(
-            ImageMediaType::Gif.to_string(),
-            "image/gif"
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null
+            )),
+            ContentBlock::ToolUse(ToolUseContentBlock::new(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null
+            )))
         );
+
+        let content_block: ContentBlock =
+            ToolUse::new("id", "name", serde_json::Value::Null).into();
         assert_eq!(
-            ImageMediaType::Webp.to_string(),
-            "image/webp"
+            content_block,
+            ContentBlock::ToolUse(ToolUseContentBlock::new(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null
+            )))
         );
     }
 
     #[test]
-    fn serialize_image_media_type() {
+    fn new_content() {
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            serde_json::to_string(&ImageMediaType::Jpeg).unwrap(),
-            "\"image/jpeg\""
+            content,
+            Content::SingleText("text".to_string())
         );
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::Text(TextContentBlock::new(
+                "text".to_string(),
+            )),
+            ContentBlock::Image(ImageContentBlock::new(
+                ImageContentSource::default(),
+            )),
+        ]);
         assert_eq!(
-            serde_json::to_string(&ImageMediaType::Png).unwrap(),
-            "\"image/png\""
+            content,
+            Content::MultipleBlocks(vec![
+                ContentBlock::Text(TextContentBlock::new(
+                    "text".to_string(),
+                )),
+                ContentBlock::Image(ImageContentBlock::new(
+                    ImageContentSource::default(),
+                )),
+            ])
         );
+    }
+
+    #[test]
+    fn default_content() {
         assert_eq!(
-            serde_json::to_string(&ImageMediaType::Gif).unwrap(),
-            "\"image/gif\""
+            Content::default(),
+            Content::SingleText(String::new())
         );
+    }
+
+    #[test]
+    fn display_content() {
+        let content = Content::SingleText("text".to_string());
+        assert_eq!(content.to_string(), "\"text\"");
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::Text(TextContentBlock::new(
+                "text".to_string(),
+            )),
+            ContentBlock::Image(ImageContentBlock::new(
+                ImageContentSource::default(),
+            )),
+        ]);
         assert_eq!(
-            serde_json::to_string(&ImageMediaType::Webp).unwrap(),
-            "\"image/webp\""
+            content.to_string(),
+            "[\n  {\n    \"type\": \"text\",\n    \"text\": \"text\"\n  },\n  {\n    \"type\": \"image\",\n    \"source\": {\n      \"type\": \"base64\",\n      \"media_type\": \"image/jpeg\",\n      \"data\": \"\"\n    }\n  }\n]"
         );
     }
 
     #[test]
-    fn deserialize_image_media_type() {
+    fn serialize_content() {
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            serde_json::from_str::<ImageMediaType>("\"image/jpeg\"").unwrap(),
-            ImageMediaType::Jpeg
+            serde_json::to_string(&content).unwrap(),
+            "\"text\""
         );
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::Text(TextContentBlock::new(
+                "text".to_string(),
+            )),
+            ContentBlock::Image(ImageContentBlock::new(
+                ImageContentSource::default(),
+            )),
+        ]);
         assert_eq!(
-            serde_json::from_str::<ImageMediaType>("\"image/png\"").unwrap(),
-            ImageMediaType::Png
+            serde_json::to_string(&content).unwrap(),
+            "[{\"type\":\"text\",\"text\":\"text\"},{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}]"
         );
+    }
+
+    #[test]
+    fn deserialize_content() {
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            serde_json::from_str::<ImageMediaType>("\"image/gif\"").unwrap(),
-            ImageMediaType::Gif
+            serde_json::from_str::<Content>("\"text\"").unwrap(),
+            content
         );
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::Text(TextContentBlock::new(
+                "text".to_string(),
+            )),
+            ContentBlock::Image(ImageContentBlock::new(
+                ImageContentSource::default(),
+            )),
+        ]);
         assert_eq!(
-            serde_json::from_str::<ImageMediaType>("\"image/webp\"").unwrap(),
-            ImageMediaType::Webp
+            serde_json::from_str::<Content>("[{\"type\":\"text\",\"text\":\"text\"},{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}]").unwrap(),
+            content
         );
     }
 
     #[test]
-    fn from_path_image_media_type() {
-        let path = PathBuf::from("image.jpeg");
+    fn from_content() {
         assert_eq!(
-            ImageMediaType::from_path(&path).unwrap(),
-            ImageMediaType::Jpeg
+            Content::from("text"),
+            Content::SingleText("text".to_string())
         );
 
-        let path = PathBuf::from("image.png");
+        let content: Content = "text".into();
         assert_eq!(
-            ImageMediaType::from_path(&path).unwrap(),
-            ImageMediaType::Png
+            content,
+            Content::SingleText("text".to_string())
         );
 
-        let path = PathBuf::from("image.gif");
         assert_eq!(
-            ImageMediaType::from_path(&path).unwrap(),
-            ImageMediaType::Gif
+            Content::from(vec![ContentBlock::from(
+                "text"
+            )]),
+            Content::MultipleBlocks(vec![ContentBlock::Text(
+                TextContentBlock::new("text")
+            )])
         );
 
-        let path = PathBuf::from("image.webp");
+        let content: Content = vec!["text".into()].into();
         assert_eq!(
-            ImageMediaType::from_path(&path).unwrap(),
-            ImageMediaType::Webp
+            content,
+            Content::MultipleBlocks(vec![ContentBlock::Text(
+                TextContentBlock::new("text")
+            )])
         );
 
-        let path = PathBuf::from("image.bmp");
         assert_eq!(
-            ImageMediaType::from_path(&path),
-            Err(ImageMediaTypeParseError::NotSupported(
-                "bmp".to_string()
-            ))
+            Content::from(ImageContentSource::default()),
+            Content::MultipleBlocks(vec![ContentBlock::Image(
+                ImageContentBlock::new(ImageContentSource::default())
+            )])
         );
 
-        let path = PathBuf::from("image");
-        assert!(ImageMediaType::from_path(&path).is_err());
+        let content: Content = ImageContentSource::default().into();
+        assert_eq!(
+            content,
+            Content::MultipleBlocks(vec![ContentBlock::Image(
+                ImageContentBlock::new(ImageContentSource::default())
+            )])
+        );
     }
 
     #[test]
-    fn new_image_content_source() {
-        let image_content_source =
-            ImageContentSource::base64(ImageMediaType::Jpeg, "data");
+    fn flatten_into_text() {
         assert_eq!(
-            image_content_source,
-            ImageContentSource {
-                _type: ImageSourceType::Base64,
-                media_type: ImageMediaType::Jpeg,
-                data: "data".to_string(),
-            }
+            Content::from("text")
+                .flatten_into_text()
+                .unwrap(),
+            "text"
+        );
+
+        assert_eq!(
+            Content::from(vec![
+                ContentBlock::from("text"),
+                ContentBlock::from(ImageContentSource::default()),
+            ])
+            .flatten_into_text()
+            .unwrap(),
+            "text"
         );
-    }
 
-    #[test]
-    fn default_image_content_source() {
         assert_eq!(
-            ImageContentSource::default(),
-            ImageContentSource {
-                _type: ImageSourceType::Base64,
-                media_type: ImageMediaType::Jpeg,
-                data: String::new(),
-            }
+            Content::from(vec![
+                ContentBlock::from("text"),
+                ContentBlock::from("second"),
+            ])
+            .flatten_into_text()
+            .unwrap(),
+            "text"
         );
-    }
 
-    #[test]
-    fn display_image_content_source() {
-        let image_content_source = ImageContentSource {
-            _type: ImageSourceType::Base64,
-            media_type: ImageMediaType::Jpeg,
-            data: "data".to_string(),
-        };
-        assert_eq!(
-            image_content_source.to_string(),
-            "{\n  \"type\": \"base64\",\n  \"media_type\": \"image/jpeg\",\n  \"data\": \"data\"\n}"
+        assert!(Content::from(vec![])
+            .flatten_into_text()
+            .is_err());
+
+        assert!(
+            Content::from(ImageContentSource::default())
+                .flatten_into_text()
+                .is_err()
         );
+
+        assert!(Content::from(vec![
+            ContentBlock::from(ImageContentSource::default()),
+            ContentBlock::from("text"),
+        ])
+        .flatten_into_text()
+        .is_err());
     }
 
     #[test]
-    fn serialize_image_content_source() {
-        let image_content_source = ImageContentSource {
-            _type: ImageSourceType::Base64,
-            media_type: ImageMediaType::Jpeg,
-            data: "data".to_string(),
-        };
+    fn collect_all_text() {
         assert_eq!(
-            serde_json::to_string(&image_content_source).unwrap(),
-            "{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"data\"}"
+            Content::from("text").collect_all_text(),
+            Ok("text".to_string())
         );
-    }
 
-    #[test]
-    fn deserialize_image_content_source() {
-        let image_content_source = ImageContentSource {
-            _type: ImageSourceType::Base64,
-            media_type: ImageMediaType::Jpeg,
-            data: "data".to_string(),
-        };
+        let content = Content::from(vec![
+            ContentBlock::from(ImageContentSource::default()),
+            ContentBlock::from("first"),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
+            )),
+            ContentBlock::from("second"),
+        ]);
         assert_eq!(
-            serde_json::from_str::<ImageContentSource>("{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"data\"}").unwrap(),
-            image_content_source
+            content.collect_all_text(),
+            Ok("first\nsecond".to_string())
         );
-    }
-
-    #[test]
-    fn new_text_content_block() {
-        let text_content_block = TextContentBlock::new("text".to_string());
         assert_eq!(
-            text_content_block,
-            TextContentBlock {
-                _type: ContentType::Text,
-                text: "text".to_string(),
-            }
+            content.collect_all_text_with_separator(" "),
+            Ok("first second".to_string())
         );
-    }
 
-    #[test]
-    fn default_text_content_block() {
         assert_eq!(
-            TextContentBlock::default(),
-            TextContentBlock {
-                _type: ContentType::Text,
-                text: String::new(),
-            }
+            Content::from(vec![]).collect_all_text(),
+            Err(ContentFlatteningError::Empty)
         );
-    }
-
-    #[test]
-    fn display_text_content_block() {
-        let text_content_block = TextContentBlock::new("text".to_string());
         assert_eq!(
-            text_content_block.to_string(),
-            "{\n  \"type\": \"text\",\n  \"text\": \"text\"\n}"
+            Content::from(ImageContentSource::default()).collect_all_text(),
+            Err(ContentFlatteningError::NotFoundTargetBlock)
         );
     }
 
     #[test]
-    fn serialize_text_content_block() {
-        let text_content_block = TextContentBlock::new("text".to_string());
+    fn flatten_into_image_source() {
+        assert!(Content::from("text")
+            .flatten_into_image_source()
+            .is_err());
+
+        assert!(Content::from(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::default()),
+        ])
+        .flatten_into_image_source()
+        .is_err());
+
+        assert!(Content::from(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from("second"),
+        ])
+        .flatten_into_image_source()
+        .is_err());
+
+        assert!(Content::from(vec![])
+            .flatten_into_image_source()
+            .is_err());
+
         assert_eq!(
-            serde_json::to_string(&text_content_block).unwrap(),
-            "{\"type\":\"text\",\"text\":\"text\"}"
+            *Content::from(ImageContentSource::default())
+                .flatten_into_image_source()
+                .unwrap(),
+            ImageContentSource::default()
         );
-    }
 
-    #[test]
-    fn deserialize_text_content_block() {
-        let text_content_block = TextContentBlock::new("text".to_string());
         assert_eq!(
-            serde_json::from_str::<TextContentBlock>(
-                "{\"type\":\"text\",\"text\":\"text\"}"
-            )
+            *Content::from(vec![
+                ContentBlock::from(ImageContentSource::default()),
+                ContentBlock::from("text"),
+            ])
+            .flatten_into_image_source()
             .unwrap(),
-            text_content_block
+            ImageContentSource::default()
         );
     }
 
     #[test]
-    fn new_image_content_block() {
-        let image_content_block =
-            ImageContentBlock::new(ImageContentSource::default());
-        assert_eq!(
-            image_content_block,
-            ImageContentBlock {
-                _type: ContentType::Image,
-                source: ImageContentSource::default(),
-            }
-        );
-    }
+    fn flatten_into_tool_use() {
+        assert!(Content::from("text")
+            .flatten_into_tool_use()
+            .is_err());
 
-    #[test]
-    fn default_image_content_block() {
-        assert_eq!(
-            ImageContentBlock::default(),
-            ImageContentBlock {
-                _type: ContentType::Image,
-                source: ImageContentSource::default(),
-            }
+        assert!(Content::from(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::default()),
+        ])
+        .flatten_into_tool_use()
+        .is_err());
+
+        assert!(Content::from(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from("second"),
+        ])
+        .flatten_into_tool_use()
+        .is_err());
+
+        assert!(Content::from(vec![])
+            .flatten_into_tool_use()
+            .is_err());
+
+        assert!(
+            Content::from(ImageContentSource::default())
+                .flatten_into_tool_use()
+                .is_err()
         );
-    }
 
-    #[test]
-    fn display_image_content_block() {
-        let image_content_block =
-            ImageContentBlock::new(ImageContentSource::default());
         assert_eq!(
-            image_content_block.to_string(),
-            "{\n  \"type\": \"image\",\n  \"source\": {\n    \"type\": \"base64\",\n    \"media_type\": \"image/jpeg\",\n    \"data\": \"\"\n  }\n}"
+            Content::from(vec![
+                ContentBlock::from("text"),
+                ContentBlock::from(ToolUse::new(
+                    "id",
+                    "name",
+                    serde_json::Value::Object(serde_json::Map::new())
+                )),
+            ])
+            .flatten_into_tool_use()
+            .unwrap(),
+            ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Object(serde_json::Map::new())
+            )
         );
     }
 
     #[test]
-    fn serialize_image_content_block() {
-        let image_content_block =
-            ImageContentBlock::new(ImageContentSource::default());
-        assert_eq!(
-            serde_json::to_string(&image_content_block).unwrap(),
-            "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}"
+    fn flatten_into_tool_result() {
+        assert!(Content::from("text")
+            .flatten_into_tool_result()
+            .is_err());
+
+        assert!(Content::from(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::default()),
+        ])
+        .flatten_into_tool_result()
+        .is_err());
+
+        assert!(Content::from(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from("second"),
+        ])
+        .flatten_into_tool_result()
+        .is_err());
+
+        assert!(Content::from(vec![])
+            .flatten_into_tool_result()
+            .is_err());
+
+        assert!(
+            Content::from(ImageContentSource::default())
+                .flatten_into_tool_result()
+                .is_err()
         );
-    }
 
-    #[test]
-    fn deserialize_image_content_block() {
-        let image_content_block =
-            ImageContentBlock::new(ImageContentSource::default());
         assert_eq!(
-            serde_json::from_str::<ImageContentBlock>(
-                "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}"
-            )
+            Content::from(vec![
+                ContentBlock::from(ToolResult::success(
+                    "tool_use_id",
+                    Some("content")
+                )),
+                ContentBlock::from("text"),
+            ])
+            .flatten_into_tool_result()
             .unwrap(),
-            image_content_block
+            ToolResult::success("tool_use_id", Some("content"))
         );
     }
 
     #[test]
-    fn new_tool_use_content_block() {
-        let tool_use_content_block = ToolUseContentBlock::new(ToolUse::new(
-            "id",
-            "name",
-            serde_json::Value::Null,
-        ));
+    fn replace_block_at() {
+        let mut content = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            ContentBlock::from("second"),
+        ]);
+        assert_eq!(
+            content
+                .replace_block_at(0, ContentBlock::from("replaced"))
+                .unwrap(),
+            ContentBlock::from("first")
+        );
+        assert_eq!(
+            content,
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("replaced"),
+                ContentBlock::from("second"),
+            ])
+        );
         assert_eq!(
-            tool_use_content_block,
-            ToolUseContentBlock {
-                _type: ContentType::ToolUse,
-                tool_use: ToolUse::new("id", "name", serde_json::Value::Null,),
+            content
+                .replace_block_at(2, ContentBlock::from("replaced"))
+                .unwrap_err(),
+            ContentIndexError::OutOfBounds {
+                index: 2,
+                len: 2,
             }
         );
-    }
 
-    #[test]
-    fn default_tool_use_content_block() {
+        let mut content = Content::SingleText("text".to_string());
         assert_eq!(
-            ToolUseContentBlock::default(),
-            ToolUseContentBlock {
-                _type: ContentType::ToolUse,
-                tool_use: ToolUse::default(),
-            }
+            content
+                .replace_block_at(0, ContentBlock::from("replaced"))
+                .unwrap(),
+            ContentBlock::from("text")
+        );
+        assert_eq!(
+            content,
+            Content::MultipleBlocks(vec![ContentBlock::from(
+                "replaced"
+            )])
         );
     }
 
     #[test]
-    fn display_tool_use_content_block() {
-        let tool_use_content_block = ToolUseContentBlock {
-            _type: ContentType::ToolUse,
-            tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
-        };
+    fn remove_block_at() {
+        let mut content = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            ContentBlock::from("second"),
+        ]);
         assert_eq!(
-            tool_use_content_block.to_string(),
-            "{\n  \"type\": \"tool_use\",\n  \"id\": \"id\",\n  \"name\": \"name\",\n  \"input\": null\n}"
+            content.remove_block_at(1),
+            Some(ContentBlock::from("second"))
+        );
+        assert_eq!(content.remove_block_at(1), None);
+        assert_eq!(
+            content,
+            Content::MultipleBlocks(vec![ContentBlock::from("first")])
         );
-    }
 
-    #[test]
-    fn serialize_tool_use_content_block() {
-        let tool_use_content_block = ToolUseContentBlock {
-            _type: ContentType::ToolUse,
-            tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
-        };
+        let mut content = Content::SingleText("text".to_string());
+        assert_eq!(content.remove_block_at(1), None);
         assert_eq!(
-            serde_json::to_string(&tool_use_content_block).unwrap(),
-            "{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}"
+            content,
+            Content::SingleText("text".to_string())
+        );
+        assert_eq!(
+            content.remove_block_at(0),
+            Some(ContentBlock::from("text"))
         );
+        assert_eq!(content, Content::MultipleBlocks(vec![]));
     }
 
     #[test]
-    fn deserialize_tool_use_content_block() {
-        let tool_use_content_block = ToolUseContentBlock {
-            _type: ContentType::ToolUse,
-            tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
-        };
+    fn retain_blocks() {
+        let mut content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "base64",
+            )),
+        ]);
+        content.retain_blocks(|block| matches!(block, ContentBlock::Text(_)));
         assert_eq!(
-            serde_json::from_str::<ToolUseContentBlock>("{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}").unwrap(),
-            tool_use_content_block
+            content,
+            Content::MultipleBlocks(vec![ContentBlock::from("text")])
+        );
+
+        let mut content = Content::SingleText("text".to_string());
+        content.retain_blocks(|_| true);
+        assert_eq!(
+            content,
+            Content::SingleText("text".to_string())
         );
+
+        let mut content = Content::SingleText("text".to_string());
+        content.retain_blocks(|_| false);
+        assert_eq!(content, Content::MultipleBlocks(vec![]));
     }
 
     #[test]
-    fn new_tool_result_content_block() {
-        let tool_result_content_block = ToolResultContentBlock::new(
-            ToolResult::success("tool_use_id", Some("content")),
+    fn split_at() {
+        let image = ContentBlock::from(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "base64",
+        ));
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            image.clone(),
+            ContentBlock::from("last"),
+        ]);
+
+        assert_eq!(
+            content.split_at(0),
+            (
+                Content::MultipleBlocks(vec![]),
+                content.clone()
+            )
         );
         assert_eq!(
-            tool_result_content_block,
-            ToolResultContentBlock {
-                _type: ContentType::ToolResult,
-                tool_result: ToolResult::success(
-                    "tool_use_id",
-                    Some("content"),
-                ),
-            }
+            content.split_at(1),
+            (
+                Content::SingleText("first".to_string()),
+                Content::MultipleBlocks(vec![
+                    image.clone(),
+                    ContentBlock::from("last"),
+                ])
+            )
         );
-
-        let tool_result_content_block = ToolResultContentBlock::new(
-            ToolResult::error("tool_use_id", Some("content")),
+        assert_eq!(
+            content.split_at(2),
+            (
+                Content::MultipleBlocks(vec![
+                    ContentBlock::from("first"),
+                    image.clone(),
+                ]),
+                Content::SingleText("last".to_string())
+            )
         );
         assert_eq!(
-            tool_result_content_block,
-            ToolResultContentBlock {
-                _type: ContentType::ToolResult,
-                tool_result: ToolResult::error("tool_use_id", Some("content"),),
-            }
+            content.split_at(3),
+            (
+                content.clone(),
+                Content::MultipleBlocks(vec![])
+            )
         );
-    }
-
-    #[test]
-    fn default_tool_result_content_block() {
         assert_eq!(
-            ToolResultContentBlock::default(),
-            ToolResultContentBlock {
-                _type: ContentType::ToolResult,
-                tool_result: ToolResult::default(),
-            }
+            content.split_at(10),
+            (
+                content.clone(),
+                Content::MultipleBlocks(vec![])
+            )
         );
-    }
 
-    #[test]
-    fn display_tool_result_content_block() {
-        let tool_result_content_block = ToolResultContentBlock {
-            _type: ContentType::ToolResult,
-            tool_result: ToolResult::success("tool_use_id", Some("content")),
-        };
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            tool_result_content_block.to_string(),
-            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  }\n}"
+            content.split_at(0),
+            (
+                Content::MultipleBlocks(vec![]),
+                content.clone()
+            )
         );
-
-        let tool_result_content_block = ToolResultContentBlock {
-            _type: ContentType::ToolResult,
-            tool_result: ToolResult::error("tool_use_id", Some("content")),
-        };
         assert_eq!(
-            tool_result_content_block.to_string(),
-            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  },\n  \"is_error\": true\n}"
+            content.split_at(1),
+            (
+                content.clone(),
+                Content::MultipleBlocks(vec![])
+            )
+        );
+        assert_eq!(
+            content.split_at(2),
+            (
+                content.clone(),
+                Content::MultipleBlocks(vec![])
+            )
         );
     }
 
     #[test]
-    fn serialize_tool_result_content_block() {
-        let tool_result_content_block = ToolResultContentBlock {
-            _type: ContentType::ToolResult,
-            tool_result: ToolResult::success("tool_use_id", Some("content")),
-        };
+    fn without_blocks() {
+        let image = ContentBlock::from(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "base64",
+        ));
+        let tool_use = ContentBlock::from(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
+        ));
+        let tool_result =
+            ContentBlock::from(ToolResult::success_without_content("id"));
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            image.clone(),
+            tool_use.clone(),
+            tool_result.clone(),
+        ]);
+
         assert_eq!(
-            serde_json::to_string(&tool_result_content_block).unwrap(),
-            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}"
+            content.without_images(),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("text"),
+                tool_use,
+                tool_result,
+            ])
         );
-
-        let tool_result_content_block = ToolResultContentBlock {
-            _type: ContentType::ToolResult,
-            tool_result: ToolResult::error("tool_use_id", Some("content")),
-        };
         assert_eq!(
-            serde_json::to_string(&tool_result_content_block).unwrap(),
-            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}"
+            content.without_tool_blocks(),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("text"),
+                image,
+            ])
+        );
+        assert_eq!(
+            Content::SingleText("text".to_string()).without_images(),
+            Content::SingleText("text".to_string())
         );
     }
 
     #[test]
-    fn deserialize_tool_result_content_block() {
-        let tool_result_content_block = ToolResultContentBlock {
-            _type: ContentType::ToolResult,
-            tool_result: ToolResult::success("tool_use_id", Some("content")),
-        };
+    fn replace_all_text() {
+        let content = Content::SingleText("Hi, {name}. {name}?".to_string());
         assert_eq!(
-            serde_json::from_str::<ToolResultContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}").unwrap(),
-            tool_result_content_block
+            content.replace_all_text("{name}", "Claude"),
+            Content::SingleText("Hi, Claude. Claude?".to_string())
         );
+        assert_eq!(content.replace_all_text("", "Claude"), content);
 
-        let tool_result_content_block = ToolResultContentBlock {
-            _type: ContentType::ToolResult,
-            tool_result: ToolResult::error("tool_use_id", Some("content")),
-        };
+        let tool_use = ContentBlock::from(ToolUse::new(
+            "id",
+            "name",
+            serde_json::json!({"arg": "{name}"}),
+        ));
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("{name}"),
+            tool_use.clone(),
+            ContentBlock::from("Bye, {name}."),
+        ]);
         assert_eq!(
-            serde_json::from_str::<ToolResultContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}").unwrap(),
-            tool_result_content_block
+            content.replace_all_text("{name}", "Claude"),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("Claude"),
+                tool_use,
+                ContentBlock::from("Bye, Claude."),
+            ])
         );
     }
 
+    #[cfg(feature = "regex")]
     #[test]
-    fn new_content_block() {
-        let content_block = ContentBlock::Text(TextContentBlock::new(
-            "text".to_string(),
-        ));
+    fn replace_all_regex() {
+        let pattern = regex::Regex::new(r"\{(\w+)\}").unwrap();
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("{first} and {second}"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "{base64}",
+            )),
+        ]);
         assert_eq!(
-            content_block,
-            ContentBlock::Text(TextContentBlock {
-                _type: ContentType::Text,
-                text: "text".to_string(),
-            })
+            content.replace_all_regex(&pattern, "<$1>"),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("<first> and <second>"),
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Png,
+                    "{base64}",
+                )),
+            ])
         );
+    }
 
-        let content_block = ContentBlock::Image(ImageContentBlock::new(
-            ImageContentSource::default(),
+    #[test]
+    fn sort_by_display_priority() {
+        let image = ContentBlock::from(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "base64",
+        ));
+        let document = ContentBlock::from_pdf_bytes(b"%PDF");
+        let tool_use = ContentBlock::from(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
         ));
+        let tool_result =
+            ContentBlock::from(ToolResult::success_without_content("id"));
+        assert_eq!(ContentBlock::from("text").display_priority(), 0);
+        assert_eq!(image.display_priority(), 1);
+        assert_eq!(document.display_priority(), 1);
+        assert_eq!(tool_use.display_priority(), 2);
+        assert_eq!(tool_result.display_priority(), 3);
+
+        let mut content = Content::MultipleBlocks(vec![
+            tool_result.clone(),
+            document.clone(),
+            ContentBlock::from("first"),
+            tool_use.clone(),
+            image.clone(),
+            ContentBlock::from("second"),
+        ]);
+        let sorted = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            ContentBlock::from("second"),
+            document,
+            image,
+            tool_use,
+            tool_result,
+        ]);
+        assert_eq!(content.sorted_for_display(), sorted);
+        assert_ne!(content, sorted);
+
+        content.sort_by_display_priority();
+        assert_eq!(content, sorted);
+
+        let mut content = Content::SingleText("text".to_string());
+        content.sort_by_display_priority();
         assert_eq!(
-            content_block,
-            ContentBlock::Image(ImageContentBlock {
-                _type: ContentType::Image,
-                source: ImageContentSource::default(),
-            })
+            content,
+            Content::SingleText("text".to_string())
         );
+    }
 
-        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
-            ToolUse::new("id", "name", serde_json::Value::Null),
+    #[test]
+    fn find_block() {
+        let tool_use = ContentBlock::from(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
         ));
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            tool_use.clone(),
+            ContentBlock::from("second"),
+        ]);
+
+        assert!(content.contains_block(|block| *block == tool_use));
         assert_eq!(
-            content_block,
-            ContentBlock::ToolUse(ToolUseContentBlock {
-                _type: ContentType::ToolUse,
-                tool_use: ToolUse::new("id", "name", serde_json::Value::Null),
-            })
+            content.find_block(|block| matches!(block, ContentBlock::Text(_))),
+            Some(&ContentBlock::from("first"))
         );
-
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::error("tool_use_id", Some("content")),
-            ));
         assert_eq!(
-            content_block,
-            ContentBlock::ToolResult(ToolResultContentBlock {
-                _type: ContentType::ToolResult,
-                tool_result: ToolResult::error("tool_use_id", Some("content")),
-            })
+            content.find_block(|block| block.display_priority() == 2),
+            Some(&tool_use)
+        );
+        assert!(!content.contains_block(|block| {
+            matches!(block, ContentBlock::Image(_))
+        }));
+        assert_eq!(
+            content.find_block(|block| matches!(block, ContentBlock::Image(_))),
+            None
         );
+
+        let content = Content::SingleText("text".to_string());
+        assert!(content.contains_block(|block| {
+            matches!(block, ContentBlock::Text(_))
+        }));
+        assert_eq!(content.find_block(|_| true), None);
+        assert!(!content.contains_block(|block| {
+            matches!(block, ContentBlock::ToolUse(_))
+        }));
     }
 
     #[test]
-    fn default_content_block() {
-        assert_eq!(
-            ContentBlock::default(),
-            ContentBlock::Text(TextContentBlock::default())
-        );
+    fn has_blocks() {
+        let content = Content::SingleText("text".to_string());
+        assert!(content.has_text());
+        assert!(!content.has_images());
+        assert!(!content.has_tool_uses());
+        assert!(!content.has_tool_results());
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "base64",
+            )),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
+            )),
+        ]);
+        assert!(!content.has_text());
+        assert!(content.has_images());
+        assert!(content.has_tool_uses());
+        assert!(!content.has_tool_results());
+
+        let content = Content::MultipleBlocks(vec![ContentBlock::from(
+            ToolResult::success_without_content("id"),
+        )]);
+        assert!(content.has_tool_results());
+
+        let content = Content::MultipleBlocks(vec![]);
+        assert!(!content.has_text());
     }
 
     #[test]
-    fn display_content_block() {
-        let content_block = ContentBlock::Text(TextContentBlock::new(
-            "text".to_string(),
-        ));
+    fn partition_by_type() {
+        let content = Content::SingleText("text".to_string());
+        let partition = content.partition_by_type();
         assert_eq!(
-            content_block.to_string(),
-            "{\n  \"type\": \"text\",\n  \"text\": \"text\"\n}"
+            partition.texts,
+            vec![Cow::Owned::<TextContentBlock>(TextContentBlock::new(
+                "text"
+            ))]
         );
+        assert!(partition.images.is_empty());
 
-        let content_block = ContentBlock::Image(ImageContentBlock::new(
-            ImageContentSource::default(),
+        let text = TextContentBlock::new("text");
+        let image = ImageContentBlock::new(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "base64",
         ));
-        assert_eq!(
-            content_block.to_string(),
-            "{\n  \"type\": \"image\",\n  \"source\": {\n    \"type\": \"base64\",\n    \"media_type\": \"image/jpeg\",\n    \"data\": \"\"\n  }\n}"
-        );
-
-        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
-            ToolUse::new("id", "name", serde_json::Value::Null),
+        let tool_use = ToolUseContentBlock::new(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
         ));
-        assert_eq!(
-            content_block.to_string(),
-            "{\n  \"type\": \"tool_use\",\n  \"id\": \"id\",\n  \"name\": \"name\",\n  \"input\": null\n}"
+        let tool_result = ToolResultContentBlock::new(
+            ToolResult::success_without_content("id"),
         );
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::Text(text.clone()),
+            ContentBlock::Image(image.clone()),
+            ContentBlock::ToolUse(tool_use.clone()),
+            ContentBlock::ToolResult(tool_result.clone()),
+            ContentBlock::Text(text.clone()),
+        ]);
 
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::success("tool_use_id", Some("content")),
-            ));
+        let partition = content.partition_by_type();
         assert_eq!(
-            content_block.to_string(),
-            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  }\n}"
+            partition.texts,
+            vec![Cow::Borrowed(&text), Cow::Borrowed(&text)]
         );
+        assert_eq!(partition.images, vec![&image]);
+        assert_eq!(partition.tool_uses, vec![&tool_use]);
+        assert_eq!(partition.tool_results, vec![&tool_result]);
 
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::error("tool_use_id", Some("content")),
-            ));
+        let partition = content.into_partition();
         assert_eq!(
-            content_block.to_string(),
-            "{\n  \"type\": \"tool_result\",\n  \"tool_use_id\": \"tool_use_id\",\n  \"content\": {\n    \"type\": \"text\",\n    \"text\": \"content\"\n  },\n  \"is_error\": true\n}"
+            partition,
+            OwnedContentPartition {
+                texts: vec![text.clone(), text],
+                images: vec![image],
+                tool_uses: vec![tool_use],
+                tool_results: vec![tool_result],
+                documents: vec![],
+            }
         );
     }
 
     #[test]
-    fn serialize_content_block() {
-        let content_block = ContentBlock::Text(TextContentBlock::new(
-            "text".to_string(),
-        ));
-        assert_eq!(
-            serde_json::to_string(&content_block).unwrap(),
-            "{\"type\":\"text\",\"text\":\"text\"}"
-        );
+    fn from_image_files() {
+        let directory = std::env::temp_dir().join("clust_from_image_files");
+        std::fs::create_dir_all(&directory).unwrap();
+        let png = directory.join("image.png");
+        let jpg = directory.join("image.jpg");
+        std::fs::write(&png, b"png").unwrap();
+        std::fs::write(&jpg, b"jpg").unwrap();
 
-        let content_block = ContentBlock::Image(ImageContentBlock::new(
-            ImageContentSource::default(),
-        ));
         assert_eq!(
-            serde_json::to_string(&content_block).unwrap(),
-            "{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}"
+            Content::from_image_file(&png).unwrap(),
+            Content::MultipleBlocks(vec![ContentBlock::from(
+                ImageContentSource::base64(ImageMediaType::Png, "cG5n")
+            )])
         );
-
-        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
-            ToolUse::new("id", "name", serde_json::Value::Null),
-        ));
         assert_eq!(
-            serde_json::to_string(&content_block).unwrap(),
-            "{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}"
+            Content::from_image_files(&[&png, &jpg]).unwrap(),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Png,
+                    "cG5n"
+                )),
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Jpeg,
+                    "anBn"
+                )),
+            ])
         );
 
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::success("tool_use_id", Some("content")),
-            ));
-        assert_eq!(
-            serde_json::to_string(&content_block).unwrap(),
-            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}"
-        );
+        assert!(matches!(
+            Content::from_image_file(&directory.join("image.txt")),
+            Err(ImageLoadError::MediaTypeParseError(_))
+        ));
+        assert!(matches!(
+            Content::from_image_file(&directory.join("not_found.png")),
+            Err(ImageLoadError::IoError(_))
+        ));
+    }
 
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::error("tool_use_id", Some("content")),
-            ));
-        assert_eq!(
-            serde_json::to_string(&content_block).unwrap(),
-            "{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}"
-        );
+    #[test]
+    fn text_statistics() {
+        let content = Content::from("");
+        assert_eq!(content.count_characters(), 0);
+        assert_eq!(content.word_count(), 0);
+        assert_eq!(content.line_count(), 0);
+
+        let content = Content::from("Hello, world!\nHow are you?");
+        assert_eq!(content.count_characters(), 26);
+        assert_eq!(content.word_count(), 5);
+        assert_eq!(content.line_count(), 2);
+
+        let content = Content::from("日本語のテキスト");
+        assert_eq!(content.count_characters(), 8);
+        assert_eq!(content.word_count(), 1);
+        assert_eq!(content.line_count(), 1);
+
+        let content = Content::from(vec![
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "data",
+            )),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
+            )),
+        ]);
+        assert_eq!(content.count_characters(), 0);
+        assert_eq!(content.word_count(), 0);
+        assert_eq!(content.line_count(), 0);
+
+        let content = Content::from(vec![
+            ContentBlock::from("Hello,"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "data",
+            )),
+            ContentBlock::from("world!\nBye."),
+        ]);
+        assert_eq!(content.count_characters(), 17);
+        assert_eq!(content.word_count(), 3);
+        assert_eq!(content.line_count(), 3);
     }
 
     #[test]
-    fn deserialize_content_block() {
-        let content_block = ContentBlock::Text(TextContentBlock::new(
-            "text".to_string(),
-        ));
+    fn indexed_access() {
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            serde_json::from_str::<ContentBlock>(
-                "{\"type\":\"text\",\"text\":\"text\"}"
-            )
-            .unwrap(),
-            content_block
+            content.nth_block(0),
+            Some(Cow::Owned(ContentBlock::from("text")))
         );
-
-        let content_block = ContentBlock::Image(ImageContentBlock::new(
-            ImageContentSource::default(),
+        assert_eq!(content.block_at_index(0), None);
+        assert_eq!(content.nth_block(1), None);
+        assert!(content.blocks().is_empty());
+        assert_eq!(content.get_text_at(0), Some("text"));
+        assert_eq!(content.get_text_at(1), None);
+
+        let image = ContentBlock::from(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "data",
         ));
+        let content = Content::MultipleBlocks(vec![
+            image.clone(),
+            ContentBlock::from("text"),
+        ]);
         assert_eq!(
-            serde_json::from_str::<ContentBlock>("{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}").unwrap(),
-            content_block
+            content.nth_block(0),
+            Some(Cow::Borrowed(&image))
         );
+        assert_eq!(content.nth_block(2), None);
+        assert_eq!(content.blocks().len(), 2);
+        assert_eq!(content.get_text_at(0), None);
+        assert_eq!(content.get_text_at(1), Some("text"));
+        assert_eq!(content.get_text_at(2), None);
+    }
 
-        let content_block = ContentBlock::ToolUse(ToolUseContentBlock::new(
-            ToolUse::new("id", "name", serde_json::Value::Null),
-        ));
+    #[test]
+    fn json_value() {
+        let tool_use = ToolUse::new(
+            "id",
+            "name",
+            serde_json::json!({"arg": 1}),
+        );
+        let values = vec![
+            serde_json::json!({"type": "text", "text": "text"}),
+            serde_json::json!({
+                "type": "tool_use",
+                "id": "id",
+                "name": "name",
+                "input": {"arg": 1}
+            }),
+        ];
+
+        let content = Content::from_json_array(values.clone()).unwrap();
         assert_eq!(
-            serde_json::from_str::<ContentBlock>("{\"type\":\"tool_use\",\"id\":\"id\",\"name\":\"name\",\"input\":null}").unwrap(),
-            content_block
+            content,
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("text"),
+                ContentBlock::from(tool_use),
+            ])
         );
+        if let Content::MultipleBlocks(blocks) = content {
+            assert_eq!(
+                blocks
+                    .iter()
+                    .map(ContentBlock::to_json_value)
+                    .collect::<Vec<_>>(),
+                values
+            );
+        }
 
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::success("tool_use_id", Some("content")),
-            ));
+        assert!(ContentBlock::from_json_value(
+            serde_json::json!({"type": "unknown", "text": "text"})
+        )
+        .is_err());
+        assert!(Content::from_json_array(vec![
+            serde_json::json!({"type": "text", "text": "text"}),
+            serde_json::json!({"type": "text"}),
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn first_and_last_text() {
+        let content = Content::SingleText("text".to_string());
+        assert_eq!(content.first_text(), Some("text"));
+        assert_eq!(content.last_text(), Some("text"));
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "data",
+            )),
+            ContentBlock::from("first"),
+            ContentBlock::from("second"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "data",
+            )),
+        ]);
+        assert_eq!(content.first_text(), Some("first"));
+        assert_eq!(content.last_text(), Some("second"));
+
+        let content = Content::MultipleBlocks(vec![ContentBlock::from(
+            ImageContentSource::base64(ImageMediaType::Png, "data"),
+        )]);
+        assert_eq!(content.first_text(), None);
+        assert_eq!(content.last_text(), None);
+    }
+
+    #[test]
+    fn into_single_text() {
+        let content = Content::SingleText("text".to_string());
+        assert_eq!(content.as_single_text(), Some("text"));
         assert_eq!(
-            serde_json::from_str::<ContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\":{\"type\":\"text\",\"text\":\"content\"}}").unwrap(),
-            content_block
+            content.into_single_text(),
+            Some("text".to_string())
         );
 
-        let content_block =
-            ContentBlock::ToolResult(ToolResultContentBlock::new(
-                ToolResult::error("tool_use_id", Some("content")),
-            ));
+        let content = Content::MultipleBlocks(vec![ContentBlock::from("text")]);
+        assert_eq!(content.as_single_text(), Some("text"));
         assert_eq!(
-            serde_json::from_str::<ContentBlock>("{\"type\":\"tool_result\",\"tool_use_id\":\"tool_use_id\",\"content\": {\"type\":\"text\",\"text\":\"content\"},\"is_error\":true}").unwrap(),
-            content_block
+            content.into_single_text(),
+            Some("text".to_string())
         );
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            ContentBlock::from("second"),
+        ]);
+        assert_eq!(content.as_single_text(), None);
+        assert_eq!(content.into_single_text(), None);
+
+        let content = Content::MultipleBlocks(vec![ContentBlock::from(
+            ImageContentSource::base64(ImageMediaType::Png, "data"),
+        )]);
+        assert_eq!(content.as_single_text(), None);
+        assert_eq!(content.into_single_text(), None);
+
+        let content = Content::MultipleBlocks(vec![]);
+        assert_eq!(content.as_single_text(), None);
+        assert_eq!(content.into_single_text(), None);
     }
 
     #[test]
-    fn from_content_block() {
-        assert_eq!(
-            ContentBlock::from("text"),
-            ContentBlock::Text(TextContentBlock::new("text"))
+    fn try_single_tool_use() {
+        let tool_use = ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
         );
 
-        let content_block: ContentBlock = "text".into();
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(tool_use.clone()),
+        ]);
         assert_eq!(
-            content_block,
-            ContentBlock::Text(TextContentBlock::new("text"))
+            content.try_single_tool_use(),
+            Ok(&tool_use)
         );
 
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from(tool_use.clone()),
+            ContentBlock::from(tool_use.clone()),
+        ]);
         assert_eq!(
-            ContentBlock::from(ImageContentSource::default()),
-            ContentBlock::Image(ImageContentBlock::new(
-                ImageContentSource::default()
-            ))
+            content.try_single_tool_use(),
+            Err(ContentFlatteningError::MultipleBlocks)
         );
 
-        let content_block: ContentBlock = ImageContentSource::default().into();
+        let content = Content::MultipleBlocks(vec![ContentBlock::from("text")]);
         assert_eq!(
-            content_block,
-            ContentBlock::Image(ImageContentBlock::new(
-                ImageContentSource::default()
-            ))
+            content.try_single_tool_use(),
+            Err(ContentFlatteningError::NotFoundTargetBlock)
         );
 
+        let content = Content::MultipleBlocks(vec![]);
         assert_eq!(
-            ContentBlock::from(ToolUse::new(
-                "id",
-                "name",
-                serde_json::Value::Null
-            )),
-            ContentBlock::ToolUse(ToolUseContentBlock::new(ToolUse::new(
-                "id",
-                "name",
-                serde_json::Value::Null
-            )))
+            content.try_single_tool_use(),
+            Err(ContentFlatteningError::Empty)
         );
 
-        let content_block: ContentBlock =
-            ToolUse::new("id", "name", serde_json::Value::Null).into();
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            content_block,
-            ContentBlock::ToolUse(ToolUseContentBlock::new(ToolUse::new(
-                "id",
-                "name",
-                serde_json::Value::Null
-            )))
+            content.try_single_tool_use(),
+            Err(ContentFlatteningError::NotFoundTargetBlock)
         );
     }
 
     #[test]
-    fn new_content() {
-        let content = Content::SingleText("text".to_string());
-        assert_eq!(
-            content,
-            Content::SingleText("text".to_string())
-        );
-
+    fn try_single_of_any_type() {
         let content = Content::MultipleBlocks(vec![
-            ContentBlock::Text(TextContentBlock::new(
-                "text".to_string(),
-            )),
-            ContentBlock::Image(ImageContentBlock::new(
-                ImageContentSource::default(),
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "base64",
             )),
+            ContentBlock::from("text"),
         ]);
+
         assert_eq!(
-            content,
-            Content::MultipleBlocks(vec![
-                ContentBlock::Text(TextContentBlock::new(
-                    "text".to_string(),
-                )),
-                ContentBlock::Image(ImageContentBlock::new(
-                    ImageContentSource::default(),
-                )),
-            ])
+            content.try_single_of_any_type(|block| {
+                matches!(block, ContentBlock::Image(_))
+            }),
+            Ok(&ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "base64",
+            )))
+        );
+        assert_eq!(
+            content.try_single_of_any_type(|block| {
+                matches!(block, ContentBlock::Text(_))
+            }),
+            Err(ContentFlatteningError::MultipleBlocks)
         );
     }
 
     #[test]
-    fn default_content() {
+    fn from_text_parts() {
         assert_eq!(
-            Content::default(),
-            Content::SingleText(String::new())
+            Content::from_text_parts(vec![
+                "first".to_string(),
+                "second".to_string()
+            ]),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("first"),
+                ContentBlock::from("second"),
+            ])
+        );
+        assert_eq!(
+            Content::from_text_parts(Vec::<String>::new()),
+            Content::MultipleBlocks(vec![])
         );
     }
 
     #[test]
-    fn display_content() {
-        let content = Content::SingleText("text".to_string());
-        assert_eq!(content.to_string(), "\"text\"");
+    fn from_text_and_image_alternating() {
+        let image = ImageContentSource::base64(ImageMediaType::Png, "base64");
 
-        let content = Content::MultipleBlocks(vec![
-            ContentBlock::Text(TextContentBlock::new(
-                "text".to_string(),
-            )),
-            ContentBlock::Image(ImageContentBlock::new(
-                ImageContentSource::default(),
-            )),
-        ]);
         assert_eq!(
-            content.to_string(),
-            "[\n  {\n    \"type\": \"text\",\n    \"text\": \"text\"\n  },\n  {\n    \"type\": \"image\",\n    \"source\": {\n      \"type\": \"base64\",\n      \"media_type\": \"image/jpeg\",\n      \"data\": \"\"\n    }\n  }\n]"
+            Content::from_text_and_image_alternating(
+                &["first", "second", "third"],
+                vec![image.clone()],
+            ),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("first"),
+                ContentBlock::from(image.clone()),
+                ContentBlock::from("second"),
+                ContentBlock::from("third"),
+            ])
+        );
+        assert_eq!(
+            Content::from_text_and_image_alternating(
+                &["first"],
+                vec![image.clone(), image.clone()],
+            ),
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("first"),
+                ContentBlock::from(image.clone()),
+                ContentBlock::from(image),
+            ])
         );
     }
 
     #[test]
-    fn serialize_content() {
-        let content = Content::SingleText("text".to_string());
-        assert_eq!(
-            serde_json::to_string(&content).unwrap(),
-            "\"text\""
-        );
-
+    fn approximate_token_count() {
+        let content = Content::SingleText("a".repeat(10));
+        assert_eq!(content.approximate_image_token_count(), 0);
+        assert_eq!(content.approximate_total_token_count(), 2);
+
+        let image = ContentBlock::from(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "base64",
+        ));
         let content = Content::MultipleBlocks(vec![
-            ContentBlock::Text(TextContentBlock::new(
-                "text".to_string(),
-            )),
-            ContentBlock::Image(ImageContentBlock::new(
-                ImageContentSource::default(),
+            image.clone(),
+            ContentBlock::from("a".repeat(8)),
+            image,
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
             )),
         ]);
+        assert_eq!(content.approximate_image_token_count(), 2000);
+        assert_eq!(content.approximate_total_token_count(), 2002);
+
         assert_eq!(
-            serde_json::to_string(&content).unwrap(),
-            "[{\"type\":\"text\",\"text\":\"text\"},{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}]"
+            Content::MultipleBlocks(vec![]).approximate_total_token_count(),
+            0
         );
     }
 
     #[test]
-    fn deserialize_content() {
-        let content = Content::SingleText("text".to_string());
+    fn display_readable() {
+        let content = Content::SingleText("single\ntext".to_string());
         assert_eq!(
-            serde_json::from_str::<Content>("\"text\"").unwrap(),
             content
+                .display_readable()
+                .to_string(),
+            "single\ntext"
         );
 
         let content = Content::MultipleBlocks(vec![
-            ContentBlock::Text(TextContentBlock::new(
-                "text".to_string(),
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "base64",
             )),
-            ContentBlock::Image(ImageContentBlock::new(
-                ImageContentSource::default(),
+            ContentBlock::from_pdf_bytes(b"%PDF"),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
             )),
+            ContentBlock::from(ToolResult::success_without_content("id")),
         ]);
         assert_eq!(
-            serde_json::from_str::<Content>("[{\"type\":\"text\",\"text\":\"text\"},{\"type\":\"image\",\"source\":{\"type\":\"base64\",\"media_type\":\"image/jpeg\",\"data\":\"\"}}]").unwrap(),
             content
+                .display_readable()
+                .to_string(),
+            "text\n[IMAGE]\n[DOCUMENT]\n[TOOL_USE]\n[TOOL_RESULT]"
+        );
+
+        assert_eq!(
+            Content::MultipleBlocks(vec![])
+                .display_readable()
+                .to_string(),
+            ""
         );
     }
 
     #[test]
-    fn from_content() {
+    fn iter_indexed() {
+        let image = ContentBlock::from(ImageContentSource::base64(
+            ImageMediaType::Png,
+            "base64",
+        ));
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            image.clone(),
+        ]);
         assert_eq!(
-            Content::from("text"),
-            Content::SingleText("text".to_string())
+            content
+                .iter_indexed()
+                .map(|(index, block)| (index, block.into_owned()))
+                .collect::<Vec<_>>(),
+            vec![
+                (0, ContentBlock::from("text")),
+                (1, image.clone()),
+            ]
         );
-
-        let content: Content = "text".into();
         assert_eq!(
-            content,
-            Content::SingleText("text".to_string())
+            content.nth_block(1),
+            Some(Cow::Borrowed(&image))
         );
-
+        assert_eq!(content.nth_block(2), None);
         assert_eq!(
-            Content::from(vec![ContentBlock::from(
-                "text"
-            )]),
-            Content::MultipleBlocks(vec![ContentBlock::Text(
-                TextContentBlock::new("text")
-            )])
+            content.block_at_index(1),
+            Some(&image)
         );
+        assert_eq!(content.block_at_index(2), None);
 
-        let content: Content = vec!["text".into()].into();
+        let content = Content::SingleText("text".to_string());
         assert_eq!(
-            content,
-            Content::MultipleBlocks(vec![ContentBlock::Text(
-                TextContentBlock::new("text")
-            )])
+            content
+                .iter_indexed()
+                .map(|(index, block)| (index, block.into_owned()))
+                .collect::<Vec<_>>(),
+            vec![(0, ContentBlock::from("text"))]
         );
-
         assert_eq!(
-            Content::from(ImageContentSource::default()),
-            Content::MultipleBlocks(vec![ContentBlock::Image(
-                ImageContentBlock::new(ImageContentSource::default())
-            )])
+            content.nth_block(0),
+            Some(Cow::Owned(ContentBlock::from("text")))
         );
+        assert_eq!(content.block_at_index(0), None);
 
-        let content: Content = ImageContentSource::default().into();
         assert_eq!(
-            content,
-            Content::MultipleBlocks(vec![ContentBlock::Image(
-                ImageContentBlock::new(ImageContentSource::default())
-            )])
+            Content::MultipleBlocks(vec![])
+                .iter_indexed()
+                .count(),
+            0
         );
     }
 
     #[test]
-    fn flatten_into_text() {
+    fn iter_blocks_by_type() {
+        let tool_use = ToolUse::new("id", "name", serde_json::Value::Null);
+        let tool_result = ToolResult::success_without_content("id");
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("first"),
+            ContentBlock::from(tool_use.clone()),
+            ContentBlock::from(tool_result.clone()),
+            ContentBlock::from("second"),
+        ]);
         assert_eq!(
-            Content::from("text")
-                .flatten_into_text()
-                .unwrap(),
-            "text"
+            content
+                .iter_text_blocks()
+                .map(Cow::into_owned)
+                .collect::<Vec<_>>(),
+            vec![
+                TextContentBlock::new("first"),
+                TextContentBlock::new("second"),
+            ]
         );
-
         assert_eq!(
-            Content::from(vec![
-                ContentBlock::from("text"),
-                ContentBlock::from(ImageContentSource::default()),
-            ])
-            .flatten_into_text()
-            .unwrap(),
-            "text"
+            content
+                .iter_tool_uses()
+                .collect::<Vec<_>>(),
+            vec![&tool_use]
         );
-
         assert_eq!(
-            Content::from(vec![
-                ContentBlock::from("text"),
-                ContentBlock::from("second"),
-            ])
-            .flatten_into_text()
-            .unwrap(),
-            "text"
+            content
+                .iter_tool_results()
+                .collect::<Vec<_>>(),
+            vec![&tool_result]
         );
 
-        assert!(Content::from(vec![])
-            .flatten_into_text()
-            .is_err());
-
-        assert!(
-            Content::from(ImageContentSource::default())
-                .flatten_into_text()
-                .is_err()
+        let content = Content::SingleText("text".to_string());
+        assert_eq!(
+            content
+                .iter_text_blocks()
+                .map(Cow::into_owned)
+                .collect::<Vec<_>>(),
+            vec![TextContentBlock::new("text")]
+        );
+        assert_eq!(
+            content
+                .iter_tool_uses()
+                .count(),
+            0
+        );
+        assert_eq!(
+            content
+                .iter_tool_results()
+                .count(),
+            0
         );
-
-        assert!(Content::from(vec![
-            ContentBlock::from(ImageContentSource::default()),
-            ContentBlock::from("text"),
-        ])
-        .flatten_into_text()
-        .is_err());
     }
 
     #[test]
-    fn flatten_into_image_source() {
-        assert!(Content::from("text")
-            .flatten_into_image_source()
-            .is_err());
-
-        assert!(Content::from(vec![
-            ContentBlock::from("text"),
-            ContentBlock::from(ImageContentSource::default()),
-        ])
-        .flatten_into_image_source()
-        .is_err());
-
-        assert!(Content::from(vec![
-            ContentBlock::from("text"),
-            ContentBlock::from("second"),
-        ])
-        .flatten_into_image_source()
-        .is_err());
-
-        assert!(Content::from(vec![])
-            .flatten_into_image_source()
-            .is_err());
-
+    fn type_summary() {
         assert_eq!(
-            *Content::from(ImageContentSource::default())
-                .flatten_into_image_source()
-                .unwrap(),
-            ImageContentSource::default()
+            Content::SingleText("text".to_string()).type_summary(),
+            "SingleText"
+        );
+        assert_eq!(
+            Content::MultipleBlocks(vec![]).type_summary(),
+            "[]"
         );
 
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "base64",
+            )),
+            ContentBlock::from_pdf_bytes(b"%PDF"),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "get_weather",
+                serde_json::Value::Null,
+            )),
+            ContentBlock::from(ToolResult::success_without_content("id")),
+        ]);
         assert_eq!(
-            *Content::from(vec![
-                ContentBlock::from(ImageContentSource::default()),
-                ContentBlock::from("text"),
-            ])
-            .flatten_into_image_source()
-            .unwrap(),
-            ImageContentSource::default()
+            content.type_summary(),
+            "[Text, Image, Document, ToolUse(get_weather), ToolResult]"
         );
     }
 
     #[test]
-    fn flatten_into_tool_use() {
-        assert!(Content::from("text")
-            .flatten_into_tool_use()
-            .is_err());
-
-        assert!(Content::from(vec![
-            ContentBlock::from("text"),
-            ContentBlock::from(ImageContentSource::default()),
-        ])
-        .flatten_into_tool_use()
-        .is_err());
-
-        assert!(Content::from(vec![
+    fn debug_display() {
+        let long_data = "A".repeat(100);
+        let content = Content::MultipleBlocks(vec![
             ContentBlock::from("text"),
-            ContentBlock::from("second"),
-        ])
-        .flatten_into_tool_use()
-        .is_err());
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                long_data.clone(),
+            )),
+            ContentBlock::from(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "short",
+            )),
+        ]);
 
-        assert!(Content::from(vec![])
-            .flatten_into_tool_use()
-            .is_err());
+        let display = content
+            .debug_display()
+            .to_string();
+        assert!(display.contains("\"data\": \"<BASE64 100 bytes>\""));
+        assert!(display.contains("\"data\": \"short\""));
+        assert!(!display.contains(&long_data));
+        assert!(content
+            .to_string()
+            .contains(&long_data));
+    }
 
-        assert!(
-            Content::from(ImageContentSource::default())
-                .flatten_into_tool_use()
-                .is_err()
+    #[test]
+    fn debug_image_content_source() {
+        let source = ImageContentSource::base64(
+            ImageMediaType::Png,
+            "A".repeat(100),
+        );
+        assert_eq!(
+            format!("{:?}", source),
+            "ImageContentSource { _type: Base64, media_type: Png, data: <BASE64 100 bytes> }"
         );
 
+        let source = ImageContentSource::base64(ImageMediaType::Png, "short");
         assert_eq!(
-            Content::from(vec![
-                ContentBlock::from("text"),
-                ContentBlock::from(ToolUse::new(
-                    "id",
-                    "name",
-                    serde_json::Value::Object(serde_json::Map::new())
-                )),
-            ])
-            .flatten_into_tool_use()
-            .unwrap(),
-            ToolUse::new(
-                "id",
-                "name",
-                serde_json::Value::Object(serde_json::Map::new())
-            )
+            format!("{:?}", source),
+            "ImageContentSource { _type: Base64, media_type: Png, data: \"short\" }"
         );
     }
 
     #[test]
-    fn flatten_into_tool_result() {
-        assert!(Content::from("text")
-            .flatten_into_tool_result()
-            .is_err());
-
-        assert!(Content::from(vec![
-            ContentBlock::from("text"),
-            ContentBlock::from(ImageContentSource::default()),
-        ])
-        .flatten_into_tool_result()
-        .is_err());
-
-        assert!(Content::from(vec![
-            ContentBlock::from("text"),
-            ContentBlock::from("second"),
-        ])
-        .flatten_into_tool_result()
-        .is_err());
-
-        assert!(Content::from(vec![])
-            .flatten_into_tool_result()
-            .is_err());
-
-        assert!(
-            Content::from(ImageContentSource::default())
-                .flatten_into_tool_result()
-                .is_err()
+    fn search_text() {
+        let content = Content::SingleText("abcabc".to_string());
+        assert!(content.contains_str("ca"));
+        assert!(!content.contains_str("cb"));
+        assert_eq!(
+            content.search_text("bc"),
+            vec![(1, 3), (4, 6)]
         );
+        assert!(content.starts_with_text("abc"));
+        assert!(content.ends_with_text("cabc"));
 
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("abc"),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
+            )),
+            ContentBlock::from("日本語"),
+        ]);
+        assert!(content.contains_str("本"));
+        assert!(content.contains_str("c日"));
         assert_eq!(
-            Content::from(vec![
-                ContentBlock::from(ToolResult::success(
-                    "tool_use_id",
-                    Some("content")
-                )),
-                ContentBlock::from("text"),
-            ])
-            .flatten_into_tool_result()
-            .unwrap(),
-            ToolResult::success("tool_use_id", Some("content"))
+            content.search_text("c日"),
+            vec![(2, 6)]
         );
+        assert!(content
+            .search_text("name")
+            .is_empty());
+        assert!(content.starts_with_text("abc日"));
+        assert!(content.ends_with_text("語"));
+        assert!(!content.ends_with_text("abc"));
     }
 }
@@ -0,0 +1,1033 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::messages::{
+    ClaudeModel, Content, ContentBlock, ConversationError, MaxTokens, Message,
+    MessagesError, MessagesRequestBody, MessagesResponseBody, Role,
+    StopReason, SystemPrompt, TokenBudget, ToolChoice, ToolDefinition,
+    ToolList, ToolResult,
+};
+use crate::Client;
+
+/// A conversation with the assistant that holds the message history.
+///
+/// ## Example
+/// ```rust,no_run
+/// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message, SystemPrompt};
+/// use clust::Client;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::from_env()?;
+/// let model = ClaudeModel::Claude3Haiku20240307;
+/// let mut conversation = Conversation::new(model, MaxTokens::new(1024, model)?)
+///     .with_system(SystemPrompt::new("You are an excellent AI assistant."));
+///
+/// let response = conversation
+///     .send(&client, Message::user("Where is the capital of Japan?"))
+///     .await?;
+/// let response = conversation
+///     .send(&client, Message::user("What is the population of the city?"))
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Conversation {
+    /// The model that will complete your prompt.
+    model: ClaudeModel,
+    /// The maximum number of tokens to generate before stopping.
+    max_tokens: MaxTokens,
+    /// System prompt.
+    system: Option<SystemPrompt>,
+    /// The message history.
+    messages: Vec<Message>,
+    /// The token budget across calls.
+    token_budget: Option<TokenBudget>,
+    /// Definitions of tools that the model may use.
+    tool_definitions: Vec<ToolDefinition>,
+    /// How the model should use the provided tools.
+    tool_choice: Option<ToolChoice>,
+    /// The number of times `fork()` was called from this instance.
+    branch_points: AtomicUsize,
+}
+
+impl Clone for Conversation {
+    fn clone(&self) -> Self {
+        Self {
+            model: self.model,
+            max_tokens: self.max_tokens,
+            system: self.system.clone(),
+            messages: self.messages.clone(),
+            token_budget: self.token_budget,
+            tool_definitions: self.tool_definitions.clone(),
+            tool_choice: self.tool_choice.clone(),
+            branch_points: AtomicUsize::new(self.branch_points()),
+        }
+    }
+}
+
+impl Conversation {
+    /// Creates a new empty conversation.
+    ///
+    /// ## Arguments
+    /// - `model` - The model that will complete your prompt.
+    /// - `max_tokens` - The maximum number of tokens to generate before stopping.
+    pub fn new(
+        model: ClaudeModel,
+        max_tokens: MaxTokens,
+    ) -> Self {
+        Self {
+            model,
+            max_tokens,
+            system: None,
+            messages: Vec::new(),
+            token_budget: None,
+            tool_definitions: Vec::new(),
+            tool_choice: None,
+            branch_points: AtomicUsize::new(0),
+        }
+    }
+
+    /// Reconstructs a conversation from a saved message history.
+    ///
+    /// ## Arguments
+    /// - `model` - The model that will complete your prompt.
+    /// - `max_tokens` - The maximum number of tokens to generate before stopping.
+    /// - `system` - The system prompt.
+    /// - `messages` - The message history.
+    ///
+    /// ## Errors
+    /// It returns an error if the messages do not start with `Role::User` or their roles do not alternate.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message};
+    ///
+    /// let conversation = Conversation::from_messages(
+    ///     ClaudeModel::Claude3Haiku20240307,
+    ///     MaxTokens::default(),
+    ///     None,
+    ///     vec![
+    ///         Message::user("Hello, Claude!"),
+    ///         Message::assistant("Hello!"),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn from_messages(
+        model: ClaudeModel,
+        max_tokens: MaxTokens,
+        system: Option<SystemPrompt>,
+        messages: Vec<Message>,
+    ) -> Result<Self, ConversationError> {
+        validate_roles(&messages)?;
+
+        Ok(Self {
+            model,
+            max_tokens,
+            system,
+            messages,
+            token_budget: None,
+            tool_definitions: Vec::new(),
+            tool_choice: None,
+            branch_points: AtomicUsize::new(0),
+        })
+    }
+
+    /// Sets the system prompt.
+    pub fn with_system(
+        mut self,
+        system: SystemPrompt,
+    ) -> Self {
+        self.system = Some(system);
+        self
+    }
+
+    /// Sets the token budget to adjust `max_tokens` per call.
+    pub fn with_token_budget(
+        mut self,
+        token_budget: TokenBudget,
+    ) -> Self {
+        self.token_budget = Some(token_budget);
+        self
+    }
+
+    /// Sets the definitions of tools that the model may use.
+    pub fn with_tool_definitions(
+        mut self,
+        tool_definitions: Vec<ToolDefinition>,
+    ) -> Self {
+        self.tool_definitions = tool_definitions;
+        self
+    }
+
+    /// Sets how the model should use the provided tools.
+    pub fn with_tool_choice(
+        mut self,
+        tool_choice: ToolChoice,
+    ) -> Self {
+        self.tool_choice = Some(tool_choice);
+        self
+    }
+
+    /// The model of this conversation.
+    pub fn model(&self) -> ClaudeModel {
+        self.model
+    }
+
+    /// The maximum number of tokens of this conversation.
+    pub fn max_tokens(&self) -> MaxTokens {
+        self.max_tokens
+    }
+
+    /// The system prompt of this conversation.
+    pub fn system(&self) -> Option<&SystemPrompt> {
+        self.system.as_ref()
+    }
+
+    /// The token budget of this conversation.
+    pub fn token_budget(&self) -> Option<&TokenBudget> {
+        self.token_budget.as_ref()
+    }
+
+    /// The message history of this conversation.
+    pub fn messages(&self) -> &[Message] {
+        &self.messages
+    }
+
+    /// Appends a message to the history without calling the API.
+    pub fn push(
+        &mut self,
+        message: Message,
+    ) {
+        self.messages.push(message);
+    }
+
+    /// Appends the messages to the history without calling the API.
+    ///
+    /// ## Arguments
+    /// - `messages` - The messages to append.
+    ///
+    /// ## Errors
+    /// It returns an error and keeps the history unchanged if the roles of the history do not alternate after appending.
+    pub fn try_extend<I>(
+        &mut self,
+        messages: I,
+    ) -> Result<(), ConversationError>
+    where
+        I: IntoIterator<Item = Message>,
+    {
+        let len = self.messages.len();
+        self.messages.extend(messages);
+
+        if let Err(error) = validate_roles(&self.messages) {
+            self.messages.truncate(len);
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Removes the last message from the history and returns it.
+    pub fn pop_last_message(&mut self) -> Option<Message> {
+        self.messages.pop()
+    }
+
+    /// Removes the last turn, the last two messages of a user message and an assistant response, from the history.
+    ///
+    /// It returns `false` without removing any message if the history has less than two messages
+    /// or the last two messages are not a user message followed by an assistant message.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message};
+    ///
+    /// let mut conversation = Conversation::from_messages(
+    ///     ClaudeModel::Claude3Haiku20240307,
+    ///     MaxTokens::default(),
+    ///     None,
+    ///     vec![
+    ///         Message::user("Hello, Claude!"),
+    ///         Message::assistant("Hello!"),
+    ///         Message::user("Tell me a joke."),
+    ///         Message::assistant("An unsatisfactory joke."),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert!(conversation.undo_last_turn());
+    /// assert_eq!(conversation.messages().len(), 2);
+    /// ```
+    pub fn undo_last_turn(&mut self) -> bool {
+        match self.messages.as_slice() {
+            | [.., user, assistant]
+                if user.role == Role::User
+                    && assistant.role == Role::Assistant =>
+            {
+                self.messages
+                    .truncate(self.messages.len() - 2);
+                true
+            },
+            | _ => false,
+        }
+    }
+
+    /// Removes all messages from the history while keeping the settings.
+    pub fn clear_messages(&mut self) -> &mut Self {
+        self.messages.clear();
+        self
+    }
+
+    /// Creates a request body with the current message history.
+    ///
+    /// `max_tokens` is limited by `TokenBudget::safe_max_tokens` if the token budget is set and not exhausted.
+    pub fn request_body(&self) -> MessagesRequestBody {
+        let max_tokens = match self
+            .token_budget
+            .and_then(|token_budget| token_budget.safe_max_tokens())
+        {
+            | Some(safe_max_tokens) => self.max_tokens.min(safe_max_tokens),
+            | None => self.max_tokens,
+        };
+
+        let request_body = MessagesRequestBody {
+            model: self.model,
+            messages: self.messages.clone(),
+            system: self.system.clone(),
+            max_tokens,
+            ..Default::default()
+        }
+        .with_tools(self.tool_definitions.clone());
+
+        match &self.tool_choice {
+            | Some(tool_choice) => {
+                request_body.with_tool_choice(tool_choice.clone())
+            },
+            | None => request_body,
+        }
+    }
+
+    /// Sends a message and appends both it and the assistant response to the history.
+    ///
+    /// ## Arguments
+    /// - `client` - The API client.
+    /// - `message` - The message to send.
+    ///
+    /// ## Errors
+    /// It returns an error if the API call fails. The history is not changed in that case.
+    pub async fn send(
+        &mut self,
+        client: &Client,
+        message: Message,
+    ) -> Result<MessagesResponseBody, MessagesError> {
+        self.messages.push(message);
+
+        match self.send_history(client).await {
+            | Ok(response) => Ok(response),
+            | Err(error) => {
+                self.messages.pop();
+                Err(error)
+            },
+        }
+    }
+
+    /// Sends the current history and runs the tool use loop.
+    ///
+    /// While the assistant stops with `StopReason::ToolUse`, it calls the requested tools by the executor
+    /// and appends the tool results as a user message, then sends the history again.
+    /// A tool call error is sent back to the assistant as an error tool result.
+    ///
+    /// Append a user message by `Conversation::push` before calling this.
+    ///
+    /// ## Arguments
+    /// - `client` - The API client.
+    /// - `executor` - The tools to call.
+    /// - `max_iterations` - The maximum number of API calls. At least one call is made.
+    ///
+    /// ## Errors
+    /// It returns an error if an API call fails. The history until the failed call is kept in that case.
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message, ToolList};
+    /// use clust::Client;
+    ///
+    /// # async fn run(tools: ToolList) -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::from_env()?;
+    /// let mut conversation = Conversation::new(ClaudeModel::Claude3Haiku20240307, MaxTokens::default())
+    ///     .with_tool_definitions(tools.definitions());
+    ///
+    /// conversation.push(Message::user("What is the weather like in Tokyo?"));
+    /// let response = conversation
+    ///     .send_with_tool_loop(&client, &tools, 5)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_tool_loop(
+        &mut self,
+        client: &Client,
+        executor: &ToolList,
+        max_iterations: usize,
+    ) -> Result<MessagesResponseBody, MessagesError> {
+        let mut response = self.send_history(client).await?;
+
+        for _ in 1..max_iterations {
+            if response.stop_reason != Some(StopReason::ToolUse) {
+                break;
+            }
+
+            self.messages
+                .push(call_tools(executor, &response.content));
+            response = self.send_history(client).await?;
+        }
+
+        Ok(response)
+    }
+
+    /// Sends the current history and appends the assistant response to the history.
+    ///
+    /// It returns `MessagesError::TokenBudgetExhausted` without sending if the token budget is exhausted.
+    async fn send_history(
+        &mut self,
+        client: &Client,
+    ) -> Result<MessagesResponseBody, MessagesError> {
+        if self
+            .token_budget
+            .is_some_and(|token_budget| token_budget.is_input_exhausted())
+        {
+            return Err(MessagesError::TokenBudgetExhausted);
+        }
+
+        let response = client
+            .create_a_message(self.request_body())
+            .await?;
+
+        if let Some(token_budget) = &mut self.token_budget {
+            token_budget.update_from_usage(&response.usage);
+        }
+        self.messages
+            .push(response.clone().crate_message());
+
+        Ok(response)
+    }
+
+    /// Creates a deep clone of this conversation at the current state to explore another continuation.
+    ///
+    /// The forked conversation starts with zero branch points.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message};
+    ///
+    /// let mut conversation = Conversation::new(ClaudeModel::Claude3Haiku20240307, MaxTokens::default());
+    /// conversation.push(Message::user("Hello, Claude!"));
+    ///
+    /// let mut fork = conversation.fork();
+    /// fork.push(Message::assistant("Hello!"));
+    ///
+    /// assert_eq!(conversation.messages().len(), 1);
+    /// assert_eq!(fork.messages().len(), 2);
+    /// assert_eq!(conversation.branch_points(), 1);
+    /// ```
+    pub fn fork(&self) -> Conversation {
+        self.branch_points
+            .fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            model: self.model,
+            max_tokens: self.max_tokens,
+            system: self.system.clone(),
+            messages: self.messages.clone(),
+            token_budget: self.token_budget,
+            tool_definitions: self.tool_definitions.clone(),
+            tool_choice: self.tool_choice.clone(),
+            branch_points: AtomicUsize::new(0),
+        }
+    }
+
+    /// Creates a branch of this conversation from the message at the index to explore another continuation.
+    ///
+    /// The branch has the messages up to and including the index with the same settings.
+    /// Since the message history starts with `Role::User` and alternates roles, the branch ends on a user message at an even index and an assistant message at an odd index.
+    /// It counts as a branch point as `fork()`.
+    ///
+    /// ## Arguments
+    /// - `message_index` - The index of the last message of the branch.
+    ///
+    /// ## Errors
+    /// It returns `ConversationError::IndexOutOfBounds` if the index is out of bounds of the messages.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message};
+    ///
+    /// let conversation = Conversation::from_messages(
+    ///     ClaudeModel::Claude3Haiku20240307,
+    ///     MaxTokens::default(),
+    ///     None,
+    ///     vec![
+    ///         Message::user("Hello, Claude!"),
+    ///         Message::assistant("Hello!"),
+    ///         Message::user("How are you?"),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// let mut branch = conversation.branch_from(0).unwrap();
+    /// branch.push(Message::assistant("Hi!"));
+    ///
+    /// assert_eq!(branch.messages().len(), 2);
+    /// assert_eq!(conversation.messages().len(), 3);
+    /// ```
+    pub fn branch_from(
+        &self,
+        message_index: usize,
+    ) -> Result<Conversation, ConversationError> {
+        if message_index >= self.messages.len() {
+            return Err(ConversationError::IndexOutOfBounds {
+                index: message_index,
+                len: self.messages.len(),
+            });
+        }
+
+        let mut branch = self.fork();
+        branch
+            .messages
+            .truncate(message_index + 1);
+
+        Ok(branch)
+    }
+
+    /// The number of times `fork()` was called from this instance.
+    pub fn branch_points(&self) -> usize {
+        self.branch_points
+            .load(Ordering::Relaxed)
+    }
+}
+
+/// Appends the messages to the history by `Conversation::try_extend`.
+///
+/// ## Panics
+/// It panics if the roles of the history do not alternate after appending.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{ClaudeModel, Conversation, MaxTokens, Message};
+///
+/// let saved_history = vec![
+///     Message::user("Hello, Claude!"),
+///     Message::assistant("Hello!"),
+/// ];
+///
+/// let mut conversation = Conversation::new(
+///     ClaudeModel::Claude3Haiku20240307,
+///     MaxTokens::default(),
+/// );
+/// conversation.extend(saved_history);
+/// assert_eq!(conversation.messages().len(), 2);
+/// ```
+impl Extend<Message> for Conversation {
+    fn extend<T: IntoIterator<Item = Message>>(
+        &mut self,
+        iter: T,
+    ) {
+        if let Err(error) = self.try_extend(iter) {
+            panic!("Failed to extend the conversation: {}", error);
+        }
+    }
+}
+
+/// Collects the messages into a conversation with the default model and the default max tokens.
+///
+/// ## Panics
+/// It panics if the messages do not start with `Role::User` or their roles do not alternate.
+impl FromIterator<Message> for Conversation {
+    fn from_iter<T: IntoIterator<Item = Message>>(iter: T) -> Self {
+        match Self::from_messages(
+            ClaudeModel::default(),
+            MaxTokens::default(),
+            None,
+            iter.into_iter().collect(),
+        ) {
+            | Ok(conversation) => conversation,
+            | Err(error) => {
+                panic!("Failed to collect the conversation: {}", error)
+            },
+        }
+    }
+}
+
+/// Calls the tools requested in the content and creates a user message with the tool results.
+fn call_tools(
+    executor: &ToolList,
+    content: &Content,
+) -> Message {
+    let tool_results = content
+        .partition_by_type()
+        .tool_uses
+        .into_iter()
+        .map(|block| {
+            let tool_use = block.tool_use.clone();
+            let tool_use_id = tool_use.id.clone();
+            match executor.call(tool_use) {
+                | Ok(tool_result) => ContentBlock::from(tool_result),
+                | Err(error) => ContentBlock::from(ToolResult::error(
+                    tool_use_id,
+                    Some(error.to_string()),
+                )),
+            }
+        })
+        .collect();
+
+    Message::user(Content::MultipleBlocks(tool_results))
+}
+
+/// Validates that the messages start with `Role::User` and alternate roles.
+fn validate_roles(messages: &[Message]) -> Result<(), ConversationError> {
+    if let Some(first) = messages.first() {
+        if first.role != Role::User {
+            return Err(ConversationError::FirstMessageNotUser);
+        }
+    }
+
+    for (index, pair) in messages
+        .windows(2)
+        .enumerate()
+    {
+        if pair[0].role == pair[1].role {
+            return Err(ConversationError::RolesNotAlternating(index + 1));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let conversation = Conversation::new(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+        )
+        .with_system(SystemPrompt::new("system-prompt"));
+
+        assert_eq!(
+            conversation.model(),
+            ClaudeModel::Claude3Haiku20240307
+        );
+        assert_eq!(
+            conversation.system(),
+            Some(&SystemPrompt::new("system-prompt"))
+        );
+        assert!(conversation
+            .messages()
+            .is_empty());
+        assert_eq!(conversation.branch_points(), 0);
+    }
+
+    #[test]
+    fn request_body() {
+        let mut conversation = Conversation::new(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+        );
+        conversation.push(Message::user("user-message"));
+
+        assert_eq!(
+            conversation.request_body(),
+            MessagesRequestBody {
+                model: ClaudeModel::Claude3Haiku20240307,
+                messages: vec![Message::user("user-message")],
+                max_tokens: MaxTokens::default(),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn request_body_with_token_budget() {
+        let model = ClaudeModel::Claude3Haiku20240307;
+        let mut token_budget = TokenBudget::new(model, 10000);
+        token_budget.update_from_usage(&crate::messages::Usage {
+            input_tokens: 9500,
+            output_tokens: 0,
+            ..Default::default()
+        });
+
+        let conversation =
+            Conversation::new(model, MaxTokens::new(1024, model).unwrap())
+                .with_token_budget(token_budget);
+
+        assert_eq!(
+            conversation
+                .request_body()
+                .max_tokens,
+            MaxTokens::new(500, model).unwrap()
+        );
+
+        token_budget.update_from_usage(&crate::messages::Usage {
+            input_tokens: 500,
+            output_tokens: 0,
+            ..Default::default()
+        });
+        let conversation =
+            Conversation::new(model, MaxTokens::new(1024, model).unwrap())
+                .with_token_budget(token_budget);
+
+        assert_eq!(
+            conversation
+                .request_body()
+                .max_tokens,
+            MaxTokens::new(1024, model).unwrap()
+        );
+    }
+
+    #[test]
+    fn fork() {
+        let mut conversation = Conversation::new(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+        )
+        .with_system(SystemPrompt::new("system-prompt"));
+        conversation.push(Message::user("user-message"));
+
+        let mut first = conversation.fork();
+        let second = conversation.fork();
+        first.push(Message::assistant("assistant-message"));
+
+        assert_eq!(conversation.branch_points(), 2);
+        assert_eq!(first.branch_points(), 0);
+        assert_eq!(conversation.messages().len(), 1);
+        assert_eq!(first.messages().len(), 2);
+        assert_eq!(
+            second.messages(),
+            conversation.messages()
+        );
+        assert_eq!(second.system(), conversation.system());
+    }
+
+    #[test]
+    fn branch_from() {
+        let conversation = Conversation::from_messages(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+            Some(SystemPrompt::new("system-prompt")),
+            vec![
+                Message::user("first"),
+                Message::assistant("second"),
+                Message::user("third"),
+                Message::assistant("fourth"),
+            ],
+        )
+        .unwrap();
+
+        let branch = conversation
+            .branch_from(1)
+            .unwrap();
+        assert_eq!(
+            branch.messages(),
+            &conversation.messages()[..2]
+        );
+        assert_eq!(branch.system(), conversation.system());
+        assert_eq!(branch.model(), conversation.model());
+        assert_eq!(
+            branch
+                .messages()
+                .last()
+                .unwrap()
+                .role,
+            Role::Assistant
+        );
+
+        let branch = conversation
+            .branch_from(2)
+            .unwrap();
+        assert_eq!(
+            branch
+                .messages()
+                .last()
+                .unwrap()
+                .role,
+            Role::User
+        );
+
+        let branch = conversation
+            .branch_from(3)
+            .unwrap();
+        assert_eq!(
+            branch.messages(),
+            conversation.messages()
+        );
+        assert_eq!(conversation.branch_points(), 3);
+
+        assert_eq!(
+            conversation
+                .branch_from(4)
+                .unwrap_err(),
+            ConversationError::IndexOutOfBounds {
+                index: 4,
+                len: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn undo() {
+        let mut conversation = Conversation::from_messages(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+            Some(SystemPrompt::new("system-prompt")),
+            vec![
+                Message::user("first"),
+                Message::assistant("second"),
+                Message::user("third"),
+                Message::assistant("fourth"),
+                Message::user("fifth"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            conversation.pop_last_message(),
+            Some(Message::user("fifth"))
+        );
+        assert!(conversation.undo_last_turn());
+        assert_eq!(
+            conversation.messages(),
+            &[
+                Message::user("first"),
+                Message::assistant("second"),
+            ]
+        );
+        assert!(conversation.undo_last_turn());
+        assert!(conversation
+            .messages()
+            .is_empty());
+        assert!(!conversation.undo_last_turn());
+        assert_eq!(conversation.pop_last_message(), None);
+
+        conversation.push(Message::user("first"));
+        assert!(!conversation.undo_last_turn());
+        assert_eq!(conversation.messages().len(), 1);
+
+        conversation.push(Message::assistant("second"));
+        conversation.push(Message::user("third"));
+        assert!(!conversation.undo_last_turn());
+        assert_eq!(
+            conversation.messages(),
+            &[
+                Message::user("first"),
+                Message::assistant("second"),
+                Message::user("third"),
+            ]
+        );
+
+        conversation
+            .clear_messages()
+            .push(Message::user("new"));
+        assert_eq!(
+            conversation.messages(),
+            &[Message::user("new")]
+        );
+        assert_eq!(
+            conversation.system(),
+            Some(&SystemPrompt::new("system-prompt"))
+        );
+    }
+
+    #[test]
+    fn extend() {
+        let mut conversation = Conversation::new(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+        );
+        conversation.extend(vec![
+            Message::user("first"),
+            Message::assistant("second"),
+        ]);
+        conversation.extend([Message::user("third")]);
+        assert_eq!(conversation.messages().len(), 3);
+
+        assert_eq!(
+            conversation
+                .try_extend([
+                    Message::assistant("fourth"),
+                    Message::assistant("fifth"),
+                ])
+                .unwrap_err(),
+            ConversationError::RolesNotAlternating(4)
+        );
+        assert_eq!(conversation.messages().len(), 3);
+
+        let conversation = vec![
+            Message::user("first"),
+            Message::assistant("second"),
+        ]
+        .into_iter()
+        .collect::<Conversation>();
+        assert_eq!(conversation.model(), ClaudeModel::default());
+        assert_eq!(conversation.messages().len(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn extend_invalid() {
+        let mut conversation = Conversation::new(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+        );
+        conversation.extend([Message::assistant("first")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn collect_invalid() {
+        let _ = [Message::user("first"), Message::user("second")]
+            .into_iter()
+            .collect::<Conversation>();
+    }
+
+    #[test]
+    fn from_messages() {
+        let conversation = Conversation::from_messages(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+            Some(SystemPrompt::new("system-prompt")),
+            vec![
+                Message::user("user-message"),
+                Message::assistant("assistant-message"),
+                Message::user("user-message"),
+            ],
+        )
+        .unwrap();
+        assert_eq!(conversation.messages().len(), 3);
+        assert_eq!(
+            conversation.system(),
+            Some(&SystemPrompt::new("system-prompt"))
+        );
+
+        assert!(Conversation::from_messages(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+            None,
+            vec![],
+        )
+        .is_ok());
+
+        assert_eq!(
+            Conversation::from_messages(
+                ClaudeModel::Claude3Haiku20240307,
+                MaxTokens::default(),
+                None,
+                vec![Message::assistant("assistant-message")],
+            )
+            .unwrap_err(),
+            ConversationError::FirstMessageNotUser
+        );
+
+        assert_eq!(
+            Conversation::from_messages(
+                ClaudeModel::Claude3Haiku20240307,
+                MaxTokens::default(),
+                None,
+                vec![
+                    Message::user("user-message"),
+                    Message::assistant("assistant-message"),
+                    Message::assistant("assistant-message"),
+                ],
+            )
+            .unwrap_err(),
+            ConversationError::RolesNotAlternating(2)
+        );
+    }
+
+    #[test]
+    fn request_body_with_tools() {
+        let tool_definition = ToolDefinition::new(
+            "tool",
+            None::<String>,
+            serde_json::Value::Null,
+        );
+        let conversation = Conversation::new(
+            ClaudeModel::Claude3Haiku20240307,
+            MaxTokens::default(),
+        )
+        .with_tool_definitions(vec![tool_definition.clone()])
+        .with_tool_choice(ToolChoice::Any);
+
+        let request_body = conversation.request_body();
+        assert_eq!(
+            request_body.tools,
+            Some(vec![tool_definition])
+        );
+        assert_eq!(
+            request_body.tool_choice,
+            Some(ToolChoice::Any)
+        );
+        assert_eq!(
+            conversation
+                .fork()
+                .request_body(),
+            request_body
+        );
+    }
+
+    #[test]
+    fn call_tools() {
+        struct TestTool {}
+
+        impl crate::messages::Tool for TestTool {
+            fn definition(&self) -> ToolDefinition {
+                ToolDefinition::new(
+                    "test_tool",
+                    None::<String>,
+                    serde_json::Value::Null,
+                )
+            }
+
+            fn call(
+                &self,
+                tool_use: crate::messages::ToolUse,
+            ) -> Result<ToolResult, crate::messages::ToolCallError> {
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    Some("result"),
+                ))
+            }
+        }
+
+        let executor = ToolList::new(vec![Box::new(TestTool {})]);
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(crate::messages::ToolUse::new(
+                "first",
+                "test_tool",
+                serde_json::Value::Null,
+            )),
+            ContentBlock::from(crate::messages::ToolUse::new(
+                "second",
+                "unknown_tool",
+                serde_json::Value::Null,
+            )),
+        ]);
+
+        let message = super::call_tools(&executor, &content);
+        assert_eq!(message.role, Role::User);
+        assert_eq!(
+            message.content,
+            Content::MultipleBlocks(vec![
+                ContentBlock::from(ToolResult::success(
+                    "first",
+                    Some("result")
+                )),
+                ContentBlock::from(ToolResult::error(
+                    "second",
+                    Some("Tool not found: unknown_tool")
+                )),
+            ])
+        );
+    }
+}
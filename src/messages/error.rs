@@ -1,4 +1,7 @@
-use crate::{ApiError, ClientError};
+use crate::messages::{ContentType, MessageChunkType};
+use crate::{
+    ApiError, ApiErrorBody, ApiErrorType, ApiRateLimitError, ClientError,
+};
 use std::fmt::Display;
 
 /// The error type for the messages API.
@@ -13,6 +16,59 @@ pub enum MessagesError {
     /// Stream option mismatch.
     #[error("Stream option mismatch")]
     StreamOptionMismatch,
+    /// The input tokens of the token budget are exhausted.
+    #[error("Token budget is exhausted")]
+    TokenBudgetExhausted,
+    /// The client-side rate limit error.
+    #[cfg(feature = "rate-limit")]
+    #[error(transparent)]
+    RateLimitError(#[from] crate::RateLimitError),
+}
+
+impl MessagesError {
+    /// The details of the rate limit error of the API server.
+    ///
+    /// It returns `None` if this is not a rate limit error of the API server.
+    pub fn rate_limit_error(&self) -> Option<ApiRateLimitError> {
+        match self {
+            | MessagesError::ApiError(error) => error.rate_limit_error(),
+            | _ => None,
+        }
+    }
+
+    /// Returns a human-friendly description of the error for end users of an application.
+    ///
+    /// Rate limit errors (429) and overloaded errors (529) of the API server are described as temporary.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::MessagesError;
+    ///
+    /// let error = MessagesError::StreamOptionMismatch;
+    /// assert_eq!(error.to_user_message(), "The AI service returned an error");
+    /// ```
+    pub fn to_user_message(&self) -> String {
+        match self {
+            | MessagesError::ClientError(error) => error.to_user_message(),
+            | MessagesError::ApiError(error) => match error._type {
+                | ApiErrorType::RateLimitError
+                | ApiErrorType::OverloadedError => {
+                    "Service temporarily unavailable, please try again later"
+                        .to_string()
+                },
+                | _ => "The AI service returned an error".to_string(),
+            },
+            | MessagesError::StreamOptionMismatch
+            | MessagesError::TokenBudgetExhausted => {
+                "The AI service returned an error".to_string()
+            },
+            #[cfg(feature = "rate-limit")]
+            | MessagesError::RateLimitError(_) => {
+                "Service temporarily unavailable, please try again later"
+                    .to_string()
+            },
+        }
+    }
 }
 
 /// The error type for the streaming messages.
@@ -33,6 +89,24 @@ pub enum StreamError {
     /// Chunk data deserialization error.
     #[error(transparent)]
     ChunkDataDeserializationError(#[from] serde_json::Error),
+    /// The error event sent by the API server in the stream.
+    #[error("API error in stream: {0}")]
+    ApiError(ApiErrorBody),
+    /// The message start chunk is missing before the other chunks.
+    #[error("Message start chunk is missing")]
+    MessageStartMissing,
+    /// The content block at the index is not open for the delta or stop chunk.
+    #[error("Content block is not open at index: {0}")]
+    ContentBlockNotOpen(u32),
+    /// The content block at the index has not been sealed by a stop chunk at the end of the stream.
+    #[error("Content block is not sealed at index: {0}")]
+    UnsealedContentBlock(u32),
+    /// The content block of the start chunk is not supported in streaming.
+    #[error("Unsupported content block in stream: {0}")]
+    UnsupportedContentBlock(ContentType),
+    /// The error of sending the request again to reconnect the stream.
+    #[error("Failed to reconnect the stream: {0}")]
+    ReconnectionError(Box<MessagesError>),
 }
 
 /// The error type for parsing message chunk type.
@@ -55,6 +129,14 @@ impl Display for MessageChunkTypeError {
     }
 }
 
+/// The error type for the conversion from a message chunk.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum MessageChunkConversionError {
+    /// The chunk type is not expected for the conversion.
+    #[error("Unexpected message chunk type: {0}")]
+    UnexpectedChunkType(MessageChunkType),
+}
+
 /// The error type for the content flattening.
 #[derive(Debug, PartialEq, thiserror::Error)]
 pub enum ContentFlatteningError {
@@ -64,6 +146,22 @@ pub enum ContentFlatteningError {
     /// Not found target block.
     #[error("Not found target block")]
     NotFoundTargetBlock,
+    /// Multiple target blocks are found.
+    #[error("Multiple target blocks are found")]
+    MultipleBlocks,
+}
+
+/// The error type for the content block access by an index.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ContentIndexError {
+    /// The index is out of bounds of the content blocks.
+    #[error("The index: {index} is out of bounds of the content blocks: {len}")]
+    OutOfBounds {
+        /// The specified index.
+        index: usize,
+        /// The number of the content blocks.
+        len: usize,
+    },
 }
 
 /// The error type for parsing the image media type from an extension in a path.
@@ -77,8 +175,48 @@ pub enum ImageMediaTypeParseError {
     NotFound,
 }
 
+/// The error type for parsing a model from a string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Not supported model: {value}")]
+pub struct ModelParseError {
+    /// The string that is not a supported model.
+    pub value: String,
+}
+
+/// The error type for loading a model from the environment variables.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ModelFromEnvError {
+    /// Neither `ANTHROPIC_MODEL` nor `CLAUDE_MODEL` is set.
+    #[error("Model environment variable is not set")]
+    Missing,
+    /// The value of the environment variable is not a supported model.
+    #[error("Invalid model in environment variable: {value}")]
+    Invalid {
+        /// The value of the environment variable.
+        value: String,
+        /// The parse error.
+        source: ModelParseError,
+    },
+}
+
+/// The error type for loading an image file.
+#[derive(Debug, thiserror::Error)]
+pub enum ImageLoadError {
+    /// The media type of the image is not supported.
+    #[error(transparent)]
+    MediaTypeParseError(#[from] ImageMediaTypeParseError),
+    /// The I/O error.
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+}
+
 /// The error type for the tool call.
-#[derive(Debug, PartialEq, thiserror::Error)]
+///
+/// It is serialized with the `kind` and the `message`, e.g. `{"kind":"ToolNotFound","message":"get_weather"}`.
+#[derive(
+    Debug, PartialEq, thiserror::Error, serde::Serialize, serde::Deserialize,
+)]
+#[serde(tag = "kind", content = "message")]
 pub enum ToolCallError {
     /// Tool name mismatch.
     #[error("Tool name mismatch")]
@@ -92,4 +230,149 @@ pub enum ToolCallError {
     /// Tool not found.
     #[error("Tool not found: {0}")]
     ToolNotFound(String),
+    /// Tool execution failed with the message of the error.
+    #[error("Tool execution failed: {0}")]
+    ExecutionFailed(String),
+}
+
+impl ToolCallError {
+    /// Creates `ToolCallError::ExecutionFailed` from the message of an error, e.g. stored in a database.
+    ///
+    /// ## Arguments
+    /// - `message` - The message of the error.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolCallError;
+    ///
+    /// let error = ToolCallError::from_serialized_message("timeout".to_string());
+    /// assert_eq!(
+    ///     serde_json::to_string(&error).unwrap(),
+    ///     r#"{"kind":"ExecutionFailed","message":"timeout"}"#
+    /// );
+    /// ```
+    pub fn from_serialized_message(message: String) -> Self {
+        Self::ExecutionFailed(message)
+    }
+}
+
+/// The error type for validating the request body.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum RequestValidationError {
+    /// The tool choice specifies a tool not in the tools.
+    #[error("The tool choice specifies an unknown tool: {0}")]
+    UnknownToolChoice(String),
+}
+
+/// The error type for the conversation.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ConversationError {
+    /// The first message is not a user message.
+    #[error("The first message must be a user message")]
+    FirstMessageNotUser,
+    /// The roles of the messages do not alternate.
+    #[error("The roles of the messages do not alternate at index: {0}")]
+    RolesNotAlternating(usize),
+    /// The message index is out of bounds of the message history.
+    #[error("The index: {index} is out of bounds of the messages: {len}")]
+    IndexOutOfBounds {
+        /// The specified index.
+        index: usize,
+        /// The number of the messages.
+        len: usize,
+    },
+}
+
+/// The error type for merging tool results.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum ToolMergeError {
+    /// The tool use IDs of the results are different.
+    #[error("Tool use ID mismatch: expected {expected}, actual {actual}")]
+    ToolUseIdMismatch {
+        /// The tool use ID of the merged result.
+        expected: String,
+        /// The tool use ID of the other result.
+        actual: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ApiErrorResponse, RateLimitInfo};
+
+    #[test]
+    fn to_user_message() {
+        let api_error = |status: u16, _type: &str| {
+            MessagesError::ApiError(ApiError::new(
+                reqwest::StatusCode::from_u16(status).unwrap(),
+                ApiErrorResponse {
+                    _type: "error".to_string(),
+                    error: ApiErrorBody {
+                        _type: _type.to_string(),
+                        message: "message".to_string(),
+                    },
+                },
+                RateLimitInfo::default(),
+            ))
+        };
+
+        assert_eq!(
+            api_error(429, "rate_limit_error").to_user_message(),
+            "Service temporarily unavailable, please try again later"
+        );
+        assert_eq!(
+            api_error(529, "overloaded_error").to_user_message(),
+            "Service temporarily unavailable, please try again later"
+        );
+        assert_eq!(
+            api_error(400, "invalid_request_error").to_user_message(),
+            "The AI service returned an error"
+        );
+        assert_eq!(
+            MessagesError::from(ClientError::from(ApiErrorBody {
+                _type: "overloaded_error".to_string(),
+                message: "Overloaded".to_string(),
+            }))
+            .to_user_message(),
+            "Service temporarily unavailable, please try again later"
+        );
+    }
+
+    #[test]
+    fn serialize_tool_call_error() {
+        let errors = [
+            (
+                ToolCallError::ToolNameMismatch,
+                r#"{"kind":"ToolNameMismatch"}"#,
+            ),
+            (
+                ToolCallError::ParameterNotFound("arg".to_string()),
+                r#"{"kind":"ParameterNotFound","message":"arg"}"#,
+            ),
+            (
+                ToolCallError::ParameterParseFailed("arg".to_string()),
+                r#"{"kind":"ParameterParseFailed","message":"arg"}"#,
+            ),
+            (
+                ToolCallError::ToolNotFound("name".to_string()),
+                r#"{"kind":"ToolNotFound","message":"name"}"#,
+            ),
+            (
+                ToolCallError::from_serialized_message("failed".to_string()),
+                r#"{"kind":"ExecutionFailed","message":"failed"}"#,
+            ),
+        ];
+
+        for (error, json) in errors {
+            assert_eq!(
+                serde_json::to_string(&error).unwrap(),
+                json
+            );
+            assert_eq!(
+                serde_json::from_str::<ToolCallError>(json).unwrap(),
+                error
+            );
+        }
+    }
 }
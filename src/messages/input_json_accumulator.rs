@@ -0,0 +1,442 @@
+use std::collections::BTreeMap;
+
+use crate::messages::{ContentBlockDeltaChunk, ToolUse, ToolUseContentBlock};
+
+/// The accumulator of partial JSON strings of a tool input in streaming.
+///
+/// The tool input of a tool use content block arrives as partial JSON strings of `input_json_delta`
+/// per content block index, so accumulate them for each index and reconstruct the `ToolUse`
+/// with the `ToolUseContentBlock` of the content block start chunk.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{InputJsonAccumulator, ToolUse, ToolUseContentBlock};
+///
+/// let content_block_start = ToolUseContentBlock::new(ToolUse::new(
+///     "toolu_01",
+///     "get_weather",
+///     serde_json::json!({}),
+/// ));
+///
+/// let mut accumulator = InputJsonAccumulator::new();
+/// accumulator.push_partial_json("{\"location\": ");
+/// accumulator.push_partial_json("\"Tokyo\"}");
+///
+/// let tool_use = accumulator
+///     .into_tool_use(&content_block_start)
+///     .unwrap();
+/// assert_eq!(
+///     tool_use,
+///     ToolUse::new(
+///         "toolu_01",
+///         "get_weather",
+///         serde_json::json!({"location": "Tokyo"}),
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct InputJsonAccumulator {
+    /// The accumulated JSON string.
+    json: String,
+}
+
+impl InputJsonAccumulator {
+    /// Creates a new empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a partial JSON string.
+    ///
+    /// ## Arguments
+    /// - `partial_json` - The partial JSON string of the tool input.
+    pub fn push_partial_json(
+        &mut self,
+        partial_json: &str,
+    ) {
+        self.json.push_str(partial_json);
+    }
+
+    /// The accumulated JSON string.
+    pub fn json(&self) -> &str {
+        &self.json
+    }
+
+    /// Reconstructs the tool use from the accumulated JSON and the content block start.
+    ///
+    /// An empty JSON is treated as an empty object because a tool without arguments has no partial JSON.
+    ///
+    /// ## Arguments
+    /// - `content_block_start` - The tool use content block of the content block start chunk.
+    ///
+    /// ## Errors
+    /// It returns an error if the accumulated JSON is invalid.
+    pub fn into_tool_use(
+        self,
+        content_block_start: &ToolUseContentBlock,
+    ) -> Result<ToolUse, serde_json::Error> {
+        let input = parse_tool_input(&self.json)?;
+
+        Ok(ToolUse::new(
+            content_block_start
+                .tool_use
+                .id
+                .clone(),
+            content_block_start
+                .tool_use
+                .name
+                .clone(),
+            input,
+        ))
+    }
+}
+
+/// The accumulator of `input_json_delta` chunks of a tool input at a content block index.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{ContentBlockDeltaChunk, ToolInputAccumulator};
+///
+/// let chunks = [
+///     "{\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"location\\\": \"}}",
+///     "{\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"\\\"Tokyo\\\"}\"}}",
+/// ];
+///
+/// let mut accumulator = ToolInputAccumulator::new(1);
+/// for chunk in chunks {
+///     let chunk: ContentBlockDeltaChunk = serde_json::from_str(chunk).unwrap();
+///     assert!(accumulator.feed(&chunk));
+/// }
+///
+/// assert_eq!(
+///     accumulator.finish().unwrap(),
+///     serde_json::json!({"location": "Tokyo"})
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ToolInputAccumulator {
+    /// The content block index of the tool use.
+    index: u32,
+    /// The accumulated partial JSON strings.
+    buffer: String,
+}
+
+impl ToolInputAccumulator {
+    /// Creates a new empty accumulator for the content block index.
+    ///
+    /// ## Arguments
+    /// - `index` - The content block index of the tool use.
+    pub fn new(index: u32) -> Self {
+        Self {
+            index,
+            buffer: String::new(),
+        }
+    }
+
+    /// The content block index of the tool use.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The accumulated JSON string.
+    pub fn json(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Feeds a content block delta chunk.
+    ///
+    /// The chunk is accumulated only if it is an `input_json_delta` at the index of this accumulator.
+    ///
+    /// ## Arguments
+    /// - `chunk` - The content block delta chunk.
+    ///
+    /// ## Returns
+    /// Whether the chunk has been accumulated.
+    pub fn feed(
+        &mut self,
+        chunk: &ContentBlockDeltaChunk,
+    ) -> bool {
+        if chunk.index != self.index {
+            return false;
+        }
+
+        match chunk.delta.as_partial_json() {
+            | Some(partial_json) => {
+                self.buffer.push_str(partial_json);
+                true
+            },
+            | None => false,
+        }
+    }
+
+    /// Parses the accumulated JSON as the tool input.
+    ///
+    /// An empty JSON is treated as an empty object because a tool without arguments has no partial JSON.
+    ///
+    /// ## Errors
+    /// It returns an error if the accumulated JSON is invalid.
+    pub fn finish(self) -> Result<serde_json::Value, serde_json::Error> {
+        parse_tool_input(&self.buffer)
+    }
+}
+
+/// The accumulator of tool inputs for multiple content block indices in streaming.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{ContentBlockDeltaChunk, StreamToolAccumulator};
+///
+/// let chunks = [
+///     "{\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Let me check.\"}}",
+///     "{\"type\":\"content_block_delta\",\"index\":1,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{}\"}}",
+///     "{\"type\":\"content_block_delta\",\"index\":2,\"delta\":{\"type\":\"input_json_delta\",\"partial_json\":\"{\\\"n\\\": 1}\"}}",
+/// ];
+///
+/// let mut accumulator = StreamToolAccumulator::new();
+/// for chunk in chunks {
+///     let chunk: ContentBlockDeltaChunk = serde_json::from_str(chunk).unwrap();
+///     accumulator.feed(&chunk);
+/// }
+///
+/// let inputs = accumulator.finish().unwrap();
+/// assert_eq!(inputs.len(), 2);
+/// assert_eq!(inputs[&1], serde_json::json!({}));
+/// assert_eq!(inputs[&2], serde_json::json!({"n": 1}));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StreamToolAccumulator {
+    /// The accumulators keyed by the content block index.
+    accumulators: BTreeMap<u32, ToolInputAccumulator>,
+}
+
+impl StreamToolAccumulator {
+    /// Creates a new empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a content block delta chunk to the accumulator of its index.
+    ///
+    /// ## Arguments
+    /// - `chunk` - The content block delta chunk.
+    ///
+    /// ## Returns
+    /// Whether the chunk has been accumulated, i.e. it is an `input_json_delta`.
+    pub fn feed(
+        &mut self,
+        chunk: &ContentBlockDeltaChunk,
+    ) -> bool {
+        if chunk
+            .delta
+            .as_partial_json()
+            .is_none()
+        {
+            return false;
+        }
+
+        self.accumulators
+            .entry(chunk.index)
+            .or_insert_with(|| ToolInputAccumulator::new(chunk.index))
+            .feed(chunk)
+    }
+
+    /// The accumulator of the content block index.
+    ///
+    /// ## Arguments
+    /// - `index` - The content block index of the tool use.
+    pub fn get(
+        &self,
+        index: u32,
+    ) -> Option<&ToolInputAccumulator> {
+        self.accumulators.get(&index)
+    }
+
+    /// Parses the accumulated JSON of each index as the tool input.
+    ///
+    /// ## Errors
+    /// It returns the first error of the invalid JSON.
+    pub fn finish(
+        self
+    ) -> Result<BTreeMap<u32, serde_json::Value>, serde_json::Error> {
+        self.accumulators
+            .into_iter()
+            .map(|(index, accumulator)| {
+                accumulator
+                    .finish()
+                    .map(|input| (index, input))
+            })
+            .collect()
+    }
+}
+
+/// Parses the accumulated partial JSON strings as a tool input.
+///
+/// An empty JSON is treated as an empty object because a tool without arguments has no partial JSON.
+pub(crate) fn parse_tool_input(
+    json: &str
+) -> Result<serde_json::Value, serde_json::Error> {
+    if json.trim().is_empty() {
+        Ok(serde_json::Value::Object(serde_json::Map::new()))
+    } else {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::InputJsonDeltaContentBlock;
+
+    fn content_block_start() -> ToolUseContentBlock {
+        ToolUseContentBlock::new(ToolUse::new(
+            "id",
+            "name",
+            serde_json::json!({}),
+        ))
+    }
+
+    #[test]
+    fn into_tool_use() {
+        let mut accumulator = InputJsonAccumulator::new();
+        for partial_json in
+            ["", "{\"arg", "1\": 4", "2, \"arg2\"", ": [true]}"]
+        {
+            accumulator.push_partial_json(partial_json);
+        }
+        assert_eq!(
+            accumulator.json(),
+            "{\"arg1\": 42, \"arg2\": [true]}"
+        );
+
+        assert_eq!(
+            accumulator
+                .into_tool_use(&content_block_start())
+                .unwrap(),
+            ToolUse::new(
+                "id",
+                "name",
+                serde_json::json!({"arg1": 42, "arg2": [true]}),
+            )
+        );
+    }
+
+    #[test]
+    fn into_tool_use_per_index() {
+        let mut accumulators = BTreeMap::<u32, InputJsonAccumulator>::new();
+        let deltas = [
+            (1, "{\"first\""),
+            (2, "{\"second\""),
+            (1, ": 1}"),
+            (2, ": 2}"),
+        ];
+        for (index, partial_json) in deltas {
+            accumulators
+                .entry(index)
+                .or_default()
+                .push_partial_json(partial_json);
+        }
+
+        let tool_uses = accumulators
+            .into_values()
+            .map(|accumulator| {
+                accumulator
+                    .into_tool_use(&content_block_start())
+                    .unwrap()
+                    .input
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            tool_uses,
+            vec![
+                serde_json::json!({"first": 1}),
+                serde_json::json!({"second": 2}),
+            ]
+        );
+    }
+
+    #[test]
+    fn into_tool_use_empty_or_invalid() {
+        assert_eq!(
+            InputJsonAccumulator::new()
+                .into_tool_use(&content_block_start())
+                .unwrap()
+                .input,
+            serde_json::json!({})
+        );
+
+        let mut accumulator = InputJsonAccumulator::new();
+        accumulator.push_partial_json("{\"arg1\": ");
+        assert!(accumulator
+            .into_tool_use(&content_block_start())
+            .is_err());
+    }
+
+    fn input_json_delta(
+        index: u32,
+        partial_json: &str,
+    ) -> ContentBlockDeltaChunk {
+        ContentBlockDeltaChunk::new(
+            index,
+            InputJsonDeltaContentBlock::new(partial_json).into(),
+        )
+    }
+
+    #[test]
+    fn tool_input_accumulator() {
+        let mut accumulator = ToolInputAccumulator::new(1);
+        assert!(accumulator.feed(&input_json_delta(1, "{\"arg1\"")));
+        assert!(!accumulator.feed(&input_json_delta(2, "{\"arg2\"")));
+        assert!(!accumulator.feed(&ContentBlockDeltaChunk::new(
+            1,
+            "text".into(),
+        )));
+        assert!(accumulator.feed(&input_json_delta(1, ": 42}")));
+
+        assert_eq!(accumulator.index(), 1);
+        assert_eq!(accumulator.json(), "{\"arg1\": 42}");
+        assert_eq!(
+            accumulator.finish().unwrap(),
+            serde_json::json!({"arg1": 42})
+        );
+
+        assert_eq!(
+            ToolInputAccumulator::new(0)
+                .finish()
+                .unwrap(),
+            serde_json::json!({})
+        );
+    }
+
+    #[test]
+    fn stream_tool_accumulator() {
+        let mut accumulator = StreamToolAccumulator::new();
+        assert!(!accumulator.feed(&ContentBlockDeltaChunk::new(
+            0,
+            "text".into(),
+        )));
+        assert!(accumulator.feed(&input_json_delta(1, "{\"first\"")));
+        assert!(accumulator.feed(&input_json_delta(2, "{\"second\"")));
+        assert!(accumulator.feed(&input_json_delta(1, ": 1}")));
+        assert!(accumulator.feed(&input_json_delta(2, ": 2}")));
+
+        assert!(accumulator.get(0).is_none());
+        assert_eq!(
+            accumulator
+                .get(1)
+                .unwrap()
+                .json(),
+            "{\"first\": 1}"
+        );
+        assert_eq!(
+            accumulator.finish().unwrap(),
+            BTreeMap::from([
+                (1, serde_json::json!({"first": 1})),
+                (2, serde_json::json!({"second": 2})),
+            ])
+        );
+
+        let mut accumulator = StreamToolAccumulator::new();
+        accumulator.feed(&input_json_delta(1, "{\"first\""));
+        assert!(accumulator.finish().is_err());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::messages::ClaudeModel;
+use crate::messages::{ClaudeModel, Usage};
 use crate::ValidationError;
 use std::fmt::Display;
 
@@ -77,6 +77,43 @@ impl MaxTokens {
             value: model.max_tokens(),
         }
     }
+
+    /// The value of the maximum number of tokens.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// The remaining number of output tokens after the used tokens.
+    ///
+    /// ## Arguments
+    /// - `used_output_tokens` - The number of output tokens already used.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, MaxTokens};
+    ///
+    /// let max_tokens = MaxTokens::new(1024, ClaudeModel::Claude3Haiku20240307).unwrap();
+    /// assert_eq!(max_tokens.remaining(1000), 24);
+    /// assert_eq!(max_tokens.remaining(2000), 0);
+    /// ```
+    pub fn remaining(
+        &self,
+        used_output_tokens: u32,
+    ) -> u32 {
+        self.value
+            .saturating_sub(used_output_tokens)
+    }
+
+    /// Whether the output tokens of the usage reach this maximum.
+    ///
+    /// ## Arguments
+    /// - `usage` - The usage of the API call.
+    pub fn is_exhausted_by_usage(
+        &self,
+        usage: &Usage,
+    ) -> bool {
+        usage.output_tokens >= self.value
+    }
 }
 
 #[cfg(test)]
@@ -107,13 +144,42 @@ mod tests {
     #[test]
     fn new() {
         assert!(
-            MaxTokens::new(4096, ClaudeModel::Claude3Sonnet20240229).is_ok()
+            MaxTokens::new(4096, ClaudeModel::Claude3Haiku20240307).is_ok()
         );
         assert!(
-            MaxTokens::new(4097, ClaudeModel::Claude3Sonnet20240229).is_err()
+            MaxTokens::new(4097, ClaudeModel::Claude3Haiku20240307).is_err()
         );
     }
 
+    #[test]
+    fn remaining() {
+        let max_tokens = MaxTokens {
+            value: 100,
+        };
+        assert_eq!(max_tokens.value(), 100);
+        assert_eq!(max_tokens.remaining(0), 100);
+        assert_eq!(max_tokens.remaining(60), 40);
+        assert_eq!(max_tokens.remaining(100), 0);
+        assert_eq!(max_tokens.remaining(200), 0);
+    }
+
+    #[test]
+    fn is_exhausted_by_usage() {
+        let max_tokens = MaxTokens {
+            value: 100,
+        };
+        assert!(!max_tokens.is_exhausted_by_usage(&Usage {
+            input_tokens: 1000,
+            output_tokens: 99,
+            ..Default::default()
+        }));
+        assert!(max_tokens.is_exhausted_by_usage(&Usage {
+            input_tokens: 0,
+            output_tokens: 100,
+            ..Default::default()
+        }));
+    }
+
     #[test]
     fn serialize() {
         assert_eq!(
@@ -1,5 +1,10 @@
 use crate::macros::impl_display_for_serialize;
-use crate::messages::{Content, Role};
+use crate::messages::{
+    Content, ContentBlock, ImageLoadError, Role, ToolResult,
+    ToolResultContentBlock,
+};
+use std::fmt::Display;
+use std::path::Path;
 
 /// The message.
 #[derive(
@@ -36,6 +41,120 @@ impl Message {
         }
     }
 
+    /// Create a new user message with an image loaded from a file.
+    ///
+    /// ## Arguments
+    /// - `path` - The path of the image file.
+    ///
+    /// ## Errors
+    /// It returns an error if the extension is not supported or reading the file fails.
+    ///
+    /// ## Example
+    /// ```rust,no_run
+    /// use clust::messages::Message;
+    /// use std::path::Path;
+    ///
+    /// let message = Message::user_from_image_file(Path::new("image.png")).unwrap();
+    /// ```
+    pub fn user_from_image_file(path: &Path) -> Result<Self, ImageLoadError> {
+        Ok(Self::user(Content::from_image_file(
+            path,
+        )?))
+    }
+
+    /// Parses the message from the raw JSON string.
+    ///
+    /// ## Arguments
+    /// - `json` - The JSON string.
+    ///
+    /// ## Errors
+    /// It returns an error if the JSON is invalid as `Message`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Message;
+    ///
+    /// let message = Message::from_json(r#"{"role":"user","content":"Hello, Claude!"}"#).unwrap();
+    /// assert_eq!(message, Message::user("Hello, Claude!"));
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Create a new user message with a tool result.
+    ///
+    /// ## Arguments
+    /// - `result` - The result of the tool.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Message, ToolResult};
+    ///
+    /// let message = Message::from_tool_result(ToolResult::success("id", Some("result")));
+    /// ```
+    pub fn from_tool_result(result: ToolResult) -> Self {
+        Self::from_tool_results(vec![result])
+    }
+
+    /// Create a new user message with multiple tool results.
+    ///
+    /// ## Arguments
+    /// - `results` - The results of the tools.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Message, ToolResult};
+    ///
+    /// let message = Message::from_tool_results(vec![
+    ///     ToolResult::success("first_id", Some("first result")),
+    ///     ToolResult::error("second_id", Some("second error")),
+    /// ]);
+    /// ```
+    pub fn from_tool_results(results: Vec<ToolResult>) -> Self {
+        Self::user(Content::MultipleBlocks(
+            results
+                .into_iter()
+                .map(|result| {
+                    ContentBlock::ToolResult(ToolResultContentBlock::new(
+                        result,
+                    ))
+                })
+                .collect(),
+        ))
+    }
+
+    /// Appends a tool result block to the content of this message.
+    ///
+    /// `Content::SingleText` is converted into `Content::MultipleBlocks`, and an empty text is dropped.
+    ///
+    /// ## Arguments
+    /// - `result` - The result of the tool.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Message, ToolResult};
+    ///
+    /// let mut message = Message::user("Here are the results.");
+    /// message
+    ///     .add_tool_result_block(ToolResult::success("first_id", Some("first result")))
+    ///     .add_tool_result_block(ToolResult::success("second_id", Some("second result")));
+    /// ```
+    pub fn add_tool_result_block(
+        &mut self,
+        result: ToolResult,
+    ) -> &mut Self {
+        if self.content == Content::SingleText(String::new()) {
+            self.content = Content::MultipleBlocks(Vec::new());
+        }
+
+        self.content
+            .blocks_mut()
+            .push(ContentBlock::ToolResult(
+                ToolResultContentBlock::new(result),
+            ));
+        self
+    }
+
     /// Create a new assistant message.
     ///
     /// ## Arguments
@@ -82,11 +201,155 @@ impl Message {
             content: content.into(),
         }
     }
+
+    /// The approximate number of tokens of the content by `Content::approximate_total_token_count`.
+    ///
+    /// **This is a rough estimate**, the actual number of tokens can differ significantly.
+    pub fn approximate_total_token_count(&self) -> u32 {
+        self.content
+            .approximate_total_token_count()
+    }
+
+    /// Creates a human-readable display of this message for logging and terminal output.
+    ///
+    /// The content is shown by `Content::display_readable` with the prefix of the role.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Message;
+    ///
+    /// assert_eq!(
+    ///     Message::user("Hello, Claude!").display_readable().to_string(),
+    ///     "User: Hello, Claude!"
+    /// );
+    /// assert_eq!(
+    ///     Message::assistant("Hello!").display_readable().to_string(),
+    ///     "Assistant: Hello!"
+    /// );
+    /// ```
+    pub fn display_readable(&self) -> impl Display + '_ {
+        MessageReadableDisplay {
+            message: self,
+        }
+    }
+}
+
+/// The human-readable display of `Message` created by `Message::display_readable`.
+struct MessageReadableDisplay<'a> {
+    message: &'a Message,
+}
+
+impl Display for MessageReadableDisplay<'_> {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let role = match self.message.role {
+            | Role::User => "User",
+            | Role::Assistant => "Assistant",
+        };
+
+        write!(
+            f,
+            "{}: {}",
+            role,
+            self.message
+                .content
+                .display_readable()
+        )
+    }
+}
+
+/// The extension trait for the slice of messages, e.g. `Vec<Message>`.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{Message, MessageSliceExt};
+///
+/// let messages = vec![
+///     Message::user("a".repeat(40)),
+///     Message::assistant("a".repeat(20)),
+/// ];
+/// assert_eq!(messages.approximate_total_token_count(), 15);
+/// ```
+pub trait MessageSliceExt {
+    /// The approximate number of tokens of all messages by `Message::approximate_total_token_count`.
+    ///
+    /// **This is a rough estimate**, the actual number of tokens can differ significantly.
+    fn approximate_total_token_count(&self) -> u32;
+}
+
+impl MessageSliceExt for [Message] {
+    fn approximate_total_token_count(&self) -> u32 {
+        self.iter()
+            .map(Message::approximate_total_token_count)
+            .fold(0, u32::saturating_add)
+    }
+}
+
+/// Creates a user message with the results of the tools to reply to the tool uses.
+///
+/// ## Arguments
+/// - `results` - The results of the tools.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{tool_result_message, ToolResult};
+///
+/// let message = tool_result_message([
+///     ToolResult::success("first_id", Some("first result")),
+///     ToolResult::error("second_id", Some("second error")),
+/// ]);
+/// ```
+pub fn tool_result_message(
+    results: impl IntoIterator<Item = ToolResult>
+) -> Message {
+    Message::from_tool_results(
+        results
+            .into_iter()
+            .collect(),
+    )
+}
+
+/// Creates a user message with the results of the tools to reply to the tool uses.
+///
+/// An alias of `tool_result_message` for the results collected in a `Vec`.
+///
+/// ## Arguments
+/// - `results` - The results of the tools.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{to_tool_result_message, ToolResult};
+///
+/// let message = to_tool_result_message(vec![
+///     ToolResult::success("first_id", Some("first result")),
+///     ToolResult::error("second_id", Some("second error")),
+/// ]);
+/// ```
+pub fn to_tool_result_message(results: Vec<ToolResult>) -> Message {
+    tool_result_message(results)
+}
+
+/// Creates a user message with the result of the tool to reply to the tool use.
+///
+/// ## Arguments
+/// - `result` - The result of the tool.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{single_tool_result_message, ToolResult};
+///
+/// let message = single_tool_result_message(ToolResult::success("id", Some("result")));
+/// ```
+pub fn single_tool_result_message(result: ToolResult) -> Message {
+    Message::from_tool_result(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::messages::{ImageContentSource, ImageMediaType};
 
     #[test]
     fn user() {
@@ -119,6 +382,80 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tool_results() {
+        let first = ToolResult::success("first_id", Some("first"));
+        let second = ToolResult::error("second_id", Some("second"));
+
+        let message = Message::from_tool_result(first.clone());
+        assert_eq!(message.role, Role::User);
+        assert_eq!(
+            message.content,
+            Content::MultipleBlocks(vec![ContentBlock::from(first.clone())])
+        );
+
+        let message = Message::from_tool_results(vec![
+            first.clone(),
+            second.clone(),
+        ]);
+        assert_eq!(
+            message.content,
+            Content::MultipleBlocks(vec![
+                ContentBlock::from(first.clone()),
+                ContentBlock::from(second.clone()),
+            ])
+        );
+
+        let mut added = Message::default();
+        added
+            .add_tool_result_block(first.clone())
+            .add_tool_result_block(second.clone());
+        assert_eq!(added, message);
+        assert_eq!(
+            tool_result_message([first.clone(), second.clone()]),
+            message
+        );
+        assert_eq!(
+            to_tool_result_message(vec![first.clone(), second.clone()]),
+            message
+        );
+        assert_eq!(
+            single_tool_result_message(first.clone()),
+            Message::from_tool_result(first.clone())
+        );
+
+        let mut message = Message::user("text");
+        message.add_tool_result_block(first.clone());
+        assert_eq!(
+            message.content,
+            Content::MultipleBlocks(vec![
+                ContentBlock::from("text"),
+                ContentBlock::from(first),
+            ])
+        );
+    }
+
+    #[test]
+    fn approximate_total_token_count() {
+        let messages = [
+            Message::user("a".repeat(8)),
+            Message::assistant(vec![
+                ContentBlock::from("a".repeat(4)),
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Png,
+                    "base64",
+                )),
+            ]),
+        ];
+        assert_eq!(messages[0].approximate_total_token_count(), 2);
+        assert_eq!(messages[1].approximate_total_token_count(), 1001);
+        assert_eq!(messages.approximate_total_token_count(), 1003);
+        assert_eq!(
+            Vec::<Message>::new().approximate_total_token_count(),
+            0
+        );
+    }
+
     #[test]
     fn default() {
         assert_eq!(
@@ -139,6 +476,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_readable() {
+        let message = Message::user(vec![
+            ContentBlock::from("first"),
+            ContentBlock::from(ToolResult::success_without_content("id")),
+        ]);
+        assert_eq!(
+            message
+                .display_readable()
+                .to_string(),
+            "User: first\n[TOOL_RESULT]"
+        );
+
+        let message = Message::assistant("assistant-message");
+        assert_eq!(
+            message
+                .display_readable()
+                .to_string(),
+            "Assistant: assistant-message"
+        );
+        assert_ne!(
+            message.to_string(),
+            message
+                .display_readable()
+                .to_string()
+        );
+    }
+
     #[test]
     fn serialize() {
         let message = Message::user("user-message");
@@ -158,5 +523,13 @@ mod tests {
             .unwrap(),
             message
         );
+        assert_eq!(
+            Message::from_json(
+                "{\"role\":\"user\",\"content\":\"user-message\"}"
+            )
+            .unwrap(),
+            message
+        );
+        assert!(Message::from_json("{\"role\":\"user\"}").is_err());
     }
 }
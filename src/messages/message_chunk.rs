@@ -4,11 +4,14 @@ use std::str::FromStr;
 
 use crate::macros::{
     impl_display_for_serialize, impl_enum_string_serialization,
+    impl_enum_struct_serialization,
 };
 use crate::messages::{
-    ContentType, MessageChunkTypeError, MessagesResponseBody, StopReason,
-    StopSequence, StreamError, TextContentBlock,
+    ContentBlock, ContentType, MessageChunkConversionError,
+    MessageChunkTypeError, MessagesResponseBody, StopReason, StopSequence,
+    StreamError,
 };
+use crate::{ApiErrorBody, ApiErrorResponse};
 
 /// The stream chunk of messages.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,6 +30,8 @@ pub enum MessageChunk {
     MessageDelta(MessageDeltaChunk),
     /// Message stop chunk.
     MessageStop(MessageStopChunk),
+    /// Error event sent by the API server in the stream.
+    Error(ApiErrorBody),
 }
 
 impl Display for MessageChunk {
@@ -118,11 +123,176 @@ impl Display for MessageChunk {
                     message_stop._type, json
                 )
             },
+            | MessageChunk::Error(error) => {
+                let json = json_format
+                    .format_to_string(&ApiErrorResponse {
+                        _type: MessageChunkType::Error.to_string(),
+                        error: error.clone(),
+                    })
+                    .map_err(|_| std::fmt::Error)?;
+
+                write!(
+                    f,
+                    "event: {}\ndata: {}",
+                    MessageChunkType::Error,
+                    json
+                )
+            },
+        }
+    }
+}
+
+impl TryFrom<MessageChunk> for MessagesResponseBody {
+    type Error = MessageChunkConversionError;
+
+    fn try_from(chunk: MessageChunk) -> Result<Self, Self::Error> {
+        match chunk {
+            | MessageChunk::MessageStart(message_start) => {
+                Ok(message_start.message)
+            },
+            | other => Err(
+                MessageChunkConversionError::UnexpectedChunkType(
+                    other.chunk_type(),
+                ),
+            ),
         }
     }
 }
 
 impl MessageChunk {
+    /// The response body embedded in the `MessageChunk::MessageStart` chunk.
+    ///
+    /// It returns `None` for other chunks.
+    pub fn as_message_start_body(&self) -> Option<&MessagesResponseBody> {
+        match self {
+            | MessageChunk::MessageStart(message_start) => {
+                Some(&message_start.message)
+            },
+            | _ => None,
+        }
+    }
+
+    /// Converts into the response body embedded in the `MessageChunk::MessageStart` chunk.
+    ///
+    /// It returns `None` for other chunks.
+    pub fn into_response_body(self) -> Option<MessagesResponseBody> {
+        MessagesResponseBody::try_from(self).ok()
+    }
+
+    /// The message ID of the `MessageChunk::MessageStart` chunk.
+    ///
+    /// It returns `None` for other chunks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessageChunk, MessageStartChunk, MessagesResponseBody, PingChunk};
+    ///
+    /// let chunk = MessageChunk::MessageStart(MessageStartChunk::new(
+    ///     MessagesResponseBody {
+    ///         id: "msg_id".to_string(),
+    ///         ..Default::default()
+    ///     },
+    /// ));
+    /// assert_eq!(chunk.message_start_id(), Some("msg_id"));
+    ///
+    /// let chunk = MessageChunk::Ping(PingChunk::new());
+    /// assert_eq!(chunk.message_start_id(), None);
+    /// ```
+    pub fn message_start_id(&self) -> Option<&str> {
+        self.as_message_start_body()
+            .map(|body| body.id.as_str())
+    }
+
+    /// Checks if this is the terminal `MessageChunk::MessageStop` chunk.
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, MessageChunk::MessageStop(_))
+    }
+
+    /// Checks if this is a `MessageChunk::ContentBlockDelta` chunk.
+    pub fn is_content_delta(&self) -> bool {
+        matches!(self, MessageChunk::ContentBlockDelta(_))
+    }
+
+    /// The text of the `MessageChunk::ContentBlockDelta` chunk with a text delta.
+    ///
+    /// It returns `None` for other chunks and the partial JSON of a tool input.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ContentBlockDeltaChunk, MessageChunk};
+    ///
+    /// let chunk = MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+    ///     0,
+    ///     "Hello".into(),
+    /// ));
+    /// assert_eq!(chunk.as_text_delta(), Some("Hello"));
+    /// assert_eq!(chunk.as_tool_input_delta(), None);
+    /// assert_eq!(chunk.index(), Some(0));
+    /// ```
+    pub fn as_text_delta(&self) -> Option<&str> {
+        match self {
+            | MessageChunk::ContentBlockDelta(content_block_delta) => {
+                content_block_delta.delta.as_text()
+            },
+            | _ => None,
+        }
+    }
+
+    /// The index and the partial JSON of the `MessageChunk::ContentBlockDelta` chunk with a tool input delta.
+    ///
+    /// It returns `None` for other chunks and text deltas.
+    pub fn as_tool_input_delta(&self) -> Option<(u32, &str)> {
+        match self {
+            | MessageChunk::ContentBlockDelta(content_block_delta) => {
+                content_block_delta
+                    .delta
+                    .as_partial_json()
+                    .map(|partial_json| {
+                        (content_block_delta.index, partial_json)
+                    })
+            },
+            | _ => None,
+        }
+    }
+
+    /// The content block index of the content block start, delta and stop chunks.
+    ///
+    /// It returns `None` for other chunks.
+    pub fn index(&self) -> Option<u32> {
+        match self {
+            | MessageChunk::ContentBlockStart(content_block_start) => {
+                Some(content_block_start.index)
+            },
+            | MessageChunk::ContentBlockDelta(content_block_delta) => {
+                Some(content_block_delta.index)
+            },
+            | MessageChunk::ContentBlockStop(content_block_stop) => {
+                Some(content_block_stop.index)
+            },
+            | _ => None,
+        }
+    }
+
+    /// The type of this chunk.
+    fn chunk_type(&self) -> MessageChunkType {
+        match self {
+            | MessageChunk::MessageStart(_) => MessageChunkType::MessageStart,
+            | MessageChunk::ContentBlockStart(_) => {
+                MessageChunkType::ContentBlockStart
+            },
+            | MessageChunk::Ping(_) => MessageChunkType::Ping,
+            | MessageChunk::ContentBlockDelta(_) => {
+                MessageChunkType::ContentBlockDelta
+            },
+            | MessageChunk::ContentBlockStop(_) => {
+                MessageChunkType::ContentBlockStop
+            },
+            | MessageChunk::MessageDelta(_) => MessageChunkType::MessageDelta,
+            | MessageChunk::MessageStop(_) => MessageChunkType::MessageStop,
+            | MessageChunk::Error(_) => MessageChunkType::Error,
+        }
+    }
+
     pub(crate) fn parse(source: &str) -> Result<MessageChunk, StreamError> {
         let lines = source
             .lines()
@@ -148,8 +318,6 @@ impl MessageChunk {
                     source
                 ))
             })?;
-        let chunk_type = MessageChunkType::from_str(event)
-            .map_err(StreamError::MessageChunkTypeError)?;
 
         // Parse the data segment to the chunk data.
         let second_line = lines[1];
@@ -162,6 +330,9 @@ impl MessageChunk {
                 ))
             })?;
 
+        let chunk_type = MessageChunkType::from_str(event)
+            .map_err(StreamError::MessageChunkTypeError)?;
+
         // Deserialize the chunk data.
         match chunk_type {
             | MessageChunkType::MessageStart => {
@@ -201,6 +372,11 @@ impl MessageChunk {
                     .map_err(StreamError::ChunkDataDeserializationError)?;
                 Ok(MessageChunk::MessageStop(stop))
             },
+            | MessageChunkType::Error => {
+                let response: ApiErrorResponse = serde_json::from_str(data)
+                    .map_err(StreamError::ChunkDataDeserializationError)?;
+                Ok(MessageChunk::Error(response.error))
+            },
         }
     }
 }
@@ -222,6 +398,8 @@ pub enum MessageChunkType {
     MessageDelta,
     /// message_stop
     MessageStop,
+    /// error
+    Error,
 }
 
 impl Display for MessageChunkType {
@@ -243,6 +421,7 @@ impl Display for MessageChunkType {
             },
             | MessageChunkType::MessageDelta => write!(f, "message_delta"),
             | MessageChunkType::MessageStop => write!(f, "message_stop"),
+            | MessageChunkType::Error => write!(f, "error"),
         }
     }
 }
@@ -259,6 +438,7 @@ impl FromStr for MessageChunkType {
             | "content_block_stop" => Ok(MessageChunkType::ContentBlockStop),
             | "message_delta" => Ok(MessageChunkType::MessageDelta),
             | "message_stop" => Ok(MessageChunkType::MessageStop),
+            | "error" => Ok(MessageChunkType::Error),
             | _ => Err(MessageChunkTypeError {
                 chunk_type: s.to_string(),
             }),
@@ -274,7 +454,8 @@ impl_enum_string_serialization!(
     ContentBlockDelta => "content_block_delta",
     ContentBlockStop => "content_block_stop",
     MessageDelta => "message_delta",
-    MessageStop => "message_stop"
+    MessageStop => "message_stop",
+    Error => "error"
 );
 
 /// The message start chunk.
@@ -316,8 +497,8 @@ pub struct ContentBlockStartChunk {
     pub _type: MessageChunkType,
     /// The index.
     pub index: u32,
-    /// The text content block of start.
-    pub content_block: TextContentBlock,
+    /// The content block of start, which is a text or a tool use.
+    pub content_block: ContentBlock,
 }
 
 impl Default for ContentBlockStartChunk {
@@ -336,7 +517,7 @@ impl ContentBlockStartChunk {
     /// Creates a new `ContentBlockStart` instance.
     pub fn new(
         index: u32,
-        content_block: TextContentBlock,
+        content_block: ContentBlock,
     ) -> Self {
         Self {
             _type: MessageChunkType::ContentBlockStart,
@@ -381,8 +562,8 @@ pub struct ContentBlockDeltaChunk {
     pub _type: MessageChunkType,
     /// The index.
     pub index: u32,
-    /// The text delta content block.
-    pub delta: TextDeltaContentBlock,
+    /// The delta content block.
+    pub delta: DeltaContentBlock,
 }
 
 impl Default for ContentBlockDeltaChunk {
@@ -401,7 +582,7 @@ impl ContentBlockDeltaChunk {
     /// Creates a new `ContentBlockDelta` instance.
     pub fn new(
         index: u32,
-        delta: TextDeltaContentBlock,
+        delta: DeltaContentBlock,
     ) -> Self {
         Self {
             _type: MessageChunkType::ContentBlockDelta,
@@ -507,6 +688,68 @@ impl MessageStopChunk {
     }
 }
 
+/// The delta content block of the content block delta chunk.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeltaContentBlock {
+    /// The text delta of a text content block.
+    TextDelta(TextDeltaContentBlock),
+    /// The partial JSON delta of a tool input of a tool use content block.
+    InputJsonDelta(InputJsonDeltaContentBlock),
+}
+
+impl Default for DeltaContentBlock {
+    fn default() -> Self {
+        Self::TextDelta(TextDeltaContentBlock::default())
+    }
+}
+
+impl From<String> for DeltaContentBlock {
+    fn from(text: String) -> Self {
+        Self::TextDelta(TextDeltaContentBlock::new(text))
+    }
+}
+
+impl From<&str> for DeltaContentBlock {
+    fn from(text: &str) -> Self {
+        Self::TextDelta(TextDeltaContentBlock::new(text))
+    }
+}
+
+impl_enum_struct_serialization!(
+    DeltaContentBlock,
+    type,
+    TextDelta(TextDeltaContentBlock, "text_delta"),
+    InputJsonDelta(InputJsonDeltaContentBlock, "input_json_delta")
+);
+
+impl_display_for_serialize!(DeltaContentBlock);
+
+impl DeltaContentBlock {
+    /// The text of the text delta.
+    ///
+    /// It returns `None` for a partial JSON delta.
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            | DeltaContentBlock::TextDelta(text_delta) => {
+                Some(&text_delta.text)
+            },
+            | DeltaContentBlock::InputJsonDelta(_) => None,
+        }
+    }
+
+    /// The partial JSON of the tool input delta.
+    ///
+    /// It returns `None` for a text delta.
+    pub fn as_partial_json(&self) -> Option<&str> {
+        match self {
+            | DeltaContentBlock::TextDelta(_) => None,
+            | DeltaContentBlock::InputJsonDelta(input_json_delta) => {
+                Some(&input_json_delta.partial_json)
+            },
+        }
+    }
+}
+
 /// The text delta content block.
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TextDeltaContentBlock {
@@ -553,6 +796,40 @@ impl TextDeltaContentBlock {
     }
 }
 
+/// The input JSON delta content block of a tool input.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct InputJsonDeltaContentBlock {
+    /// The content type. It is always `input_json_delta`.
+    #[serde(rename = "type")]
+    pub _type: ContentType,
+    /// The partial JSON of a tool input.
+    pub partial_json: String,
+}
+
+impl Default for InputJsonDeltaContentBlock {
+    fn default() -> Self {
+        Self {
+            _type: ContentType::InputJsonDelta,
+            partial_json: String::new(),
+        }
+    }
+}
+
+impl_display_for_serialize!(InputJsonDeltaContentBlock);
+
+impl InputJsonDeltaContentBlock {
+    /// Creates a new input JSON delta content block.
+    pub fn new<S>(partial_json: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            _type: ContentType::InputJsonDelta,
+            partial_json: partial_json.into(),
+        }
+    }
+}
+
 /// The stream stop information.
 #[derive(
     Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
@@ -620,6 +897,10 @@ mod tests {
             MessageChunkType::from_str("message_stop").unwrap(),
             MessageChunkType::MessageStop
         );
+        assert_eq!(
+            MessageChunkType::from_str("error").unwrap(),
+            MessageChunkType::Error
+        );
     }
 
     #[test]
@@ -652,6 +933,10 @@ mod tests {
             MessageChunkType::MessageStop.to_string(),
             "message_stop"
         );
+        assert_eq!(
+            MessageChunkType::Error.to_string(),
+            "error"
+        );
     }
 
     #[test]
@@ -686,6 +971,10 @@ mod tests {
             serde_json::to_string(&MessageChunkType::MessageStop).unwrap(),
             r#""message_stop""#
         );
+        assert_eq!(
+            serde_json::to_string(&MessageChunkType::Error).unwrap(),
+            r#""error""#
+        );
     }
 
     #[test]
@@ -728,6 +1017,10 @@ mod tests {
                 .unwrap(),
             MessageChunkType::MessageStop
         );
+        assert_eq!(
+            serde_json::from_str::<MessageChunkType>(r#""error""#).unwrap(),
+            MessageChunkType::Error
+        );
     }
 
     #[test]
@@ -844,18 +1137,19 @@ mod tests {
                 _type: MessageObjectType::Message,
                 role: Role::Assistant,
                 content: "content".into(),
-                model: ClaudeModel::Claude3Sonnet20240229,
+                model: ClaudeModel::Claude35Sonnet20241022,
                 stop_reason: Some(StopReason::EndTurn),
                 stop_sequence: Some(StopSequence::new("stop_sequence")),
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    ..Default::default()
                 },
             },
         };
         assert_eq!(
             message_start.to_string(),
-            "{\n  \"type\": \"message_start\",\n  \"message\": {\n    \"id\": \"id\",\n    \"type\": \"message\",\n    \"role\": \"assistant\",\n    \"content\": \"content\",\n    \"model\": \"claude-3-sonnet-20240229\",\n    \"stop_reason\": \"end_turn\",\n    \"stop_sequence\": \"stop_sequence\",\n    \"usage\": {\n      \"input_tokens\": 1,\n      \"output_tokens\": 2\n    }\n  }\n}"
+            "{\n  \"type\": \"message_start\",\n  \"message\": {\n    \"id\": \"id\",\n    \"type\": \"message\",\n    \"role\": \"assistant\",\n    \"content\": \"content\",\n    \"model\": \"claude-3-5-sonnet-20241022\",\n    \"stop_reason\": \"end_turn\",\n    \"stop_sequence\": \"stop_sequence\",\n    \"usage\": {\n      \"input_tokens\": 1,\n      \"output_tokens\": 2\n    }\n  }\n}"
         );
     }
 
@@ -868,18 +1162,19 @@ mod tests {
                 _type: MessageObjectType::Message,
                 role: Role::Assistant,
                 content: "content".into(),
-                model: ClaudeModel::Claude3Sonnet20240229,
+                model: ClaudeModel::Claude35Sonnet20241022,
                 stop_reason: Some(StopReason::EndTurn),
                 stop_sequence: Some(StopSequence::new("stop_sequence")),
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    ..Default::default()
                 },
             },
         };
         assert_eq!(
             serde_json::to_string(&message_start).unwrap(),
-            r#"{"type":"message_start","message":{"id":"id","type":"message","role":"assistant","content":"content","model":"claude-3-sonnet-20240229","stop_reason":"end_turn","stop_sequence":"stop_sequence","usage":{"input_tokens":1,"output_tokens":2}}}"#
+            r#"{"type":"message_start","message":{"id":"id","type":"message","role":"assistant","content":"content","model":"claude-3-5-sonnet-20241022","stop_reason":"end_turn","stop_sequence":"stop_sequence","usage":{"input_tokens":1,"output_tokens":2}}}"#
         );
     }
 
@@ -892,18 +1187,19 @@ mod tests {
                 _type: MessageObjectType::Message,
                 role: Role::Assistant,
                 content: "content".into(),
-                model: ClaudeModel::Claude3Sonnet20240229,
+                model: ClaudeModel::Claude35Sonnet20241022,
                 stop_reason: Some(StopReason::EndTurn),
                 stop_sequence: Some(StopSequence::new("stop_sequence")),
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    ..Default::default()
                 },
             },
         };
         assert_eq!(
             serde_json::from_str::<MessageStartChunk>(
-                r#"{"type":"message_start","message":{"id":"id","type":"message","role":"assistant","content":"content","model":"claude-3-sonnet-20240229","stop_reason":"end_turn","stop_sequence":"stop_sequence","usage":{"input_tokens":1,"output_tokens":2}}}"#
+                r#"{"type":"message_start","message":{"id":"id","type":"message","role":"assistant","content":"content","model":"claude-3-5-sonnet-20241022","stop_reason":"end_turn","stop_sequence":"stop_sequence","usage":{"input_tokens":1,"output_tokens":2}}}"#
             )
             .unwrap(),
             message_start
@@ -927,10 +1223,10 @@ mod tests {
         let content_block_start = ContentBlockStartChunk {
             _type: MessageChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             content_block_start.to_string(),
@@ -943,10 +1239,10 @@ mod tests {
         let content_block_start = ContentBlockStartChunk {
             _type: MessageChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             serde_json::to_string(&content_block_start).unwrap(),
@@ -959,10 +1255,10 @@ mod tests {
         let content_block_start = ContentBlockStartChunk {
             _type: MessageChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         assert_eq!(
             serde_json::from_str::<ContentBlockStartChunk>(
@@ -971,6 +1267,22 @@ mod tests {
             .unwrap(),
             content_block_start
         );
+
+        let content_block_start = ContentBlockStartChunk::new(
+            1,
+            ContentBlock::from(crate::messages::ToolUse::new(
+                "toolu_01",
+                "get_weather",
+                serde_json::json!({}),
+            )),
+        );
+        assert_eq!(
+            serde_json::from_str::<ContentBlockStartChunk>(
+                "{\"type\":\"content_block_start\",\"index\":1,\"content_block\":{\"type\":\"tool_use\",\"id\":\"toolu_01\",\"name\":\"get_weather\",\"input\":{}}}"
+            )
+            .unwrap(),
+            content_block_start
+        );
     }
 
     #[test]
@@ -1030,7 +1342,7 @@ mod tests {
             delta: TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }.into(),
         };
         assert_eq!(
             content_block_delta.to_string(),
@@ -1046,7 +1358,7 @@ mod tests {
             delta: TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }.into(),
         };
         assert_eq!(
             serde_json::to_string(&content_block_delta).unwrap(),
@@ -1062,7 +1374,7 @@ mod tests {
             delta: TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }.into(),
         };
         assert_eq!(
             serde_json::from_str::<ContentBlockDeltaChunk>(
@@ -1241,22 +1553,23 @@ mod tests {
                 _type: MessageObjectType::Message,
                 role: Role::Assistant,
                 content: "content".into(),
-                model: ClaudeModel::Claude3Sonnet20240229,
+                model: ClaudeModel::Claude35Sonnet20241022,
                 stop_reason: Some(StopReason::EndTurn),
                 stop_sequence: Some(StopSequence::new("stop_sequence")),
                 usage: Usage {
                     input_tokens: 1,
                     output_tokens: 2,
+                    ..Default::default()
                 },
             },
         };
         let content_block_start = ContentBlockStartChunk {
             _type: MessageChunkType::ContentBlockStart,
             index: 1,
-            content_block: TextContentBlock {
+            content_block: ContentBlock::Text(TextContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }),
         };
         let ping = PingChunk::default();
         let content_block_delta = ContentBlockDeltaChunk {
@@ -1265,7 +1578,7 @@ mod tests {
             delta: TextDeltaContentBlock {
                 text: "text".to_string(),
                 ..Default::default()
-            },
+            }.into(),
         };
         let content_block_stop = ContentBlockStopChunk {
             _type: MessageChunkType::ContentBlockStop,
@@ -1285,7 +1598,7 @@ mod tests {
 
         assert_eq!(
             MessageChunk::MessageStart(message_start).to_string(),
-            "event: message_start\ndata: {\"type\": \"message_start\", \"message\": {\"id\": \"id\", \"type\": \"message\", \"role\": \"assistant\", \"content\": \"content\", \"model\": \"claude-3-sonnet-20240229\", \"stop_reason\": \"end_turn\", \"stop_sequence\": \"stop_sequence\", \"usage\": {\"input_tokens\": 1, \"output_tokens\": 2}}}"
+            "event: message_start\ndata: {\"type\": \"message_start\", \"message\": {\"id\": \"id\", \"type\": \"message\", \"role\": \"assistant\", \"content\": \"content\", \"model\": \"claude-3-5-sonnet-20241022\", \"stop_reason\": \"end_turn\", \"stop_sequence\": \"stop_sequence\", \"usage\": {\"input_tokens\": 1, \"output_tokens\": 2}}}"
         );
 
         assert_eq!(
@@ -1340,6 +1653,7 @@ data: {"type": "message_start", "message": {"id": "msg_1nZdL29xx5MUA1yADyHTEsnR8
                     usage: Usage {
                         input_tokens: 25,
                         output_tokens: 1,
+                        ..Default::default()
                     },
                 },
             })
@@ -1351,10 +1665,10 @@ data: {"type": "content_block_start", "index": 0, "content_block": {"type": "tex
             MessageChunk::ContentBlockStart(ContentBlockStartChunk {
                 _type: MessageChunkType::ContentBlockStart,
                 index: 0,
-                content_block: TextContentBlock {
+                content_block: ContentBlock::Text(TextContentBlock {
                     text: "".to_string(),
                     ..Default::default()
-                },
+                }),
             })
         );
 
@@ -1379,7 +1693,7 @@ data: {"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta"
                 delta: TextDeltaContentBlock {
                     text: "Hello".to_string(),
                     ..Default::default()
-                },
+                }.into(),
             })
         );
 
@@ -1425,6 +1739,79 @@ data: {"type": "message_stop"}"#
         assert!(MessageChunk::parse("event: unknown\ndata: {}").is_err());
     }
 
+    #[test]
+    fn classify_chunk() {
+        let text_delta = MessageChunk::ContentBlockDelta(
+            ContentBlockDeltaChunk::new(1, "text".into()),
+        );
+        let tool_input_delta =
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                2,
+                InputJsonDeltaContentBlock::new("{}").into(),
+            ));
+        let stop = MessageChunk::MessageStop(MessageStopChunk::new());
+        let ping = MessageChunk::Ping(PingChunk::new());
+
+        assert!(stop.is_terminal());
+        assert!(!text_delta.is_terminal());
+
+        assert!(text_delta.is_content_delta());
+        assert!(tool_input_delta.is_content_delta());
+        assert!(!ping.is_content_delta());
+
+        assert_eq!(text_delta.as_text_delta(), Some("text"));
+        assert_eq!(tool_input_delta.as_text_delta(), None);
+        assert_eq!(ping.as_text_delta(), None);
+
+        assert_eq!(text_delta.as_tool_input_delta(), None);
+        assert_eq!(
+            tool_input_delta.as_tool_input_delta(),
+            Some((2, "{}"))
+        );
+
+        assert_eq!(text_delta.index(), Some(1));
+        assert_eq!(
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                3,
+                "".into(),
+            ))
+            .index(),
+            Some(3)
+        );
+        assert_eq!(
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(4))
+                .index(),
+            Some(4)
+        );
+        assert_eq!(stop.index(), None);
+    }
+
+    #[test]
+    fn parse_error_event() {
+        let chunk = MessageChunk::parse(
+            r#"event: error
+data: {"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            chunk,
+            MessageChunk::Error(ApiErrorBody {
+                _type: "overloaded_error".to_string(),
+                message: "Overloaded".to_string(),
+            })
+        );
+        assert_eq!(
+            chunk.to_string(),
+            r#"event: error
+data: {"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}"#
+        );
+
+        assert!(matches!(
+            MessageChunk::parse("event: error\ndata: {}"),
+            Err(StreamError::ChunkDataDeserializationError(_))
+        ));
+    }
+
     #[test]
     fn new_text_delta_content_block() {
         let text_delta_content_block =
@@ -1495,4 +1882,101 @@ data: {"type": "message_stop"}"#
             TextDeltaContentBlock::new("text")
         );
     }
+
+    #[test]
+    fn new_input_json_delta_content_block() {
+        assert_eq!(
+            InputJsonDeltaContentBlock::new("{}"),
+            InputJsonDeltaContentBlock {
+                _type: ContentType::InputJsonDelta,
+                partial_json: "{}".to_string(),
+            }
+        );
+        assert_eq!(
+            InputJsonDeltaContentBlock::default(),
+            InputJsonDeltaContentBlock::new("")
+        );
+    }
+
+    #[test]
+    fn serialize_delta_content_block() {
+        assert_eq!(
+            serde_json::to_string(&DeltaContentBlock::from("text")).unwrap(),
+            "{\"type\":\"text_delta\",\"text\":\"text\"}"
+        );
+        assert_eq!(
+            serde_json::to_string(&DeltaContentBlock::from(
+                InputJsonDeltaContentBlock::new("{}")
+            ))
+            .unwrap(),
+            "{\"type\":\"input_json_delta\",\"partial_json\":\"{}\"}"
+        );
+    }
+
+    #[test]
+    fn deserialize_delta_content_block() {
+        assert_eq!(
+            serde_json::from_str::<DeltaContentBlock>(
+                "{\"type\":\"text_delta\",\"text\":\"text\"}"
+            )
+            .unwrap(),
+            DeltaContentBlock::from("text")
+        );
+        assert_eq!(
+            serde_json::from_str::<DeltaContentBlock>(
+                "{\"type\":\"input_json_delta\",\"partial_json\":\"{}\"}"
+            )
+            .unwrap(),
+            DeltaContentBlock::from(InputJsonDeltaContentBlock::new("{}"))
+        );
+        assert!(serde_json::from_str::<DeltaContentBlock>(
+            "{\"type\":\"input_json_delta\",\"text\":\"{}\"}"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn delta_content_block_accessors() {
+        let text_delta = DeltaContentBlock::from("text");
+        assert_eq!(text_delta.as_text(), Some("text"));
+        assert_eq!(text_delta.as_partial_json(), None);
+
+        let input_json_delta =
+            DeltaContentBlock::from(InputJsonDeltaContentBlock::new("{}"));
+        assert_eq!(input_json_delta.as_text(), None);
+        assert_eq!(input_json_delta.as_partial_json(), Some("{}"));
+    }
+
+    #[test]
+    fn message_start_body() {
+        let body = MessagesResponseBody {
+            id: "msg_id".to_string(),
+            ..Default::default()
+        };
+        let chunk =
+            MessageChunk::MessageStart(MessageStartChunk::new(body.clone()));
+
+        assert_eq!(chunk.as_message_start_body(), Some(&body));
+        assert_eq!(chunk.message_start_id(), Some("msg_id"));
+        assert_eq!(
+            chunk
+                .clone()
+                .into_response_body(),
+            Some(body.clone())
+        );
+        assert_eq!(
+            MessagesResponseBody::try_from(chunk).unwrap(),
+            body
+        );
+
+        let chunk = MessageChunk::Ping(PingChunk::new());
+        assert_eq!(chunk.as_message_start_body(), None);
+        assert_eq!(chunk.message_start_id(), None);
+        assert_eq!(
+            MessagesResponseBody::try_from(chunk).unwrap_err(),
+            MessageChunkConversionError::UnexpectedChunkType(
+                MessageChunkType::Ping
+            )
+        );
+    }
 }
@@ -0,0 +1,410 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_core::Stream;
+use pin_project::pin_project;
+
+use crate::messages::{
+    ContentBlockDeltaChunk, DeltaContentBlock, MessageChunk, StreamError,
+};
+
+/// The extension trait for the stream of message chunks returned by `clust::Client::create_a_message_stream`.
+///
+/// ## Example
+/// ```rust,no_run
+/// use clust::messages::{MessageChunk, MessageStreamExt, StreamError};
+/// use futures_core::Stream;
+///
+/// async fn print_text(
+///     stream: impl Stream<Item = Result<MessageChunk, StreamError>> + Unpin,
+/// ) -> Result<(), StreamError> {
+///     let text = stream.full_text().await?;
+///     println!("{}", text);
+///     Ok(())
+/// }
+/// ```
+pub trait MessageStreamExt:
+    Stream<Item = Result<MessageChunk, StreamError>> + Sized
+{
+    /// Converts into the stream of only text delta strings.
+    ///
+    /// Chunks other than the text delta and errors are skipped.
+    fn text_deltas(self) -> TextDeltas<Self> {
+        TextDeltas {
+            stream: self,
+        }
+    }
+
+    /// Collects all text deltas into a single string.
+    ///
+    /// ## Errors
+    /// It returns the first error in the stream.
+    fn full_text(self) -> FullText<Self> {
+        FullText {
+            stream: self,
+            text: String::new(),
+        }
+    }
+
+    /// Removes `MessageChunk::Ping` chunks from the stream.
+    fn filter_pings(self) -> FilterPings<Self> {
+        FilterPings {
+            stream: self,
+        }
+    }
+
+    /// Ends the stream after a `MessageChunk::MessageStop` chunk.
+    fn until_stop(self) -> UntilStop<Self> {
+        UntilStop {
+            stream: self,
+            stopped: false,
+        }
+    }
+}
+
+impl<S> MessageStreamExt for S where
+    S: Stream<Item = Result<MessageChunk, StreamError>> + Sized
+{
+}
+
+/// The stream of text delta strings created by `MessageStreamExt::text_deltas`.
+#[pin_project]
+pub struct TextDeltas<S> {
+    #[pin]
+    stream: S,
+}
+
+impl<S> Stream for TextDeltas<S>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    type Item = String;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                | Poll::Ready(Some(Ok(MessageChunk::ContentBlockDelta(
+                    ContentBlockDeltaChunk {
+                        delta: DeltaContentBlock::TextDelta(text_delta),
+                        ..
+                    },
+                )))) => {
+                    return Poll::Ready(Some(text_delta.text));
+                },
+                // Skip other chunks and errors.
+                | Poll::Ready(Some(_)) => {},
+                | Poll::Ready(None) => return Poll::Ready(None),
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// Converts the stream of message chunks into the stream of only text delta strings.
+///
+/// Unlike `MessageStreamExt::text_deltas`, errors are passed through and chunks other than the text delta are skipped.
+///
+/// ## Arguments
+/// - `stream` - The stream of message chunks.
+///
+/// ## Example
+/// ```rust,no_run
+/// use clust::messages::{text_delta_stream, MessageChunk, StreamError};
+/// use futures_core::Stream;
+/// use tokio_stream::StreamExt;
+///
+/// async fn print_text(
+///     stream: impl Stream<Item = Result<MessageChunk, StreamError>>,
+/// ) -> Result<(), StreamError> {
+///     let mut texts = Box::pin(text_delta_stream(stream));
+///     while let Some(text) = texts.next().await {
+///         print!("{}", text?);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub fn text_delta_stream<S>(stream: S) -> TryTextDeltas<S>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    TryTextDeltas {
+        stream,
+    }
+}
+
+/// The stream of text delta strings and errors created by `text_delta_stream`.
+#[pin_project]
+pub struct TryTextDeltas<S> {
+    #[pin]
+    stream: S,
+}
+
+impl<S> Stream for TryTextDeltas<S>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    type Item = Result<String, StreamError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                | Poll::Ready(Some(Ok(MessageChunk::ContentBlockDelta(
+                    ContentBlockDeltaChunk {
+                        delta: DeltaContentBlock::TextDelta(text_delta),
+                        ..
+                    },
+                )))) => {
+                    return Poll::Ready(Some(Ok(text_delta.text)));
+                },
+                // Skip other chunks.
+                | Poll::Ready(Some(Ok(_))) => {},
+                | Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(Some(Err(error)));
+                },
+                | Poll::Ready(None) => return Poll::Ready(None),
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// The future of collected text created by `MessageStreamExt::full_text`.
+#[pin_project]
+pub struct FullText<S> {
+    #[pin]
+    stream: S,
+    text: String,
+}
+
+impl<S> Future for FullText<S>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    type Output = Result<String, StreamError>;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Self::Output> {
+        let mut this = self.project();
+
+        loop {
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                | Poll::Ready(Some(Ok(MessageChunk::ContentBlockDelta(
+                    ContentBlockDeltaChunk {
+                        delta: DeltaContentBlock::TextDelta(text_delta),
+                        ..
+                    },
+                )))) => {
+                    this.text
+                        .push_str(&text_delta.text);
+                },
+                | Poll::Ready(Some(Ok(_))) => {},
+                | Poll::Ready(Some(Err(error))) => {
+                    return Poll::Ready(Err(error));
+                },
+                | Poll::Ready(None) => {
+                    return Poll::Ready(Ok(std::mem::take(this.text)));
+                },
+                | Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+/// The stream without ping chunks created by `MessageStreamExt::filter_pings`.
+#[pin_project]
+pub struct FilterPings<S> {
+    #[pin]
+    stream: S,
+}
+
+impl<S> Stream for FilterPings<S>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    type Item = Result<MessageChunk, StreamError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+
+        loop {
+            match this
+                .stream
+                .as_mut()
+                .poll_next(cx)
+            {
+                | Poll::Ready(Some(Ok(MessageChunk::Ping(_)))) => {},
+                | other => return other,
+            }
+        }
+    }
+}
+
+/// The stream ending after the message stop chunk created by `MessageStreamExt::until_stop`.
+#[pin_project]
+pub struct UntilStop<S> {
+    #[pin]
+    stream: S,
+    stopped: bool,
+}
+
+impl<S> Stream for UntilStop<S>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    type Item = Result<MessageChunk, StreamError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        if *this.stopped {
+            return Poll::Ready(None);
+        }
+
+        let poll = this.stream.poll_next(cx);
+        if let Poll::Ready(Some(Ok(MessageChunk::MessageStop(_)))) = &poll {
+            *this.stopped = true;
+        }
+
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        ContentBlockDeltaChunk, MessageStopChunk, PingChunk,
+    };
+    use tokio_stream::StreamExt;
+
+    fn chunks() -> Vec<Result<MessageChunk, StreamError>> {
+        vec![
+            Ok(MessageChunk::Ping(PingChunk::new())),
+            Ok(MessageChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(0, "Hello".into()),
+            )),
+            Ok(MessageChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(0, ", world!".into()),
+            )),
+            Ok(MessageChunk::MessageStop(MessageStopChunk::new())),
+            Ok(MessageChunk::Ping(PingChunk::new())),
+        ]
+    }
+
+    #[tokio::test]
+    async fn text_deltas() {
+        let deltas: Vec<String> = tokio_stream::iter(chunks())
+            .text_deltas()
+            .collect()
+            .await;
+
+        assert_eq!(
+            deltas,
+            vec!["Hello".to_string(), ", world!".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn text_delta_stream() {
+        let mut chunks = chunks();
+        chunks.insert(
+            2,
+            Err(StreamError::ParseChunkStringError(
+                "error".to_string(),
+            )),
+        );
+        let deltas: Vec<Result<String, StreamError>> =
+            super::text_delta_stream(tokio_stream::iter(chunks))
+                .collect()
+                .await;
+
+        assert_eq!(deltas.len(), 3);
+        assert_eq!(deltas[0].as_ref().unwrap(), "Hello");
+        assert!(matches!(
+            deltas[1],
+            Err(StreamError::ParseChunkStringError(_))
+        ));
+        assert_eq!(deltas[2].as_ref().unwrap(), ", world!");
+    }
+
+    #[tokio::test]
+    async fn full_text() {
+        let text = tokio_stream::iter(chunks())
+            .full_text()
+            .await
+            .unwrap();
+        assert_eq!(text, "Hello, world!");
+
+        let mut chunks = chunks();
+        chunks.insert(
+            1,
+            Err(StreamError::ParseChunkStringError(
+                "error".to_string(),
+            )),
+        );
+        let result = tokio_stream::iter(chunks)
+            .full_text()
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn filter_pings() {
+        let chunks: Vec<Result<MessageChunk, StreamError>> =
+            tokio_stream::iter(chunks())
+                .filter_pings()
+                .collect()
+                .await;
+
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks
+            .iter()
+            .all(|chunk| !matches!(chunk, Ok(MessageChunk::Ping(_)))));
+    }
+
+    #[tokio::test]
+    async fn until_stop() {
+        let chunks: Vec<Result<MessageChunk, StreamError>> =
+            tokio_stream::iter(chunks())
+                .until_stop()
+                .collect()
+                .await;
+
+        assert_eq!(chunks.len(), 4);
+        assert!(matches!(
+            chunks.last(),
+            Some(Ok(MessageChunk::MessageStop(_)))
+        ));
+    }
+}
@@ -1,7 +1,8 @@
 use crate::macros::impl_display_for_serialize;
 use crate::messages::{
     ClaudeModel, MaxTokens, Message, Metadata, StopSequence, StreamOption,
-    SystemPrompt, Temperature, ToolDefinition, TopK, TopP,
+    RequestValidationError, SystemPrompt, Temperature, TemperaturePreset,
+    ToolChoice, ToolDefinition, TopK, TopP,
 };
 use crate::ValidationError;
 
@@ -68,6 +69,11 @@ pub struct MessagesRequestBody {
     /// - input_schema: JSON schema for the tool input shape that the model will produce in tool_use output content blocks.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tools: Option<Vec<ToolDefinition>>,
+    /// How the model should use the provided tools.
+    ///
+    /// The model can use a specific tool, any available tool, or decide by itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
     /// Use nucleus sampling.
     ///
     /// In nucleus sampling, we compute the cumulative distribution over all the options for each subsequent token in decreasing probability order and cut it off once it reaches a particular probability specified by top_p. You should either alter temperature or top_p, but not both.
@@ -86,16 +92,316 @@ pub struct MessagesRequestBody {
 
 impl_display_for_serialize!(MessagesRequestBody);
 
+impl MessagesRequestBody {
+    /// Applies the non-`None` fields of the overrides to this request body.
+    ///
+    /// ## Arguments
+    /// - `overrides` - The overrides for a template request body.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, MessagesRequestBody, MessagesRequestBodyOverrides, SystemPrompt, Temperature};
+    ///
+    /// let mut request_body = MessagesRequestBody {
+    ///     system: Some(SystemPrompt::new("template-system-prompt")),
+    ///     ..Default::default()
+    /// };
+    /// request_body.apply_overrides(&MessagesRequestBodyOverrides {
+    ///     model: Some(ClaudeModel::Claude3Haiku20240307),
+    ///     temperature: Some(Temperature::new(0.5).unwrap()),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// assert_eq!(request_body.model, ClaudeModel::Claude3Haiku20240307);
+    /// assert_eq!(request_body.system, Some(SystemPrompt::new("template-system-prompt")));
+    /// assert_eq!(request_body.temperature, Some(Temperature::new(0.5).unwrap()));
+    /// ```
+    pub fn apply_overrides(
+        &mut self,
+        overrides: &MessagesRequestBodyOverrides,
+    ) {
+        if let Some(model) = overrides.model {
+            self.model = model;
+        }
+        if let Some(messages) = &overrides.messages {
+            self.messages = messages.clone();
+        }
+        if let Some(system) = &overrides.system {
+            self.system = Some(system.clone());
+        }
+        if let Some(max_tokens) = overrides.max_tokens {
+            self.max_tokens = max_tokens;
+        }
+        if let Some(metadata) = &overrides.metadata {
+            self.metadata = Some(metadata.clone());
+        }
+        if let Some(stop_sequences) = &overrides.stop_sequences {
+            self.stop_sequences = Some(stop_sequences.clone());
+        }
+        if let Some(stream) = overrides.stream {
+            self.stream = Some(stream);
+        }
+        if let Some(temperature) = overrides.temperature {
+            self.temperature = Some(temperature);
+        }
+        if let Some(tools) = &overrides.tools {
+            self.tools = Some(tools.clone());
+        }
+        if let Some(tool_choice) = &overrides.tool_choice {
+            self.tool_choice = Some(tool_choice.clone());
+        }
+        if let Some(top_p) = overrides.top_p {
+            self.top_p = Some(top_p);
+        }
+        if let Some(top_k) = overrides.top_k {
+            self.top_k = Some(top_k);
+        }
+    }
+
+    /// Replaces the tools of this request body.
+    ///
+    /// An empty list removes the tools from the request.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessagesRequestBody, ToolDefinition};
+    ///
+    /// let should_use_tools = false;
+    /// let tool_definitions = vec![ToolDefinition::new(
+    ///     "get_weather",
+    ///     Some("Get the current weather.".to_string()),
+    ///     serde_json::json!({"type": "object"}),
+    /// )];
+    ///
+    /// let request_body = MessagesRequestBody::default().with_tools(
+    ///     if should_use_tools {
+    ///         tool_definitions
+    ///     } else {
+    ///         vec![]
+    ///     },
+    /// );
+    /// assert_eq!(request_body.tools, None);
+    /// ```
+    pub fn with_tools(
+        mut self,
+        tools: impl IntoIterator<Item = ToolDefinition>,
+    ) -> Self {
+        let tools = tools
+            .into_iter()
+            .collect::<Vec<_>>();
+        self.tools = if tools.is_empty() {
+            None
+        } else {
+            Some(tools)
+        };
+        self
+    }
+
+    /// Replaces the messages of this request body.
+    pub fn with_messages(
+        mut self,
+        messages: Vec<Message>,
+    ) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Appends the messages to the messages of this request body.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Message, MessagesRequestBody, SystemPrompt};
+    ///
+    /// let request_body = MessagesRequestBody::default()
+    ///     .with_system(SystemPrompt::new("system-prompt"))
+    ///     .with_messages(vec![Message::user("first")])
+    ///     .append_messages(vec![
+    ///         Message::assistant("second"),
+    ///         Message::user("third"),
+    ///     ]);
+    ///
+    /// assert_eq!(request_body.messages.len(), 3);
+    /// assert!(request_body.system.is_some());
+    /// ```
+    pub fn append_messages(
+        mut self,
+        messages: Vec<Message>,
+    ) -> Self {
+        self.messages.extend(messages);
+        self
+    }
+
+    /// Replaces the system prompt of this request body.
+    pub fn with_system(
+        mut self,
+        system: SystemPrompt,
+    ) -> Self {
+        self.system = Some(system);
+        self
+    }
+
+    /// Removes the system prompt from this request body.
+    pub fn without_system(mut self) -> Self {
+        self.system = None;
+        self
+    }
+
+    /// Replaces the tool choice of this request body.
+    pub fn with_tool_choice(
+        mut self,
+        choice: ToolChoice,
+    ) -> Self {
+        self.tool_choice = Some(choice);
+        self
+    }
+
+    /// The stop sequences, or an empty slice if not set.
+    pub fn stop_sequences(&self) -> &[StopSequence] {
+        self.stop_sequences
+            .as_deref()
+            .unwrap_or(&[])
+    }
+
+    /// The estimated size in bytes of this request body serialized as JSON.
+    ///
+    /// Large images are the primary contributor to the request size.
+    /// The image data is held as Base64 which is about 4/3 times larger than the raw bytes,
+    /// and this estimate includes the expansion because it measures the serialized JSON.
+    /// It returns `0` if the serialization fails.
+    #[cold]
+    pub fn estimated_size_bytes(&self) -> usize {
+        serde_json::to_string(self)
+            .map(|json| json.len())
+            .unwrap_or(0)
+    }
+
+    /// Whether the estimated size of this request body exceeds the limit.
+    ///
+    /// ## Arguments
+    /// - `max_bytes` - The maximum size in bytes.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessagesRequestBody, Message};
+    ///
+    /// let request_body = MessagesRequestBody {
+    ///     messages: vec![Message::user("Hello, Claude!")],
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(!request_body.exceeds_size_limit(32 * 1024 * 1024));
+    /// assert!(request_body.exceeds_size_limit(16));
+    /// ```
+    pub fn exceeds_size_limit(
+        &self,
+        max_bytes: usize,
+    ) -> bool {
+        self.estimated_size_bytes() > max_bytes
+    }
+
+    /// Validates this request body before sending it.
+    ///
+    /// ## Errors
+    /// It returns `RequestValidationError::UnknownToolChoice` if `ToolChoice::Tool` specifies a tool not in the tools.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{
+    ///     MessagesRequestBuilder, ClaudeModel, RequestValidationError, ToolChoice, ToolDefinition,
+    /// };
+    ///
+    /// let request_body = MessagesRequestBuilder::new(ClaudeModel::Claude35Sonnet20241022)
+    ///     .tools(vec![ToolDefinition::new(
+    ///         "get_weather",
+    ///         None::<String>,
+    ///         serde_json::json!({"type": "object"}),
+    ///     )])
+    ///     .tool_choice(ToolChoice::Tool("get_time".to_string()))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     request_body.validate(),
+    ///     Err(RequestValidationError::UnknownToolChoice("get_time".to_string()))
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), RequestValidationError> {
+        if let Some(ToolChoice::Tool(name)) = &self.tool_choice {
+            let found = self
+                .tools
+                .iter()
+                .flatten()
+                .any(|tool| tool.name == *name);
+            if !found {
+                return Err(RequestValidationError::UnknownToolChoice(
+                    name.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Converts into a builder to modify this request body.
+    pub fn into_builder(self) -> MessagesRequestBuilder {
+        MessagesRequestBuilder::from_body(self)
+    }
+}
+
+/// The overrides for `MessagesRequestBody` applied by `MessagesRequestBody::apply_overrides`.
+///
+/// Each `None` field keeps the original value.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessagesRequestBodyOverrides {
+    /// The model that will complete your prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<ClaudeModel>,
+    /// Input messages.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub messages: Option<Vec<Message>>,
+    /// System prompt.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system: Option<SystemPrompt>,
+    /// The maximum number of tokens to generate before stopping.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<MaxTokens>,
+    /// An object describing metadata about the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Metadata>,
+    /// Custom text sequences that will cause the model to stop generating.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<StopSequence>>,
+    /// Whether to incrementally stream the response using server-sent events.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<StreamOption>,
+    /// Amount of randomness injected into the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// Definitions of tools that the model may use.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tools: Option<Vec<ToolDefinition>>,
+    /// How the model should use the provided tools.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_choice: Option<ToolChoice>,
+    /// Use nucleus sampling.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<TopP>,
+    /// Only sample from the top K options for each subsequent token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<TopK>,
+}
+
 /// A builder for the `MessagesRequestBody`.
 ///
 /// ## Example
 /// ```
 /// use clust::messages::{MessagesRequestBuilder, ClaudeModel, Message, SystemPrompt, MaxTokens, Metadata, StopSequence, StreamOption, Temperature, TopP, TopK};
 ///
-/// let request_body = MessagesRequestBuilder::new(ClaudeModel::Claude3Sonnet20240229)
+/// let request_body = MessagesRequestBuilder::new(ClaudeModel::Claude35Sonnet20241022)
 ///     .messages(vec![Message::user("Hello, Claude!")])
 ///     .system(SystemPrompt::new("system-prompt"))
-///     .max_tokens(MaxTokens::new(1024, ClaudeModel::Claude3Sonnet20240229).unwrap())
+///     .max_tokens(MaxTokens::new(1024, ClaudeModel::Claude35Sonnet20241022).unwrap())
 ///     .metadata(Metadata { user_id: "metadata".into() })
 ///     .stop_sequences(vec![StopSequence::new("stop-sequence")])
 ///     .stream(StreamOption::ReturnOnce)
@@ -104,7 +410,7 @@ impl_display_for_serialize!(MessagesRequestBody);
 ///     .top_k(TopK::new(50))
 ///     .build();
 ///
-/// let request_body = MessagesRequestBuilder::new_with_max_tokens(ClaudeModel::Claude3Sonnet20240229, 1024).unwrap()
+/// let request_body = MessagesRequestBuilder::new_with_max_tokens(ClaudeModel::Claude35Sonnet20241022, 1024).unwrap()
 ///     .messages(vec![Message::user("Hello, Claude!")])
 ///     .system(SystemPrompt::new("system-prompt"))
 ///     .metadata(Metadata { user_id: "metadata".into() })
@@ -153,6 +459,27 @@ impl MessagesRequestBuilder {
         })
     }
 
+    /// Creates a new `MessagesRequestBuilder` from an existing request body.
+    ///
+    /// ## Arguments
+    /// - `body` - The request body to modify.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessagesRequestBody, MessagesRequestBuilder, Temperature};
+    ///
+    /// let template = MessagesRequestBody::default();
+    ///
+    /// let request_body = MessagesRequestBuilder::from_body(template)
+    ///     .temperature(Temperature::new(0.5).unwrap())
+    ///     .build();
+    /// ```
+    pub fn from_body(body: MessagesRequestBody) -> Self {
+        Self {
+            request_body: body,
+        }
+    }
+
     /// Sets the messages.
     pub fn messages(
         mut self,
@@ -199,6 +526,45 @@ impl MessagesRequestBuilder {
         self
     }
 
+    /// Appends a stop sequence.
+    pub fn add_stop_sequence(
+        mut self,
+        stop_sequence: StopSequence,
+    ) -> Self {
+        self.request_body
+            .stop_sequences
+            .get_or_insert_with(Vec::new)
+            .push(stop_sequence);
+        self
+    }
+
+    /// Removes all stop sequences equal to the value.
+    ///
+    /// The stop sequences are cleared if no stop sequence remains.
+    pub fn remove_stop_sequence(
+        mut self,
+        stop_sequence: &str,
+    ) -> Self {
+        if let Some(stop_sequences) = self
+            .request_body
+            .stop_sequences
+            .as_mut()
+        {
+            stop_sequences
+                .retain(|sequence| sequence.value() != stop_sequence);
+            if stop_sequences.is_empty() {
+                self.request_body.stop_sequences = None;
+            }
+        }
+        self
+    }
+
+    /// Clears the stop sequences.
+    pub fn clear_stop_sequences(mut self) -> Self {
+        self.request_body.stop_sequences = None;
+        self
+    }
+
     /// Sets the stream option.
     pub fn stream(
         mut self,
@@ -217,12 +583,50 @@ impl MessagesRequestBuilder {
         self
     }
 
+    /// Sets the temperature by the named preset.
+    ///
+    /// ## Arguments
+    /// - `preset` - The preset of the temperature.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessagesRequestBuilder, Temperature, TemperaturePreset};
+    ///
+    /// let request_body = MessagesRequestBuilder::default()
+    ///     .temperature_preset(TemperaturePreset::Creative)
+    ///     .build();
+    ///
+    /// assert_eq!(request_body.temperature, Some(Temperature::new(0.9).unwrap()));
+    /// ```
+    pub fn temperature_preset(
+        self,
+        preset: TemperaturePreset,
+    ) -> Self {
+        self.temperature(preset.into_temperature())
+    }
+
     /// Sets the tools.
+    ///
+    /// ## Arguments
+    /// - `tools` - The tool definitions, e.g. `Vec`, array or `ToolList::definitions()`.
     pub fn tools(
         mut self,
-        tools: Vec<ToolDefinition>,
+        tools: impl IntoIterator<Item = ToolDefinition>,
     ) -> Self {
-        self.request_body.tools = Some(tools);
+        self.request_body.tools = Some(
+            tools
+                .into_iter()
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets the tool choice.
+    pub fn tool_choice(
+        mut self,
+        choice: ToolChoice,
+    ) -> Self {
+        self.request_body.tool_choice = Some(choice);
         self
     }
 
@@ -250,28 +654,35 @@ impl MessagesRequestBuilder {
     }
 }
 
+impl From<MessagesRequestBody> for MessagesRequestBuilder {
+    fn from(body: MessagesRequestBody) -> Self {
+        Self::from_body(body)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::messages::{ImageContentSource, ImageMediaType};
 
     #[test]
     fn new() {
         let messages_request_body = MessagesRequestBody {
-            model: ClaudeModel::Claude3Sonnet20240229,
+            model: ClaudeModel::Claude35Sonnet20241022,
             messages: vec![],
-            max_tokens: MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+            max_tokens: MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022)
                 .unwrap(),
             ..Default::default()
         };
         assert_eq!(
             messages_request_body.model,
-            ClaudeModel::Claude3Sonnet20240229
+            ClaudeModel::Claude35Sonnet20241022
         );
         assert_eq!(messages_request_body.messages, vec![]);
         assert_eq!(messages_request_body.system, None);
         assert_eq!(
             messages_request_body.max_tokens,
-            MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229).unwrap()
+            MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022).unwrap()
         );
         assert_eq!(messages_request_body.metadata, None);
         assert_eq!(
@@ -305,16 +716,186 @@ mod tests {
         assert_eq!(messages_request_body.stream, None);
         assert_eq!(messages_request_body.temperature, None);
         assert_eq!(messages_request_body.tools, None);
+        assert_eq!(messages_request_body.tool_choice, None);
         assert_eq!(messages_request_body.top_p, None);
         assert_eq!(messages_request_body.top_k, None);
     }
 
+    #[test]
+    fn with_tools() {
+        let tool = ToolDefinition::new(
+            "tool",
+            None::<String>,
+            serde_json::json!({"type": "object"}),
+        );
+
+        let messages_request_body =
+            MessagesRequestBody::default().with_tools(vec![tool.clone()]);
+        assert_eq!(
+            messages_request_body.tools,
+            Some(vec![tool.clone()])
+        );
+
+        let messages_request_body =
+            messages_request_body.with_tools(vec![]);
+        assert_eq!(messages_request_body.tools, None);
+
+        let messages_request_body = messages_request_body.with_tools(
+            std::slice::from_ref(&tool)
+                .iter()
+                .cloned(),
+        );
+        assert_eq!(
+            messages_request_body.tools,
+            Some(vec![tool.clone()])
+        );
+
+        let messages_request_body =
+            messages_request_body.with_tools(std::iter::empty());
+        assert_eq!(messages_request_body.tools, None);
+
+        let messages_request_body =
+            MessagesRequestBuilder::new(ClaudeModel::Claude3Haiku20240307)
+                .tools([tool.clone()])
+                .build();
+        assert_eq!(
+            messages_request_body.tools,
+            Some(vec![tool])
+        );
+    }
+
+    #[test]
+    fn with_messages() {
+        let messages_request_body = MessagesRequestBody::default()
+            .with_messages(vec![Message::user("first")])
+            .append_messages(vec![
+                Message::assistant("second"),
+                Message::user("third"),
+            ]);
+        assert_eq!(
+            messages_request_body.messages,
+            vec![
+                Message::user("first"),
+                Message::assistant("second"),
+                Message::user("third"),
+            ]
+        );
+
+        let messages_request_body = messages_request_body
+            .with_messages(vec![Message::user("replaced")]);
+        assert_eq!(
+            messages_request_body.messages,
+            vec![Message::user("replaced")]
+        );
+    }
+
+    #[test]
+    fn with_system() {
+        let messages_request_body = MessagesRequestBody::default()
+            .with_system(SystemPrompt::new("system-prompt"));
+        assert_eq!(
+            messages_request_body.system,
+            Some(SystemPrompt::new("system-prompt"))
+        );
+
+        let messages_request_body = messages_request_body.without_system();
+        assert_eq!(messages_request_body.system, None);
+    }
+
+    #[test]
+    fn with_tool_choice() {
+        let messages_request_body = MessagesRequestBody::default()
+            .with_tool_choice(ToolChoice::Tool("tool".to_string()));
+        assert_eq!(
+            messages_request_body.tool_choice,
+            Some(ToolChoice::Tool("tool".to_string()))
+        );
+        assert_eq!(
+            serde_json::to_string(&messages_request_body).unwrap(),
+            "{\"model\":\"claude-3-5-sonnet-20241022\",\"messages\":[],\"max_tokens\":4096,\"tool_choice\":{\"type\":\"tool\",\"name\":\"tool\"}}"
+        );
+    }
+
+    #[test]
+    fn validate_tool_choice() {
+        let builder = MessagesRequestBuilder::new(
+            ClaudeModel::Claude35Sonnet20241022,
+        )
+        .tools(vec![ToolDefinition::new(
+            "tool",
+            None::<String>,
+            serde_json::json!({}),
+        )]);
+
+        assert_eq!(
+            builder
+                .clone()
+                .build()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            builder
+                .clone()
+                .tool_choice(ToolChoice::Any)
+                .build()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            builder
+                .clone()
+                .tool_choice(ToolChoice::Tool("tool".to_string()))
+                .build()
+                .validate(),
+            Ok(())
+        );
+        assert_eq!(
+            builder
+                .tool_choice(ToolChoice::Tool("unknown".to_string()))
+                .build()
+                .validate(),
+            Err(RequestValidationError::UnknownToolChoice(
+                "unknown".to_string()
+            ))
+        );
+        assert_eq!(
+            MessagesRequestBody::default()
+                .with_tool_choice(ToolChoice::Tool("tool".to_string()))
+                .validate(),
+            Err(RequestValidationError::UnknownToolChoice(
+                "tool".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn estimated_size_bytes() {
+        let messages_request_body = MessagesRequestBody::default();
+        let size = "{\"model\":\"claude-3-5-sonnet-20241022\",\"messages\":[],\"max_tokens\":4096}".len();
+        assert_eq!(
+            messages_request_body.estimated_size_bytes(),
+            size
+        );
+        assert!(!messages_request_body.exceeds_size_limit(size));
+        assert!(messages_request_body.exceeds_size_limit(size - 1));
+
+        let messages_request_body = MessagesRequestBody {
+            messages: vec![Message::user(ImageContentSource::base64(
+                ImageMediaType::Png,
+                "A".repeat(1024),
+            ))],
+            ..Default::default()
+        };
+        assert!(messages_request_body.estimated_size_bytes() > 1024);
+    }
+
     #[test]
     fn display() {
         let messages_request_body = MessagesRequestBody::default();
         assert_eq!(
             messages_request_body.to_string(),
-            "{\n  \"model\": \"claude-3-sonnet-20240229\",\n  \"messages\": [],\n  \"max_tokens\": 4096\n}"
+            "{\n  \"model\": \"claude-3-5-sonnet-20241022\",\n  \"messages\": [],\n  \"max_tokens\": 4096\n}"
         );
     }
 
@@ -323,13 +904,13 @@ mod tests {
         let messages_request_body = MessagesRequestBody::default();
         assert_eq!(
             serde_json::to_string(&messages_request_body).unwrap(),
-            "{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"max_tokens\":4096}"
+            "{\"model\":\"claude-3-5-sonnet-20241022\",\"messages\":[],\"max_tokens\":4096}"
         );
 
         let messages_request_body = MessagesRequestBody {
-            model: ClaudeModel::Claude3Sonnet20240229,
+            model: ClaudeModel::Claude35Sonnet20241022,
             messages: vec![],
-            max_tokens: MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+            max_tokens: MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022)
                 .unwrap(),
             system: Some(SystemPrompt::new("system-prompt")),
             metadata: Some(Metadata {
@@ -341,12 +922,13 @@ mod tests {
             stream: Some(StreamOption::ReturnOnce),
             temperature: Some(Temperature::new(0.5).unwrap()),
             tools: None,
+            tool_choice: None,
             top_p: Some(TopP::new(0.5).unwrap()),
             top_k: Some(TopK::new(50)),
         };
         assert_eq!(
             serde_json::to_string(&messages_request_body).unwrap(),
-            "{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50}"
+            "{\"model\":\"claude-3-5-sonnet-20241022\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50}"
         );
     }
 
@@ -354,14 +936,14 @@ mod tests {
     fn deserialize() {
         let messages_request_body = MessagesRequestBody::default();
         assert_eq!(
-            serde_json::from_str::<MessagesRequestBody>("{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"max_tokens\":4096}").unwrap(),
+            serde_json::from_str::<MessagesRequestBody>("{\"model\":\"claude-3-5-sonnet-20241022\",\"messages\":[],\"max_tokens\":4096}").unwrap(),
             messages_request_body
         );
 
         let messages_request_body = MessagesRequestBody {
-            model: ClaudeModel::Claude3Sonnet20240229,
+            model: ClaudeModel::Claude35Sonnet20241022,
             messages: vec![],
-            max_tokens: MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+            max_tokens: MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022)
                 .unwrap(),
             system: Some(SystemPrompt::new("system-prompt")),
             metadata: Some(Metadata {
@@ -373,11 +955,12 @@ mod tests {
             stream: Some(StreamOption::ReturnOnce),
             temperature: Some(Temperature::new(0.5).unwrap()),
             tools: None,
+            tool_choice: None,
             top_p: Some(TopP::new(0.5).unwrap()),
             top_k: Some(TopK::new(50)),
         };
         assert_eq!(
-            serde_json::from_str::<MessagesRequestBody>("{\"model\":\"claude-3-sonnet-20240229\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50}").unwrap(),
+            serde_json::from_str::<MessagesRequestBody>("{\"model\":\"claude-3-5-sonnet-20241022\",\"messages\":[],\"system\":\"system-prompt\",\"max_tokens\":16,\"metadata\":{\"user_id\":\"metadata\"},\"stop_sequences\":[\"stop-sequence\"],\"stream\":false,\"temperature\":0.5,\"top_p\":0.5,\"top_k\":50}").unwrap(),
             messages_request_body
         );
     }
@@ -385,11 +968,11 @@ mod tests {
     #[test]
     fn builder() {
         let messages_request_body =
-            MessagesRequestBuilder::new(ClaudeModel::Claude3Sonnet20240229)
+            MessagesRequestBuilder::new(ClaudeModel::Claude35Sonnet20241022)
                 .messages(vec![])
                 .system(SystemPrompt::new("system-prompt"))
                 .max_tokens(
-                    MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229)
+                    MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022)
                         .unwrap(),
                 )
                 .metadata(Metadata {
@@ -411,7 +994,7 @@ mod tests {
 
         assert_eq!(
             messages_request_body.model,
-            ClaudeModel::Claude3Sonnet20240229
+            ClaudeModel::Claude35Sonnet20241022
         );
         assert_eq!(messages_request_body.messages, vec![]);
         assert_eq!(
@@ -420,7 +1003,7 @@ mod tests {
         );
         assert_eq!(
             messages_request_body.max_tokens,
-            MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229).unwrap()
+            MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022).unwrap()
         );
         assert_eq!(
             messages_request_body.metadata,
@@ -464,7 +1047,7 @@ mod tests {
     fn builder_with_max_tokens() {
         let messages_request_body =
             MessagesRequestBuilder::new_with_max_tokens(
-                ClaudeModel::Claude3Sonnet20240229,
+                ClaudeModel::Claude35Sonnet20241022,
                 16,
             )
             .unwrap()
@@ -489,7 +1072,7 @@ mod tests {
 
         assert_eq!(
             messages_request_body.model,
-            ClaudeModel::Claude3Sonnet20240229
+            ClaudeModel::Claude35Sonnet20241022
         );
         assert_eq!(messages_request_body.messages, vec![]);
         assert_eq!(
@@ -498,7 +1081,7 @@ mod tests {
         );
         assert_eq!(
             messages_request_body.max_tokens,
-            MaxTokens::new(16, ClaudeModel::Claude3Sonnet20240229).unwrap()
+            MaxTokens::new(16, ClaudeModel::Claude35Sonnet20241022).unwrap()
         );
         assert_eq!(
             messages_request_body.metadata,
@@ -537,4 +1120,134 @@ mod tests {
             Some(TopK::new(50))
         );
     }
+
+    #[test]
+    fn from_body() {
+        let messages_request_body = MessagesRequestBody {
+            model: ClaudeModel::Claude3Haiku20240307,
+            messages: vec![Message::user("user-message")],
+            system: Some(SystemPrompt::new("system-prompt")),
+            ..Default::default()
+        };
+
+        let built = MessagesRequestBuilder::from_body(
+            messages_request_body.clone(),
+        )
+        .temperature(Temperature::new(0.5).unwrap())
+        .build();
+        assert_eq!(built.model, ClaudeModel::Claude3Haiku20240307);
+        assert_eq!(
+            built.messages,
+            vec![Message::user("user-message")]
+        );
+        assert_eq!(
+            built.system,
+            Some(SystemPrompt::new("system-prompt"))
+        );
+        assert_eq!(
+            built.temperature,
+            Some(Temperature::new(0.5).unwrap())
+        );
+
+        let built = MessagesRequestBuilder::from(
+            messages_request_body.clone(),
+        )
+        .build();
+        assert_eq!(built, messages_request_body);
+
+        let built = messages_request_body
+            .clone()
+            .into_builder()
+            .build();
+        assert_eq!(built, messages_request_body);
+    }
+
+    #[test]
+    fn apply_overrides() {
+        let model = ClaudeModel::Claude3Haiku20240307;
+        let mut messages_request_body = MessagesRequestBody {
+            model,
+            messages: vec![Message::user("user-message")],
+            system: Some(SystemPrompt::new("system-prompt")),
+            temperature: Some(Temperature::new(0.5).unwrap()),
+            ..Default::default()
+        };
+
+        messages_request_body.apply_overrides(
+            &MessagesRequestBodyOverrides::default(),
+        );
+        assert_eq!(
+            messages_request_body,
+            MessagesRequestBody {
+                model,
+                messages: vec![Message::user("user-message")],
+                system: Some(SystemPrompt::new("system-prompt")),
+                temperature: Some(Temperature::new(0.5).unwrap()),
+                ..Default::default()
+            }
+        );
+
+        messages_request_body.apply_overrides(&MessagesRequestBodyOverrides {
+            max_tokens: Some(MaxTokens::new(16, model).unwrap()),
+            system: Some(SystemPrompt::new("overridden-system-prompt")),
+            top_k: Some(TopK::new(10)),
+            ..Default::default()
+        });
+        assert_eq!(
+            messages_request_body,
+            MessagesRequestBody {
+                model,
+                messages: vec![Message::user("user-message")],
+                system: Some(SystemPrompt::new("overridden-system-prompt")),
+                max_tokens: MaxTokens::new(16, model).unwrap(),
+                temperature: Some(Temperature::new(0.5).unwrap()),
+                top_k: Some(TopK::new(10)),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn stop_sequences() {
+        let builder =
+            MessagesRequestBuilder::new(ClaudeModel::Claude3Haiku20240307)
+                .add_stop_sequence(StopSequence::new("first"))
+                .add_stop_sequence(StopSequence::new("second"))
+                .add_stop_sequence(StopSequence::new("first"));
+        assert_eq!(
+            builder
+                .clone()
+                .build()
+                .stop_sequences(),
+            &[
+                StopSequence::new("first"),
+                StopSequence::new("second"),
+                StopSequence::new("first"),
+            ]
+        );
+
+        let request_body = builder
+            .clone()
+            .remove_stop_sequence("first")
+            .build();
+        assert_eq!(
+            request_body.stop_sequences,
+            Some(vec![StopSequence::new("second")])
+        );
+
+        let request_body = builder
+            .clone()
+            .remove_stop_sequence("first")
+            .remove_stop_sequence("second")
+            .build();
+        assert_eq!(request_body.stop_sequences, None);
+        assert!(request_body
+            .stop_sequences()
+            .is_empty());
+
+        let request_body = builder
+            .clear_stop_sequences()
+            .build();
+        assert_eq!(request_body.stop_sequences, None);
+    }
 }
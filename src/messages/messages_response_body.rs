@@ -4,8 +4,8 @@ use crate::macros::{
     impl_display_for_serialize, impl_enum_string_serialization,
 };
 use crate::messages::{
-    ClaudeModel, Content, Message, Role, StopReason,
-    StopSequence, Usage,
+    ClaudeModel, Content, ContentBlock, ImageSourceData, Message, Role,
+    StopReason, StopSequence, ToolResultContent, Usage,
 };
 
 /// The response body for the Messages API.
@@ -75,6 +75,17 @@ impl Default for MessagesResponseBody {
 impl_display_for_serialize!(MessagesResponseBody);
 
 impl MessagesResponseBody {
+    /// Parses the response body from the raw JSON string.
+    ///
+    /// ## Arguments
+    /// - `json` - The JSON string.
+    ///
+    /// ## Errors
+    /// It returns an error if the JSON is invalid as `MessagesResponseBody`.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
     /// Creates `Message` from the response body.
     pub fn crate_message(self) -> Message {
         Message {
@@ -82,6 +93,184 @@ impl MessagesResponseBody {
             content: self.content,
         }
     }
+
+    /// The text of the response, which is the single text or the first text block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::MessagesResponseBody;
+    ///
+    /// let response = MessagesResponseBody {
+    ///     content: "Hello, world!".into(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(response.text(), Some("Hello, world!"));
+    /// assert_eq!(response.all_text(), "Hello, world!");
+    /// assert_eq!(response.text_or_default(), "Hello, world!");
+    /// ```
+    pub fn text(&self) -> Option<&str> {
+        match &self.content {
+            | Content::SingleText(text) => Some(text),
+            | Content::MultipleBlocks(blocks) => {
+                blocks
+                    .iter()
+                    .find_map(|block| match block {
+                        | ContentBlock::Text(text) => Some(text.text.as_str()),
+                        | _ => None,
+                    })
+            },
+        }
+    }
+
+    /// All text blocks of the response concatenated.
+    pub fn all_text(&self) -> String {
+        self.content
+            .concatenated_text()
+            .into_owned()
+    }
+
+    /// The text of the response or an empty string if there is no text.
+    pub fn text_or_default(&self) -> &str {
+        self.text().unwrap_or_default()
+    }
+
+    /// Formats the content as Markdown for rendering.
+    ///
+    /// This is a lossy conversion:
+    /// - Text blocks are paragraphs.
+    /// - Image blocks are inline images with data URLs or the image URLs.
+    /// - Tool use blocks are fenced code blocks with the JSON input.
+    /// - Tool result blocks are blockquotes.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ContentBlock, MessagesResponseBody};
+    ///
+    /// let response = MessagesResponseBody {
+    ///     content: Content::MultipleBlocks(vec![
+    ///         ContentBlock::from("Hello,"),
+    ///         ContentBlock::from("world!"),
+    ///     ]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(response.content_as_markdown(), "Hello,\n\nworld!");
+    /// ```
+    pub fn content_as_markdown(&self) -> String {
+        match &self.content {
+            | Content::SingleText(text) => text.clone(),
+            | Content::MultipleBlocks(blocks) => blocks_as_markdown(blocks),
+        }
+    }
+
+    /// Converts the response body into the parts for destructuring.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessagesResponseBody, ResponseParts};
+    ///
+    /// let response = MessagesResponseBody {
+    ///     content: "Hello, world!".into(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let ResponseParts {
+    ///     content,
+    ///     usage,
+    ///     ..
+    /// } = response.into_parts();
+    ///
+    /// assert_eq!(content, "Hello, world!".into());
+    /// assert_eq!(usage.output_tokens, 0);
+    /// ```
+    pub fn into_parts(self) -> ResponseParts {
+        ResponseParts {
+            id: self.id,
+            model: self.model,
+            role: self.role,
+            content: self.content,
+            stop_reason: self.stop_reason,
+            stop_sequence: self.stop_sequence,
+            usage: self.usage,
+        }
+    }
+}
+
+/// The parts of `MessagesResponseBody` created by `MessagesResponseBody::into_parts`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseParts {
+    /// Unique object identifier.
+    pub id: String,
+    /// The model that handled the request.
+    pub model: ClaudeModel,
+    /// Conversational role of the generated message.
+    pub role: Role,
+    /// Content generated by the model.
+    pub content: Content,
+    /// The reason that we stopped.
+    pub stop_reason: Option<StopReason>,
+    /// Which custom stop sequence was generated, if any.
+    pub stop_sequence: Option<StopSequence>,
+    /// Billing and rate-limit usage.
+    pub usage: Usage,
+}
+
+/// Formats the content blocks as Markdown paragraphs.
+fn blocks_as_markdown(blocks: &[ContentBlock]) -> String {
+    blocks
+        .iter()
+        .map(block_as_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Formats the content block as Markdown.
+fn block_as_markdown(block: &ContentBlock) -> String {
+    match block {
+        | ContentBlock::Text(text) => text.text.clone(),
+        | ContentBlock::Image(image) => match &image.source.source_data {
+            | ImageSourceData::Base64 {
+                media_type,
+                data,
+            } => format!("![](data:{};base64,{})", media_type, data),
+            | ImageSourceData::Url {
+                url,
+            } => format!("![]({})", url),
+        },
+        | ContentBlock::Document(document) => format!(
+            "[document](data:{};base64,{})",
+            document.source.media_type, document.source.data
+        ),
+        | ContentBlock::ToolUse(tool_use) => format!(
+            "```json\n{}\n```",
+            serde_json::to_string_pretty(&tool_use.tool_use.input)
+                .unwrap_or_default()
+        ),
+        | ContentBlock::ToolResult(tool_result) => {
+            let content = match &tool_result.tool_result.content {
+                | Some(ToolResultContent::SingleText(text)) => {
+                    text.text.clone()
+                },
+                | Some(ToolResultContent::MultipleBlocks(blocks)) => {
+                    blocks_as_markdown(blocks)
+                },
+                | None => String::new(),
+            };
+
+            content
+                .split('\n')
+                .map(|line| {
+                    if line.is_empty() {
+                        ">".to_string()
+                    } else {
+                        format!("> {}", line)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        },
+    }
 }
 
 /// The object type for message.
@@ -126,17 +315,18 @@ mod tests {
             _type: MessageObjectType::Message,
             role: Role::Assistant,
             content: "content".into(),
-            model: ClaudeModel::Claude3Sonnet20240229,
+            model: ClaudeModel::Claude35Sonnet20241022,
             stop_reason: Some(StopReason::EndTurn),
             stop_sequence: Some(StopSequence::new("stop_sequence")),
             usage: Usage {
                 input_tokens: 1,
                 output_tokens: 2,
+                ..Default::default()
             },
         };
         assert_eq!(
             serde_json::to_string(&response).unwrap(),
-            "{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":\"content\",\"model\":\"claude-3-sonnet-20240229\",\"stop_reason\":\"end_turn\",\"stop_sequence\":\"stop_sequence\",\"usage\":{\"input_tokens\":1,\"output_tokens\":2}}"
+            "{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":\"content\",\"model\":\"claude-3-5-sonnet-20241022\",\"stop_reason\":\"end_turn\",\"stop_sequence\":\"stop_sequence\",\"usage\":{\"input_tokens\":1,\"output_tokens\":2}}"
         );
     }
 
@@ -147,17 +337,24 @@ mod tests {
             _type: MessageObjectType::Message,
             role: Role::Assistant,
             content: "content".into(),
-            model: ClaudeModel::Claude3Sonnet20240229,
+            model: ClaudeModel::Claude35Sonnet20241022,
             stop_reason: Some(StopReason::EndTurn),
             stop_sequence: Some(StopSequence::new("stop_sequence")),
             usage: Usage {
                 input_tokens: 1,
                 output_tokens: 2,
+                ..Default::default()
             },
         };
         assert_eq!(
             serde_json::from_str::<MessagesResponseBody>(
-            "{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":\"content\",\"model\":\"claude-3-sonnet-20240229\",\"stop_reason\":\"end_turn\",\"stop_sequence\":\"stop_sequence\",\"usage\":{\"input_tokens\":1,\"output_tokens\":2}}"
+            "{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":\"content\",\"model\":\"claude-3-5-sonnet-20241022\",\"stop_reason\":\"end_turn\",\"stop_sequence\":\"stop_sequence\",\"usage\":{\"input_tokens\":1,\"output_tokens\":2}}"
+            ).unwrap(),
+            response
+        );
+        assert_eq!(
+            MessagesResponseBody::from_json(
+            "{\"id\":\"id\",\"type\":\"message\",\"role\":\"assistant\",\"content\":\"content\",\"model\":\"claude-3-5-sonnet-20241022\",\"stop_reason\":\"end_turn\",\"stop_sequence\":\"stop_sequence\",\"usage\":{\"input_tokens\":1,\"output_tokens\":2}}"
             ).unwrap(),
             response
         );
@@ -170,17 +367,18 @@ mod tests {
             _type: MessageObjectType::Message,
             role: Role::Assistant,
             content: "content".into(),
-            model: ClaudeModel::Claude3Sonnet20240229,
+            model: ClaudeModel::Claude35Sonnet20241022,
             stop_reason: Some(StopReason::EndTurn),
             stop_sequence: Some(StopSequence::new("stop_sequence")),
             usage: Usage {
                 input_tokens: 1,
                 output_tokens: 2,
+                ..Default::default()
             },
         };
         assert_eq!(
             response.to_string(),
-            "{\n  \"id\": \"id\",\n  \"type\": \"message\",\n  \"role\": \"assistant\",\n  \"content\": \"content\",\n  \"model\": \"claude-3-sonnet-20240229\",\n  \"stop_reason\": \"end_turn\",\n  \"stop_sequence\": \"stop_sequence\",\n  \"usage\": {\n    \"input_tokens\": 1,\n    \"output_tokens\": 2\n  }\n}"
+            "{\n  \"id\": \"id\",\n  \"type\": \"message\",\n  \"role\": \"assistant\",\n  \"content\": \"content\",\n  \"model\": \"claude-3-5-sonnet-20241022\",\n  \"stop_reason\": \"end_turn\",\n  \"stop_sequence\": \"stop_sequence\",\n  \"usage\": {\n    \"input_tokens\": 1,\n    \"output_tokens\": 2\n  }\n}"
         );
     }
 
@@ -228,4 +426,108 @@ mod tests {
             Message::assistant("content")
         );
     }
+
+    #[test]
+    fn content_as_markdown() {
+        let response = MessagesResponseBody {
+            content: "text".into(),
+            ..Default::default()
+        };
+        assert_eq!(response.content_as_markdown(), "text");
+
+        let response = MessagesResponseBody {
+            content: Content::MultipleBlocks(vec![
+                ContentBlock::from("text"),
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Png,
+                    "data",
+                )),
+                ContentBlock::from(ToolUse::new(
+                    "id",
+                    "name",
+                    serde_json::json!({"arg1": 42}),
+                )),
+                ContentBlock::from(ToolResult::success(
+                    "id",
+                    Some("first line\nsecond line"),
+                )),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(
+            response.content_as_markdown(),
+            "text\n\n![](data:image/png;base64,data)\n\n```json\n{\n  \"arg1\": 42\n}\n```\n\n> first line\n> second line"
+        );
+
+        let response = MessagesResponseBody {
+            content: Content::MultipleBlocks(vec![ContentBlock::from(
+                ImageContentSource::url("https://example.com/image.png"),
+            )]),
+            ..Default::default()
+        };
+        assert_eq!(
+            response.content_as_markdown(),
+            "![](https://example.com/image.png)"
+        );
+    }
+
+    #[test]
+    fn text() {
+        let response = MessagesResponseBody {
+            content: Content::MultipleBlocks(vec![
+                ContentBlock::from(ToolUse::new(
+                    "id",
+                    "name",
+                    serde_json::Value::Null,
+                )),
+                ContentBlock::from("first"),
+                ContentBlock::from("second"),
+            ]),
+            ..Default::default()
+        };
+        assert_eq!(response.text(), Some("first"));
+        assert_eq!(response.all_text(), "firstsecond");
+        assert_eq!(response.text_or_default(), "first");
+
+        let response = MessagesResponseBody {
+            content: Content::MultipleBlocks(vec![]),
+            ..Default::default()
+        };
+        assert_eq!(response.text(), None);
+        assert_eq!(response.all_text(), "");
+        assert_eq!(response.text_or_default(), "");
+    }
+
+    #[test]
+    fn into_parts() {
+        let response = MessagesResponseBody {
+            id: "id".to_string(),
+            content: "text".into(),
+            stop_reason: Some(StopReason::StopSequence),
+            stop_sequence: Some(StopSequence::new("stop")),
+            usage: Usage {
+                input_tokens: 1,
+                output_tokens: 2,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            response.clone().into_parts(),
+            ResponseParts {
+                id: "id".to_string(),
+                model: response.model,
+                role: Role::Assistant,
+                content: "text".into(),
+                stop_reason: Some(StopReason::StopSequence),
+                stop_sequence: Some(StopSequence::new("stop")),
+                usage: Usage {
+                    input_tokens: 1,
+                    output_tokens: 2,
+                    ..Default::default()
+                },
+            }
+        );
+    }
 }
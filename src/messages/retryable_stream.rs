@@ -0,0 +1,388 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_core::Stream;
+
+use crate::messages::{
+    DeltaContentBlock, MessageChunk, MessagesError, MessagesRequestBody,
+    StreamError,
+};
+use crate::{Client, ClientError};
+
+/// The configuration of reconnecting a message stream on transient network errors.
+///
+/// ## Example
+/// ```rust
+/// use std::time::Duration;
+///
+/// use clust::messages::StreamRetryConfig;
+/// use clust::{ApiKey, ClientBuilder};
+///
+/// let client = ClientBuilder::new(ApiKey::new("api-key"))
+///     .with_stream_retry(StreamRetryConfig {
+///         max_retries: 5,
+///         retry_delay: Duration::from_millis(500),
+///     })
+///     .build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamRetryConfig {
+    /// The maximum number of reconnections in a stream.
+    pub max_retries: u32,
+    /// The delay before each reconnection.
+    pub retry_delay: Duration,
+}
+
+impl Default for StreamRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+type BoxedChunkStream =
+    Pin<Box<dyn Stream<Item = Result<MessageChunk, StreamError>> + Send>>;
+
+type BoxedReconnection = Pin<
+    Box<dyn Future<Output = Result<BoxedChunkStream, MessagesError>> + Send>,
+>;
+
+/// The stream of message chunks reconnecting on transient network errors created by `Client::create_a_message_stream_with_retry`.
+///
+/// On a network error of the stream, the same request is sent again after `StreamRetryConfig::retry_delay`
+/// and the chunks already returned are skipped by the character offset of the deltas for each content block index.
+/// API errors and parse errors are returned as is.
+///
+/// ## NOTE
+/// The skipped content is assumed to be the same as the previous response,
+/// e.g. with `Temperature` of `0.0`, because the response is regenerated by the reconnection.
+pub struct RetryableStream {
+    client: Client,
+    request_body: MessagesRequestBody,
+    config: StreamRetryConfig,
+    retries: u32,
+    state: State,
+    deduplicator: ChunkDeduplicator,
+}
+
+/// The state of `RetryableStream`.
+enum State {
+    /// Receiving chunks from the stream.
+    Streaming(BoxedChunkStream),
+    /// Waiting for the delay and the new stream.
+    Reconnecting(BoxedReconnection),
+    /// The stream has ended.
+    Done,
+}
+
+impl RetryableStream {
+    /// Creates a new retryable stream from the first stream.
+    pub(crate) fn new(
+        client: Client,
+        request_body: MessagesRequestBody,
+        config: StreamRetryConfig,
+        stream: impl Stream<Item = Result<MessageChunk, StreamError>>
+            + Send
+            + 'static,
+    ) -> Self {
+        Self {
+            client,
+            request_body,
+            config,
+            retries: 0,
+            state: State::Streaming(Box::pin(stream)),
+            deduplicator: ChunkDeduplicator::default(),
+        }
+    }
+
+    /// The number of reconnections so far.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Starts a reconnection if the retries remain.
+    fn reconnect(&mut self) -> bool {
+        if self.retries >= self.config.max_retries {
+            return false;
+        }
+
+        self.retries += 1;
+        self.deduplicator.reconnect();
+
+        #[cfg(feature = "tracing")]
+        tracing::warn!(
+            retries = self.retries,
+            "Reconnecting the message stream"
+        );
+
+        let client = self.client.clone();
+        let request_body = self.request_body.clone();
+        let retry_delay = self.config.retry_delay;
+        self.state = State::Reconnecting(Box::pin(async move {
+            tokio::time::sleep(retry_delay).await;
+            let stream = crate::messages::api::create_a_message_stream(
+                &client,
+                request_body,
+            )
+            .await?;
+            Ok(Box::pin(stream) as BoxedChunkStream)
+        }));
+
+        true
+    }
+}
+
+impl Stream for RetryableStream {
+    type Item = Result<MessageChunk, StreamError>;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            match &mut this.state {
+                | State::Streaming(stream) => {
+                    match stream.as_mut().poll_next(cx) {
+                        | Poll::Ready(Some(Ok(chunk))) => {
+                            if let Some(chunk) =
+                                this.deduplicator.filter(chunk)
+                            {
+                                return Poll::Ready(Some(Ok(chunk)));
+                            }
+                        },
+                        | Poll::Ready(Some(Err(
+                            StreamError::ReqwestError(error),
+                        ))) if is_transient(&error) => {
+                            if !this.reconnect() {
+                                this.state = State::Done;
+                                return Poll::Ready(Some(Err(
+                                    StreamError::ReqwestError(error),
+                                )));
+                            }
+                        },
+                        | Poll::Ready(Some(Err(error))) => {
+                            return Poll::Ready(Some(Err(error)));
+                        },
+                        | Poll::Ready(None) => {
+                            this.state = State::Done;
+                            return Poll::Ready(None);
+                        },
+                        | Poll::Pending => return Poll::Pending,
+                    }
+                },
+                | State::Reconnecting(reconnection) => {
+                    match reconnection.as_mut().poll(cx) {
+                        | Poll::Ready(Ok(stream)) => {
+                            this.state = State::Streaming(stream);
+                        },
+                        | Poll::Ready(Err(MessagesError::ClientError(
+                            ClientError::HttpRequestError(error),
+                        ))) if is_transient(&error) => {
+                            if !this.reconnect() {
+                                this.state = State::Done;
+                                return Poll::Ready(Some(Err(
+                                    StreamError::ReqwestError(error),
+                                )));
+                            }
+                        },
+                        | Poll::Ready(Err(error)) => {
+                            this.state = State::Done;
+                            return Poll::Ready(Some(Err(
+                                StreamError::ReconnectionError(Box::new(
+                                    error,
+                                )),
+                            )));
+                        },
+                        | Poll::Pending => return Poll::Pending,
+                    }
+                },
+                | State::Done => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Checks if the error is a transient network error.
+fn is_transient(error: &reqwest::Error) -> bool {
+    error.is_timeout()
+        || error.is_connect()
+        || error.is_request()
+        || error.is_body()
+}
+
+/// The filter of chunks already returned before a reconnection.
+#[derive(Debug, Clone, Default)]
+struct ChunkDeduplicator {
+    /// Whether the message start chunk has been returned.
+    message_started: bool,
+    /// The indices of the returned content block start chunks.
+    started_blocks: BTreeSet<u32>,
+    /// The indices of the returned content block stop chunks.
+    stopped_blocks: BTreeSet<u32>,
+    /// The number of returned delta characters for each index.
+    returned_chars: BTreeMap<u32, usize>,
+    /// The number of received delta characters for each index in the current stream.
+    received_chars: BTreeMap<u32, usize>,
+}
+
+impl ChunkDeduplicator {
+    /// Resets the offsets of the current stream for a new stream.
+    fn reconnect(&mut self) {
+        self.received_chars.clear();
+    }
+
+    /// Returns the chunk if it has not been returned yet.
+    ///
+    /// A delta crossing the returned offset is trimmed to the new part.
+    fn filter(
+        &mut self,
+        chunk: MessageChunk,
+    ) -> Option<MessageChunk> {
+        match chunk {
+            | MessageChunk::MessageStart(_) => {
+                if self.message_started {
+                    return None;
+                }
+                self.message_started = true;
+                Some(chunk)
+            },
+            | MessageChunk::ContentBlockStart(content_block_start) => {
+                self.started_blocks
+                    .insert(content_block_start.index)
+                    .then_some(MessageChunk::ContentBlockStart(
+                        content_block_start,
+                    ))
+            },
+            | MessageChunk::ContentBlockStop(content_block_stop) => {
+                self.stopped_blocks
+                    .insert(content_block_stop.index)
+                    .then_some(MessageChunk::ContentBlockStop(
+                        content_block_stop,
+                    ))
+            },
+            | MessageChunk::ContentBlockDelta(mut content_block_delta) => {
+                let index = content_block_delta.index;
+                let delta = match &mut content_block_delta.delta {
+                    | DeltaContentBlock::TextDelta(text_delta) => {
+                        &mut text_delta.text
+                    },
+                    | DeltaContentBlock::InputJsonDelta(input_json_delta) => {
+                        &mut input_json_delta.partial_json
+                    },
+                };
+                let length = delta.chars().count();
+                let received = self
+                    .received_chars
+                    .entry(index)
+                    .or_default();
+                let returned = self
+                    .returned_chars
+                    .entry(index)
+                    .or_default();
+
+                let start = *received;
+                *received += length;
+                if *received <= *returned {
+                    return None;
+                }
+
+                let skip = returned.saturating_sub(start);
+                *returned = *received;
+                if skip > 0 {
+                    *delta = delta
+                        .chars()
+                        .skip(skip)
+                        .collect();
+                }
+
+                Some(MessageChunk::ContentBlockDelta(
+                    content_block_delta,
+                ))
+            },
+            | chunk => Some(chunk),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        ContentBlockDeltaChunk, ContentBlockStartChunk, ContentBlockStopChunk,
+        MessageStartChunk, PingChunk,
+    };
+
+    fn delta(
+        index: u32,
+        text: &str,
+    ) -> MessageChunk {
+        MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+            index,
+            text.into(),
+        ))
+    }
+
+    #[test]
+    fn default_stream_retry_config() {
+        assert_eq!(
+            StreamRetryConfig::default(),
+            StreamRetryConfig {
+                max_retries: 3,
+                retry_delay: Duration::from_secs(1),
+            }
+        );
+    }
+
+    #[test]
+    fn deduplicate_chunks() {
+        let mut deduplicator = ChunkDeduplicator::default();
+        let first = vec![
+            MessageChunk::MessageStart(MessageStartChunk::default()),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                "".into(),
+            )),
+            delta(0, "Hello"),
+            delta(0, ", wo"),
+        ];
+        for chunk in first.clone() {
+            assert_eq!(deduplicator.filter(chunk.clone()), Some(chunk));
+        }
+
+        deduplicator.reconnect();
+        let second = vec![
+            MessageChunk::MessageStart(MessageStartChunk::default()),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                "".into(),
+            )),
+            MessageChunk::Ping(PingChunk::new()),
+            delta(0, "Hel"),
+            delta(0, "lo, wor"),
+            delta(0, "ld!"),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+        ];
+        let filtered = second
+            .into_iter()
+            .filter_map(|chunk| deduplicator.filter(chunk))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            filtered,
+            vec![
+                MessageChunk::Ping(PingChunk::new()),
+                delta(0, "r"),
+                delta(0, "ld!"),
+                MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(
+                    0
+                )),
+            ]
+        );
+    }
+}
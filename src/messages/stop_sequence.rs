@@ -26,6 +26,11 @@ impl StopSequence {
             value: value.into(),
         }
     }
+
+    /// The value of the stop sequence.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
 }
 
 #[cfg(test)]
@@ -36,6 +41,7 @@ mod tests {
     fn new() {
         let stop_sequence = StopSequence::new("stop-sequence");
         assert_eq!(stop_sequence.value, "stop-sequence");
+        assert_eq!(stop_sequence.value(), "stop-sequence");
     }
 
     #[test]
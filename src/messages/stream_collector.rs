@@ -0,0 +1,593 @@
+use futures_core::Stream;
+
+use crate::messages::input_json_accumulator::parse_tool_input;
+use crate::messages::{
+    ContentBlock, DeltaContentBlock, MessageChunk, MessagesResponseBody,
+    StreamError, ToolUse,
+};
+
+/// The accumulator of a content block in streaming.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContentBlockAccumulator {
+    /// The accumulated text of a text content block.
+    Text(String),
+    /// The accumulated tool use of a tool use content block.
+    ToolUse {
+        /// The ID of the tool use.
+        id: String,
+        /// The name of the tool.
+        name: String,
+        /// The accumulated partial JSON of the tool input.
+        input_buffer: String,
+    },
+}
+
+impl ContentBlockAccumulator {
+    /// Opens an accumulator with the content block of the start chunk.
+    ///
+    /// ## Arguments
+    /// - `content_block` - The content block of the content block start chunk.
+    ///
+    /// ## Errors
+    /// It returns `StreamError::UnsupportedContentBlock` if the content block is neither a text nor a tool use.
+    pub fn open(content_block: ContentBlock) -> Result<Self, StreamError> {
+        match content_block {
+            | ContentBlock::Text(text) => Ok(Self::Text(text.text)),
+            | ContentBlock::ToolUse(tool_use) => Ok(Self::ToolUse {
+                id: tool_use.tool_use.id,
+                name: tool_use.tool_use.name,
+                input_buffer: String::new(),
+            }),
+            | ContentBlock::Image(image) => {
+                Err(StreamError::UnsupportedContentBlock(image._type))
+            },
+            | ContentBlock::ToolResult(tool_result) => Err(
+                StreamError::UnsupportedContentBlock(tool_result._type),
+            ),
+            | ContentBlock::Document(document) => {
+                Err(StreamError::UnsupportedContentBlock(document._type))
+            },
+        }
+    }
+
+    /// Appends a delta.
+    ///
+    /// A text delta is appended to a text and a partial JSON to a tool use, otherwise the delta is ignored.
+    ///
+    /// ## Arguments
+    /// - `delta` - The delta of the content block delta chunk.
+    pub fn push_delta(
+        &mut self,
+        delta: &DeltaContentBlock,
+    ) {
+        match (self, delta) {
+            | (Self::Text(text), DeltaContentBlock::TextDelta(text_delta)) => {
+                text.push_str(&text_delta.text);
+            },
+            | (
+                Self::ToolUse {
+                    input_buffer,
+                    ..
+                },
+                DeltaContentBlock::InputJsonDelta(input_json_delta),
+            ) => {
+                input_buffer.push_str(&input_json_delta.partial_json);
+            },
+            | _ => {},
+        }
+    }
+
+    /// Converts into the content block.
+    ///
+    /// An empty input of a tool use is treated as an empty object because a tool without arguments has no partial JSON.
+    ///
+    /// ## Errors
+    /// It returns an error if the accumulated JSON of a tool input is invalid.
+    pub fn into_content_block(self) -> Result<ContentBlock, serde_json::Error> {
+        match self {
+            | Self::Text(text) => Ok(ContentBlock::from(text)),
+            | Self::ToolUse {
+                id,
+                name,
+                input_buffer,
+            } => Ok(ContentBlock::from(ToolUse::new(
+                id,
+                name,
+                parse_tool_input(&input_buffer)?,
+            ))),
+        }
+    }
+}
+
+/// The collector of message chunks into a complete response body.
+///
+/// The response body of the message start chunk is completed with the content blocks of the content block chunks,
+/// the stop reason and sequence of the message delta chunk and the output tokens of the message delta chunk.
+///
+/// Each content block start chunk opens a `ContentBlockAccumulator`, the delta chunks are appended to it
+/// and the stop chunk seals it.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{
+///     ContentBlockDeltaChunk, ContentBlockStartChunk, ContentBlockStopChunk, DeltaUsage,
+///     MessageChunk, MessageDeltaChunk, MessageStartChunk, MessagesResponseBody, StopReason,
+///     StreamCollector, StreamStop,
+/// };
+///
+/// let mut collector = StreamCollector::new();
+/// collector
+///     .feed(MessageChunk::MessageStart(MessageStartChunk::new(
+///         MessagesResponseBody {
+///             content: vec![].into(),
+///             ..Default::default()
+///         },
+///     )))
+///     .unwrap();
+/// collector
+///     .feed(MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+///         0,
+///         "".into(),
+///     )))
+///     .unwrap();
+/// collector
+///     .feed(MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+///         0,
+///         "Hello!".into(),
+///     )))
+///     .unwrap();
+/// collector
+///     .feed(MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(0)))
+///     .unwrap();
+/// collector
+///     .feed(MessageChunk::MessageDelta(MessageDeltaChunk::new(
+///         StreamStop {
+///             stop_reason: Some(StopReason::EndTurn),
+///             stop_sequence: None,
+///         },
+///         DeltaUsage {
+///             output_tokens: 2,
+///         },
+///     )))
+///     .unwrap();
+///
+/// let response = collector.finish().unwrap();
+/// assert_eq!(response.all_text(), "Hello!");
+/// assert_eq!(response.stop_reason, Some(StopReason::EndTurn));
+/// assert_eq!(response.usage.output_tokens, 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct StreamCollector {
+    /// The response body of the message start chunk.
+    response: Option<MessagesResponseBody>,
+    /// The accumulators of the content blocks in the order of the start chunks.
+    blocks: Vec<IndexedAccumulator>,
+}
+
+/// The accumulator with the content block index.
+#[derive(Debug, Clone, PartialEq)]
+struct IndexedAccumulator {
+    index: u32,
+    accumulator: ContentBlockAccumulator,
+    sealed: bool,
+}
+
+impl StreamCollector {
+    /// Creates a new empty collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a chunk of the stream.
+    ///
+    /// ## Arguments
+    /// - `chunk` - The chunk of the stream.
+    ///
+    /// ## Errors
+    /// - `StreamError::MessageStartMissing` if a chunk other than the ping arrives before the message start chunk.
+    /// - `StreamError::ContentBlockNotOpen` if a delta or stop chunk arrives for a content block which is not open.
+    /// - `StreamError::UnsupportedContentBlock` if a start chunk has neither a text nor a tool use.
+    /// - `StreamError::ApiError` if the chunk is an error event.
+    pub fn feed(
+        &mut self,
+        chunk: MessageChunk,
+    ) -> Result<(), StreamError> {
+        match chunk {
+            | MessageChunk::MessageStart(message_start) => {
+                self.response = Some(message_start.message);
+            },
+            | MessageChunk::Ping(_) => {},
+            | MessageChunk::ContentBlockStart(content_block_start) => {
+                self.response_mut()?;
+                self.blocks
+                    .push(IndexedAccumulator {
+                        index: content_block_start.index,
+                        accumulator: ContentBlockAccumulator::open(
+                            content_block_start.content_block,
+                        )?,
+                        sealed: false,
+                    });
+            },
+            | MessageChunk::ContentBlockDelta(content_block_delta) => {
+                self.response_mut()?;
+                self.open_block_mut(content_block_delta.index)?
+                    .accumulator
+                    .push_delta(&content_block_delta.delta);
+            },
+            | MessageChunk::ContentBlockStop(content_block_stop) => {
+                self.response_mut()?;
+                self.open_block_mut(content_block_stop.index)?
+                    .sealed = true;
+            },
+            | MessageChunk::MessageDelta(message_delta) => {
+                let response = self.response_mut()?;
+                response.stop_reason = message_delta.delta.stop_reason;
+                response.stop_sequence = message_delta.delta.stop_sequence;
+                // The output tokens of the message delta are cumulative.
+                response.usage.output_tokens = message_delta
+                    .usage
+                    .output_tokens;
+            },
+            | MessageChunk::MessageStop(_) => {
+                self.response_mut()?;
+            },
+            | MessageChunk::Error(error) => {
+                return Err(StreamError::ApiError(error));
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Finishes the collection and returns the complete response body.
+    ///
+    /// The content blocks are appended to the content of the message start chunk in the order of the start chunks.
+    ///
+    /// ## Errors
+    /// - `StreamError::MessageStartMissing` if the message start chunk has not been fed.
+    /// - `StreamError::UnsealedContentBlock` if a content block has not been sealed by the stop chunk.
+    /// - `StreamError::ChunkDataDeserializationError` if the accumulated JSON of a tool input is invalid.
+    pub fn finish(self) -> Result<MessagesResponseBody, StreamError> {
+        let mut response = self
+            .response
+            .ok_or(StreamError::MessageStartMissing)?;
+
+        for block in self.blocks {
+            if !block.sealed {
+                return Err(StreamError::UnsealedContentBlock(
+                    block.index,
+                ));
+            }
+
+            response
+                .content
+                .blocks_mut()
+                .push(
+                    block
+                        .accumulator
+                        .into_content_block()?,
+                );
+        }
+
+        Ok(response)
+    }
+
+    /// The response body of the message start chunk.
+    fn response_mut(
+        &mut self
+    ) -> Result<&mut MessagesResponseBody, StreamError> {
+        self.response
+            .as_mut()
+            .ok_or(StreamError::MessageStartMissing)
+    }
+
+    /// The open accumulator of the content block index.
+    fn open_block_mut(
+        &mut self,
+        index: u32,
+    ) -> Result<&mut IndexedAccumulator, StreamError> {
+        self.blocks
+            .iter_mut()
+            .find(|block| block.index == index && !block.sealed)
+            .ok_or(StreamError::ContentBlockNotOpen(index))
+    }
+}
+
+/// Collects the stream of message chunks into a complete response body with `StreamCollector`.
+///
+/// ## Arguments
+/// - `stream` - The stream of message chunks.
+///
+/// ## Errors
+/// It returns the first error of the stream or the error of `StreamCollector`.
+pub async fn collect_stream<S>(
+    stream: S
+) -> Result<MessagesResponseBody, StreamError>
+where
+    S: Stream<Item = Result<MessageChunk, StreamError>>,
+{
+    let mut stream = std::pin::pin!(stream);
+    let mut collector = StreamCollector::new();
+
+    while let Some(chunk) =
+        std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await
+    {
+        collector.feed(chunk?)?;
+    }
+
+    collector.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{
+        ContentBlockDeltaChunk, ContentBlockStartChunk, ContentBlockStopChunk,
+        ContentType, DeltaUsage, ImageContentSource,
+        InputJsonDeltaContentBlock, MessageDeltaChunk, MessageStartChunk,
+        MessageStopChunk, PingChunk, StopReason, StreamStop,
+        ToolUseContentBlock, Usage,
+    };
+
+    fn chunks() -> Vec<MessageChunk> {
+        vec![
+            MessageChunk::MessageStart(MessageStartChunk::new(
+                MessagesResponseBody {
+                    id: "id".to_string(),
+                    content: vec![].into(),
+                    usage: Usage {
+                        input_tokens: 25,
+                        output_tokens: 1,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                "".into(),
+            )),
+            MessageChunk::Ping(PingChunk::new()),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                "Hello".into(),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                "!".into(),
+            )),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                1,
+                "".into(),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                "Bye.".into(),
+            )),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(1)),
+            MessageChunk::MessageDelta(MessageDeltaChunk::new(
+                StreamStop {
+                    stop_reason: Some(StopReason::EndTurn),
+                    stop_sequence: None,
+                },
+                DeltaUsage {
+                    output_tokens: 15,
+                },
+            )),
+            MessageChunk::MessageStop(MessageStopChunk::new()),
+        ]
+    }
+
+    fn expected() -> MessagesResponseBody {
+        MessagesResponseBody {
+            id: "id".to_string(),
+            content: vec![
+                ContentBlock::from("Hello!"),
+                ContentBlock::from("Bye."),
+            ]
+            .into(),
+            stop_reason: Some(StopReason::EndTurn),
+            usage: Usage {
+                input_tokens: 25,
+                output_tokens: 15,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn feed_and_finish() {
+        let mut collector = StreamCollector::new();
+        for chunk in chunks() {
+            collector.feed(chunk).unwrap();
+        }
+
+        assert_eq!(collector.finish().unwrap(), expected());
+    }
+
+    #[test]
+    fn text_and_tool_use() {
+        let mut collector = StreamCollector::new();
+        let chunks = vec![
+            MessageChunk::MessageStart(MessageStartChunk::new(
+                MessagesResponseBody {
+                    content: vec![].into(),
+                    ..Default::default()
+                },
+            )),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                "".into(),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                0,
+                "Let me check.".into(),
+            )),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                1,
+                ContentBlock::ToolUse(ToolUseContentBlock::new(ToolUse::new(
+                    "toolu_01",
+                    "get_weather",
+                    serde_json::json!({}),
+                ))),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                InputJsonDeltaContentBlock::new("{\"location\": ").into(),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                InputJsonDeltaContentBlock::new("\"Tokyo\"}").into(),
+            )),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(1)),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                2,
+                "".into(),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                2,
+                "Done.".into(),
+            )),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(2)),
+        ];
+        for chunk in chunks {
+            collector.feed(chunk).unwrap();
+        }
+
+        assert_eq!(
+            collector
+                .finish()
+                .unwrap()
+                .content
+                .blocks(),
+            vec![
+                ContentBlock::from("Let me check."),
+                ContentBlock::from(ToolUse::new(
+                    "toolu_01",
+                    "get_weather",
+                    serde_json::json!({"location": "Tokyo"}),
+                )),
+                ContentBlock::from("Done."),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsealed_content_block() {
+        let mut collector = StreamCollector::new();
+        let chunks = vec![
+            MessageChunk::MessageStart(MessageStartChunk::default()),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                0,
+                "".into(),
+            )),
+            MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+            MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+                1,
+                "".into(),
+            )),
+            MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+                1,
+                "text".into(),
+            )),
+        ];
+        for chunk in chunks {
+            collector.feed(chunk).unwrap();
+        }
+
+        assert!(matches!(
+            collector.finish(),
+            Err(StreamError::UnsealedContentBlock(1))
+        ));
+    }
+
+    #[test]
+    fn content_block_not_open() {
+        let mut collector = StreamCollector::new();
+        collector
+            .feed(MessageChunk::MessageStart(
+                MessageStartChunk::default(),
+            ))
+            .unwrap();
+        assert!(matches!(
+            collector.feed(MessageChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(0, "text".into()),
+            )),
+            Err(StreamError::ContentBlockNotOpen(0))
+        ));
+
+        collector
+            .feed(MessageChunk::ContentBlockStart(
+                ContentBlockStartChunk::new(0, "".into()),
+            ))
+            .unwrap();
+        collector
+            .feed(MessageChunk::ContentBlockStop(
+                ContentBlockStopChunk::new(0),
+            ))
+            .unwrap();
+        assert!(matches!(
+            collector.feed(MessageChunk::ContentBlockStop(
+                ContentBlockStopChunk::new(0),
+            )),
+            Err(StreamError::ContentBlockNotOpen(0))
+        ));
+
+        assert!(matches!(
+            collector.feed(MessageChunk::ContentBlockStart(
+                ContentBlockStartChunk::new(
+                    1,
+                    ContentBlock::from(ImageContentSource::default()),
+                ),
+            )),
+            Err(StreamError::UnsupportedContentBlock(ContentType::Image))
+        ));
+    }
+
+    #[test]
+    fn message_start_missing() {
+        let mut collector = StreamCollector::new();
+        collector
+            .feed(MessageChunk::Ping(PingChunk::new()))
+            .unwrap();
+        assert!(matches!(
+            collector.feed(MessageChunk::ContentBlockDelta(
+                ContentBlockDeltaChunk::new(0, "text".into()),
+            )),
+            Err(StreamError::MessageStartMissing)
+        ));
+        assert!(matches!(
+            StreamCollector::new().finish(),
+            Err(StreamError::MessageStartMissing)
+        ));
+    }
+
+    #[tokio::test]
+    async fn collect_stream() {
+        let stream = tokio_stream::iter(
+            chunks()
+                .into_iter()
+                .map(Ok),
+        );
+        assert_eq!(
+            super::collect_stream(stream)
+                .await
+                .unwrap(),
+            expected()
+        );
+
+        let stream = tokio_stream::iter(vec![
+            Ok(chunks().remove(0)),
+            Err(StreamError::ParseChunkStringError(
+                "error".to_string(),
+            )),
+        ]);
+        assert!(matches!(
+            super::collect_stream(stream).await,
+            Err(StreamError::ParseChunkStringError(_))
+        ));
+    }
+}
@@ -1,5 +1,8 @@
 use crate::ValidationError;
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul};
 
 /// Amount of randomness injected into the response.
 ///
@@ -11,11 +14,10 @@ use std::fmt::Display;
     Clone,
     Copy,
     PartialEq,
-    PartialOrd,
     serde::Serialize,
     serde::Deserialize,
 )]
-#[serde(transparent)]
+#[serde(try_from = "f32", into = "f32")]
 pub struct Temperature {
     value: f32,
 }
@@ -46,7 +48,7 @@ impl Temperature {
     /// ## Errors
     /// It returns a validation error if the value is not in range: `[0.0, 1.0]`.
     pub fn new(value: f32) -> Result<Self, ValidationError<f32>> {
-        if value < 0.0 || value > 1.0 {
+        if !(0.0..=1.0).contains(&value) {
             return Err(ValidationError {
                 _type: "Temperature".to_string(),
                 expected: "The temperature must be in range: [0.0, 1.0]."
@@ -59,6 +61,138 @@ impl Temperature {
             value,
         })
     }
+
+    /// The value of the temperature.
+    pub fn as_f32(&self) -> f32 {
+        self.value
+    }
+}
+
+// NaN is rejected by the validation including deserialization, so the value is always comparable.
+impl Eq for Temperature {}
+
+impl Hash for Temperature {
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        // Normalizes -0.0 to 0.0 to be consistent with `PartialEq`.
+        let value = if self.value == 0.0 {
+            0.0f32
+        } else {
+            self.value
+        };
+        value.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for Temperature {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Temperature {
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering {
+        self.value
+            .partial_cmp(&other.value)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl TryFrom<f32> for Temperature {
+    type Error = ValidationError<f32>;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<Temperature> for f32 {
+    fn from(value: Temperature) -> Self {
+        value.value
+    }
+}
+
+impl Mul<f32> for Temperature {
+    /// `None` if the result is out of range.
+    type Output = Option<Temperature>;
+
+    fn mul(
+        self,
+        rhs: f32,
+    ) -> Self::Output {
+        Self::new(self.value * rhs).ok()
+    }
+}
+
+impl Add<Temperature> for Temperature {
+    /// `None` if the result is out of range.
+    type Output = Option<Temperature>;
+
+    fn add(
+        self,
+        rhs: Temperature,
+    ) -> Self::Output {
+        Self::new(self.value + rhs.value).ok()
+    }
+}
+
+/// The named presets of the temperature.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{Temperature, TemperaturePreset};
+///
+/// assert_eq!(
+///     TemperaturePreset::Analytical.into_temperature(),
+///     Temperature::new(0.2).unwrap()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemperaturePreset {
+    /// 0.0, for tasks that expect the most likely answer, e.g. classification and extraction.
+    ///
+    /// Note that the results will not be fully deterministic even with this preset.
+    Deterministic,
+    /// 0.2, for analytical tasks such as multiple choice, coding and summarization.
+    Analytical,
+    /// 0.5, for general tasks balancing accuracy and variety such as conversation.
+    Balanced,
+    /// 0.9, for creative and generative tasks such as writing and brainstorming.
+    Creative,
+    /// 1.0, the maximum randomness that is also the default of the API.
+    MaxRandom,
+}
+
+impl TemperaturePreset {
+    /// The temperature of this preset.
+    pub fn into_temperature(self) -> Temperature {
+        let value = match self {
+            | TemperaturePreset::Deterministic => 0.0,
+            | TemperaturePreset::Analytical => 0.2,
+            | TemperaturePreset::Balanced => 0.5,
+            | TemperaturePreset::Creative => 0.9,
+            | TemperaturePreset::MaxRandom => 1.0,
+        };
+
+        // The values of the presets are always in range.
+        Temperature {
+            value,
+        }
+    }
+}
+
+impl From<TemperaturePreset> for Temperature {
+    fn from(preset: TemperaturePreset) -> Self {
+        preset.into_temperature()
+    }
 }
 
 #[cfg(test)]
@@ -78,6 +212,61 @@ mod tests {
         assert!(Temperature::new(0.5).is_ok());
         assert!(Temperature::new(1.0).is_ok());
         assert!(Temperature::new(1.1).is_err());
+        assert!(Temperature::new(f32::NAN).is_err());
+        assert!(Temperature::new(f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+
+        fn hash_of(value: Temperature) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let zero = Temperature::new(0.0).unwrap();
+        let negative_zero = Temperature::new(-0.0).unwrap();
+        assert_eq!(zero, negative_zero);
+        assert_eq!(hash_of(zero), hash_of(negative_zero));
+
+        let one = Temperature::new(1.0).unwrap();
+        let below_one = Temperature::new(1.0 - f32::EPSILON).unwrap();
+        assert_ne!(one, below_one);
+        assert_ne!(hash_of(one), hash_of(below_one));
+
+        let mut map = HashMap::new();
+        map.insert(zero, "zero");
+        map.insert(one, "one");
+        assert_eq!(map.get(&negative_zero), Some(&"zero"));
+        assert_eq!(map.get(&Temperature::new(1.0).unwrap()), Some(&"one"));
+        assert_eq!(map.get(&below_one), None);
+    }
+
+    #[test]
+    fn ord() {
+        use std::collections::BTreeMap;
+
+        let zero = Temperature::new(0.0).unwrap();
+        let half = Temperature::new(0.5).unwrap();
+        let one = Temperature::new(1.0).unwrap();
+        assert!(zero < half && half < one);
+        assert_eq!(
+            zero.cmp(&Temperature::new(-0.0).unwrap()),
+            Ordering::Equal
+        );
+
+        let map = BTreeMap::from([
+            (one, "one"),
+            (zero, "zero"),
+            (half, "half"),
+        ]);
+        assert_eq!(
+            map.into_values().collect::<Vec<_>>(),
+            vec!["zero", "half", "one"]
+        );
     }
 
     #[test]
@@ -85,12 +274,29 @@ mod tests {
         assert_eq!(Temperature::default().value, 1.0);
     }
 
+    #[test]
+    fn as_f32() {
+        let temperature = Temperature::new(0.5).unwrap();
+        assert_eq!(temperature.as_f32(), 0.5);
+    }
+
     #[test]
     fn display() {
         let temperature = Temperature::new(0.5).unwrap();
         assert_eq!(temperature.to_string(), "0.5");
     }
 
+    #[test]
+    fn arithmetic() {
+        let half = Temperature::try_from(0.5).unwrap();
+        assert_eq!(half * 0.5, Some(Temperature::new(0.25).unwrap()));
+        assert_eq!(half * 3.0, None);
+        assert_eq!(half * -1.0, None);
+        assert_eq!(half + half, Some(Temperature::new(1.0).unwrap()));
+        assert_eq!(half + Temperature::default(), None);
+        assert!(Temperature::try_from(1.5).is_err());
+    }
+
     #[test]
     fn serialize() {
         let temperature = Temperature::new(0.5).unwrap();
@@ -107,5 +313,27 @@ mod tests {
             serde_json::from_str::<Temperature>("0.5").unwrap(),
             temperature
         );
+        assert!(serde_json::from_str::<Temperature>("1.5").is_err());
+        assert!(serde_json::from_str::<Temperature>("-0.1").is_err());
+    }
+
+    #[test]
+    fn preset() {
+        for (preset, value) in [
+            (TemperaturePreset::Deterministic, 0.0),
+            (TemperaturePreset::Analytical, 0.2),
+            (TemperaturePreset::Balanced, 0.5),
+            (TemperaturePreset::Creative, 0.9),
+            (TemperaturePreset::MaxRandom, 1.0),
+        ] {
+            assert_eq!(
+                preset.into_temperature(),
+                Temperature::new(value).unwrap()
+            );
+            assert_eq!(
+                Temperature::from(preset),
+                Temperature::new(value).unwrap()
+            );
+        }
     }
 }
@@ -0,0 +1,277 @@
+//! Test utilities for streaming-aware code and content assertions.
+//!
+//! This module is available with the `testing` feature.
+
+use futures_core::Stream;
+
+use crate::messages::{
+    Content, ContentBlock, ContentBlockDeltaChunk, ContentBlockStartChunk,
+    ContentBlockStopChunk, DeltaUsage, MessageChunk, MessageDeltaChunk,
+    MessageStartChunk, MessageStopChunk, MessagesResponseBody, PingChunk,
+    StopReason, StreamError, StreamStop, ToolUse,
+};
+
+/// Creates a stream of message chunks from pre-recorded chunks.
+///
+/// ## Arguments
+/// - `chunks` - The chunks to be yielded in order.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::testing::{chunk_stream_from_vec, complete_stream};
+/// use clust::messages::MessageStreamExt;
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// let chunks = complete_stream("Hello, world!")
+///     .into_iter()
+///     .map(Ok)
+///     .collect();
+/// let text = chunk_stream_from_vec(chunks)
+///     .full_text()
+///     .await
+///     .unwrap();
+///
+/// assert_eq!(text, "Hello, world!");
+/// # }
+/// ```
+pub fn chunk_stream_from_vec(
+    chunks: Vec<Result<MessageChunk, StreamError>>
+) -> impl Stream<Item = Result<MessageChunk, StreamError>> + Unpin {
+    futures_util::stream::iter(chunks)
+}
+
+/// Generates a minimal valid sequence of message chunks for the text.
+///
+/// The sequence is `message_start`, `content_block_start`, `ping`, `content_block_delta`,
+/// `content_block_stop`, `message_delta` with `end_turn` and `message_stop`.
+///
+/// ## Arguments
+/// - `text` - The text of the single content block delta.
+pub fn complete_stream(text: &str) -> Vec<MessageChunk> {
+    vec![
+        MessageChunk::MessageStart(MessageStartChunk::new(
+            MessagesResponseBody {
+                content: Content::MultipleBlocks(Vec::new()),
+                ..Default::default()
+            },
+        )),
+        MessageChunk::ContentBlockStart(ContentBlockStartChunk::new(
+            0,
+            "".into(),
+        )),
+        MessageChunk::Ping(PingChunk::new()),
+        MessageChunk::ContentBlockDelta(ContentBlockDeltaChunk::new(
+            0,
+            text.into(),
+        )),
+        MessageChunk::ContentBlockStop(ContentBlockStopChunk::new(0)),
+        MessageChunk::MessageDelta(MessageDeltaChunk::new(
+            StreamStop {
+                stop_reason: Some(StopReason::EndTurn),
+                stop_sequence: None,
+            },
+            DeltaUsage {
+                output_tokens: 0,
+            },
+        )),
+        MessageChunk::MessageStop(MessageStopChunk::new()),
+    ]
+}
+
+/// The assertions of the content for tests.
+///
+/// These assertions panic with the actual content instead of returning `Result`.
+impl Content {
+    /// Asserts that the content is a single text and returns the text.
+    ///
+    /// ## Panics
+    /// It panics if the content is not `Content::SingleText` or a single text block.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Content;
+    ///
+    /// assert_eq!(Content::from("text").assert_is_single_text(), "text");
+    /// ```
+    pub fn assert_is_single_text(&self) -> &str {
+        match self.as_single_text() {
+            | Some(text) => text,
+            | None => panic!(
+                "expected the content to be a single text, but got: {}",
+                self.debug_display()
+            ),
+        }
+    }
+
+    /// Asserts that the content has a tool use with the name and returns the first one.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the tool.
+    ///
+    /// ## Panics
+    /// It panics if the content has no tool use with the name.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{Content, ToolUse};
+    ///
+    /// let content = Content::from(ToolUse::new("id", "get_weather", serde_json::Value::Null));
+    /// assert_eq!(content.assert_has_tool_use("get_weather").id, "id");
+    /// ```
+    pub fn assert_has_tool_use(
+        &self,
+        name: &str,
+    ) -> &ToolUse {
+        let tool_use = self
+            .blocks()
+            .iter()
+            .find_map(|block| match block {
+                | ContentBlock::ToolUse(tool_use)
+                    if tool_use.tool_use.name == name =>
+                {
+                    Some(&tool_use.tool_use)
+                },
+                | _ => None,
+            });
+
+        match tool_use {
+            | Some(tool_use) => tool_use,
+            | None => panic!(
+                "expected the content to have a tool use named `{}`, but got: {}",
+                name,
+                self.debug_display()
+            ),
+        }
+    }
+
+    /// Asserts that the content has no tool use.
+    ///
+    /// ## Panics
+    /// It panics if the content has any tool use.
+    pub fn assert_no_tool_use(&self) {
+        if self.has_tool_uses() {
+            panic!(
+                "expected the content to have no tool use, but got: {}",
+                self.debug_display()
+            );
+        }
+    }
+
+    /// Asserts the number of the content blocks.
+    ///
+    /// `Content::SingleText` is treated as a single text block.
+    ///
+    /// ## Arguments
+    /// - `expected` - The expected number of the blocks.
+    ///
+    /// ## Panics
+    /// It panics if the number of the blocks is not the expected one.
+    pub fn assert_block_count(
+        &self,
+        expected: usize,
+    ) {
+        let actual = self.blocks_len();
+        if actual != expected {
+            panic!(
+                "expected the content to have {} blocks, but got {} blocks: {}",
+                expected,
+                actual,
+                self.debug_display()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::StreamExt;
+
+    #[tokio::test]
+    async fn chunk_stream_from_vec() {
+        let chunks = complete_stream("text");
+        assert_eq!(chunks.len(), 7);
+
+        let collected: Vec<Result<MessageChunk, StreamError>> =
+            super::chunk_stream_from_vec(
+                chunks
+                    .clone()
+                    .into_iter()
+                    .map(Ok)
+                    .collect(),
+            )
+            .collect()
+            .await;
+
+        assert_eq!(
+            collected
+                .into_iter()
+                .map(Result::unwrap)
+                .collect::<Vec<_>>(),
+            chunks
+        );
+    }
+
+    #[test]
+    fn content_assertions() {
+        let content = Content::from("text");
+        assert_eq!(content.assert_is_single_text(), "text");
+        content.assert_no_tool_use();
+        content.assert_block_count(1);
+
+        let content = Content::MultipleBlocks(vec![
+            ContentBlock::from("text"),
+            ContentBlock::from(ToolUse::new(
+                "id",
+                "name",
+                serde_json::Value::Null,
+            )),
+        ]);
+        assert_eq!(
+            content
+                .assert_has_tool_use("name")
+                .id,
+            "id"
+        );
+        content.assert_block_count(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected the content to be a single text")]
+    fn assert_is_single_text_panics() {
+        Content::MultipleBlocks(vec![]).assert_is_single_text();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "expected the content to have a tool use named `other`"
+    )]
+    fn assert_has_tool_use_panics() {
+        Content::from(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
+        ))
+        .assert_has_tool_use("other");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected the content to have no tool use")]
+    fn assert_no_tool_use_panics() {
+        Content::from(ToolUse::new(
+            "id",
+            "name",
+            serde_json::Value::Null,
+        ))
+        .assert_no_tool_use();
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "expected the content to have 2 blocks, but got 1 blocks"
+    )]
+    fn assert_block_count_panics() {
+        Content::from("text").assert_block_count(2);
+    }
+}
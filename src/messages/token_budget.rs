@@ -0,0 +1,188 @@
+use crate::messages::{ClaudeModel, MaxTokens, Usage};
+
+/// The token budget to manage the context across multiple API calls.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{ClaudeModel, MaxTokens, TokenBudget, Usage};
+///
+/// let mut budget = TokenBudget::new(ClaudeModel::Claude3Haiku20240307, 10000);
+/// budget.update_from_usage(&Usage {
+///     input_tokens: 8000,
+///     output_tokens: 500,
+///     ..Default::default()
+/// });
+///
+/// assert_eq!(budget.remaining_input(), 2000);
+/// assert_eq!(
+///     budget.safe_max_tokens(),
+///     Some(MaxTokens::new(2000, ClaudeModel::Claude3Haiku20240307).unwrap())
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TokenBudget {
+    /// The target model.
+    model: ClaudeModel,
+    /// The maximum number of input tokens across calls.
+    max_input_tokens: u32,
+    /// The number of input tokens used so far.
+    used_input: u32,
+    /// The number of output tokens used so far.
+    used_output: u32,
+}
+
+impl TokenBudget {
+    /// Creates a new unused token budget.
+    ///
+    /// ## Arguments
+    /// - `model` - The target model.
+    /// - `max_input_tokens` - The maximum number of input tokens across calls.
+    pub fn new(
+        model: ClaudeModel,
+        max_input_tokens: u32,
+    ) -> Self {
+        Self {
+            model,
+            max_input_tokens,
+            used_input: 0,
+            used_output: 0,
+        }
+    }
+
+    /// The target model.
+    pub fn model(&self) -> ClaudeModel {
+        self.model
+    }
+
+    /// The maximum number of input tokens across calls.
+    pub fn max_input_tokens(&self) -> u32 {
+        self.max_input_tokens
+    }
+
+    /// The number of input tokens used so far.
+    pub fn used_input(&self) -> u32 {
+        self.used_input
+    }
+
+    /// The number of output tokens used so far.
+    pub fn used_output(&self) -> u32 {
+        self.used_output
+    }
+
+    /// The number of remaining input tokens.
+    pub fn remaining_input(&self) -> u32 {
+        self.max_input_tokens
+            .saturating_sub(self.used_input)
+    }
+
+    /// Whether the input tokens are exhausted.
+    pub fn is_input_exhausted(&self) -> bool {
+        self.remaining_input() == 0
+    }
+
+    /// Adds the usage of an API call to this budget.
+    ///
+    /// ## Arguments
+    /// - `usage` - The usage of the API call.
+    pub fn update_from_usage(
+        &mut self,
+        usage: &Usage,
+    ) {
+        self.used_input = self
+            .used_input
+            .saturating_add(usage.input_tokens);
+        self.used_output = self
+            .used_output
+            .saturating_add(usage.output_tokens);
+    }
+
+    /// The maximum number of tokens for the next call,
+    /// which is the smaller of the maximum of the model and the remaining input tokens.
+    ///
+    /// It returns `None` if the input tokens are exhausted.
+    pub fn safe_max_tokens(&self) -> Option<MaxTokens> {
+        if self.is_input_exhausted() {
+            return None;
+        }
+
+        let value = self
+            .model
+            .max_tokens()
+            .min(self.remaining_input());
+
+        MaxTokens::new(value, self.model).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new() {
+        let budget = TokenBudget::new(ClaudeModel::Claude3Haiku20240307, 100);
+        assert_eq!(budget.model(), ClaudeModel::Claude3Haiku20240307);
+        assert_eq!(budget.max_input_tokens(), 100);
+        assert_eq!(budget.used_input(), 0);
+        assert_eq!(budget.used_output(), 0);
+        assert_eq!(budget.remaining_input(), 100);
+        assert!(!budget.is_input_exhausted());
+    }
+
+    #[test]
+    fn update_from_usage() {
+        let mut budget =
+            TokenBudget::new(ClaudeModel::Claude3Haiku20240307, 100);
+
+        budget.update_from_usage(&Usage {
+            input_tokens: 60,
+            output_tokens: 10,
+            ..Default::default()
+        });
+        assert_eq!(budget.used_input(), 60);
+        assert_eq!(budget.used_output(), 10);
+        assert_eq!(budget.remaining_input(), 40);
+
+        budget.update_from_usage(&Usage {
+            input_tokens: 60,
+            output_tokens: 10,
+            ..Default::default()
+        });
+        assert_eq!(budget.used_input(), 120);
+        assert_eq!(budget.used_output(), 20);
+        assert_eq!(budget.remaining_input(), 0);
+        assert!(budget.is_input_exhausted());
+    }
+
+    #[test]
+    fn safe_max_tokens() {
+        let mut budget =
+            TokenBudget::new(ClaudeModel::Claude3Haiku20240307, 10000);
+        assert_eq!(
+            budget.safe_max_tokens(),
+            Some(MaxTokens::from_model(
+                ClaudeModel::Claude3Haiku20240307
+            ))
+        );
+
+        budget.update_from_usage(&Usage {
+            input_tokens: 9000,
+            output_tokens: 0,
+            ..Default::default()
+        });
+        assert_eq!(
+            budget.safe_max_tokens(),
+            Some(
+                MaxTokens::new(1000, ClaudeModel::Claude3Haiku20240307)
+                    .unwrap()
+            )
+        );
+
+        budget.update_from_usage(&Usage {
+            input_tokens: 1000,
+            output_tokens: 0,
+            ..Default::default()
+        });
+        assert_eq!(budget.safe_max_tokens(), None);
+    }
+}
@@ -1,6 +1,11 @@
 use crate::macros::impl_display_for_serialize;
-use crate::messages::{TextContentBlock, ToolCallError};
+use crate::messages::{
+    ContentBlock, MessagesRequestBody, MessagesResponseBody, TextContentBlock,
+    ToolCallError, ToolMergeError,
+};
 use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
 
 /// A tool that can be used by assistant.
 pub trait Tool {
@@ -24,9 +29,135 @@ pub trait AsyncTool {
     ) -> impl Future<Output = Result<ToolResult, ToolCallError>> + Send;
 }
 
+/// A function that can be called as a tool, implemented for closures.
+pub trait ToolFn:
+    Fn(ToolUse) -> Result<ToolResult, ToolCallError> + Send + Sync
+{
+}
+
+impl<F> ToolFn for F where
+    F: Fn(ToolUse) -> Result<ToolResult, ToolCallError> + Send + Sync
+{
+}
+
+/// The boxed future of an asynchronous tool call.
+type ToolCallFuture<'a> = Pin<
+    Box<dyn Future<Output = Result<ToolResult, ToolCallError>> + Send + 'a>,
+>;
+
+/// A tool implemented by a closure with the definition.
+///
+/// ## Example
+/// ```rust
+/// use clust::messages::{ClosureTool, Tool, ToolDefinition, ToolResult, ToolUse};
+///
+/// let tool = ClosureTool::new(
+///     ToolDefinition::new("echo", Some("Echoes the input."), serde_json::json!({})),
+///     |tool_use: ToolUse| {
+///         Ok(ToolResult::success(tool_use.id, Some(tool_use.input.to_string())))
+///     },
+/// );
+///
+/// let tool_result = tool
+///     .call(ToolUse::new("id", "echo", serde_json::json!({"text": "Hello"})))
+///     .unwrap();
+/// assert_eq!(tool_result.tool_use_id, "id");
+/// ```
+pub struct ClosureTool {
+    /// The definition of the tool.
+    definition: ToolDefinition,
+    /// The handler of the tool call.
+    handler: Box<dyn ToolFn>,
+}
+
+impl ClosureTool {
+    /// Creates a new tool with the definition and the handler.
+    ///
+    /// ## Arguments
+    /// - `definition` - The definition of the tool.
+    /// - `handler` - The function called with the tool use.
+    pub fn new<F>(
+        definition: ToolDefinition,
+        handler: F,
+    ) -> Self
+    where
+        F: ToolFn + 'static,
+    {
+        Self {
+            definition,
+            handler: Box::new(handler),
+        }
+    }
+}
+
+impl Tool for ClosureTool {
+    fn definition(&self) -> ToolDefinition {
+        self.definition.clone()
+    }
+
+    fn call(
+        &self,
+        tool_use: ToolUse,
+    ) -> Result<ToolResult, ToolCallError> {
+        (self.handler)(tool_use)
+    }
+}
+
+/// An asynchronous tool implemented by a closure with the definition.
+pub struct AsyncClosureTool {
+    /// The definition of the tool.
+    definition: ToolDefinition,
+    /// The handler of the tool call.
+    handler: Box<dyn Fn(ToolUse) -> ToolCallFuture<'static> + Send + Sync>,
+}
+
+impl AsyncClosureTool {
+    /// Creates a new asynchronous tool with the definition and the handler.
+    ///
+    /// ## Arguments
+    /// - `definition` - The definition of the tool.
+    /// - `handler` - The asynchronous function called with the tool use.
+    pub fn new<F, Fut>(
+        definition: ToolDefinition,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(ToolUse) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ToolResult, ToolCallError>>
+            + Send
+            + 'static,
+    {
+        Self {
+            definition,
+            handler: Box::new(move |tool_use| Box::pin(handler(tool_use))),
+        }
+    }
+}
+
+impl AsyncTool for AsyncClosureTool {
+    fn definition(&self) -> ToolDefinition {
+        self.definition.clone()
+    }
+
+    fn call(
+        &self,
+        tool_use: ToolUse,
+    ) -> impl Future<Output = Result<ToolResult, ToolCallError>> + Send {
+        (self.handler)(tool_use)
+    }
+}
+
 /// A tool definition that can be used by assistant.
+///
+/// `Hash` is computed only by the name, which is consistent with `PartialEq` of all fields.
 #[derive(
-    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub struct ToolDefinition {
     /// Name of the tool.
@@ -39,6 +170,33 @@ pub struct ToolDefinition {
 
 impl_display_for_serialize!(ToolDefinition);
 
+impl std::hash::Hash for ToolDefinition {
+    fn hash<H: std::hash::Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        self.name.hash(state);
+    }
+}
+
+impl PartialEq<str> for ToolDefinition {
+    fn eq(
+        &self,
+        other: &str,
+    ) -> bool {
+        self.name == other
+    }
+}
+
+impl PartialEq<&str> for ToolDefinition {
+    fn eq(
+        &self,
+        other: &&str,
+    ) -> bool {
+        self.name == *other
+    }
+}
+
 impl ToolDefinition {
     /// Creates a new `ToolDefinition`.
     pub fn new<S, T>(
@@ -56,6 +214,151 @@ impl ToolDefinition {
             input_schema,
         }
     }
+
+    /// Whether this definition has the same name as the other.
+    ///
+    /// ## Arguments
+    /// - `other` - The other tool definition.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolDefinition;
+    ///
+    /// let first = ToolDefinition::new("get_weather", Some("first"), serde_json::Value::Null);
+    /// let second = ToolDefinition::new("get_weather", Some("second"), serde_json::Value::Null);
+    ///
+    /// assert!(first.same_name(&second));
+    /// assert_ne!(first, second);
+    /// assert!(first == "get_weather");
+    /// ```
+    pub fn same_name(
+        &self,
+        other: &ToolDefinition,
+    ) -> bool {
+        self.name == other.name
+    }
+
+    /// Whether this definition has the structurally same input schema as the other.
+    ///
+    /// ## Arguments
+    /// - `other` - The other tool definition.
+    pub fn same_schema_as(
+        &self,
+        other: &ToolDefinition,
+    ) -> bool {
+        self.input_schema == other.input_schema
+    }
+
+    /// Describes the differences of the input schema from this definition to the other.
+    ///
+    /// Each line describes a difference at the JSON pointer, or `None` if the schemas are the same.
+    ///
+    /// ## Arguments
+    /// - `other` - The other tool definition.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolDefinition;
+    ///
+    /// let old = ToolDefinition::new(
+    ///     "get_weather",
+    ///     None::<String>,
+    ///     serde_json::json!({"properties": {"location": {"type": "string"}}}),
+    /// );
+    /// let new = ToolDefinition::new(
+    ///     "get_weather",
+    ///     None::<String>,
+    ///     serde_json::json!({"properties": {"location": {"type": "object"}}}),
+    /// );
+    ///
+    /// assert!(!old.same_schema_as(&new));
+    /// assert_eq!(
+    ///     old.schema_diff(&new).unwrap(),
+    ///     "changed `/properties/location/type`: \"string\" -> \"object\""
+    /// );
+    /// assert_eq!(old.schema_diff(&old), None);
+    /// ```
+    pub fn schema_diff(
+        &self,
+        other: &ToolDefinition,
+    ) -> Option<String> {
+        let mut differences = Vec::new();
+        collect_differences(
+            "",
+            &self.input_schema,
+            &other.input_schema,
+            &mut differences,
+        );
+
+        if differences.is_empty() {
+            None
+        } else {
+            Some(differences.join("\n"))
+        }
+    }
+}
+
+/// Collects the differences from the left to the right JSON value at the JSON pointer.
+fn collect_differences(
+    pointer: &str,
+    left: &serde_json::Value,
+    right: &serde_json::Value,
+    differences: &mut Vec<String>,
+) {
+    use serde_json::Value;
+
+    match (left, right) {
+        | (Value::Object(left), Value::Object(right)) => {
+            for (key, left_value) in left {
+                let pointer = child_pointer(pointer, key);
+                match right.get(key) {
+                    | Some(right_value) => collect_differences(
+                        &pointer,
+                        left_value,
+                        right_value,
+                        differences,
+                    ),
+                    | None => differences.push(format!(
+                        "removed `{}`: {}",
+                        pointer, left_value
+                    )),
+                }
+            }
+
+            for (key, right_value) in right {
+                if !left.contains_key(key) {
+                    differences.push(format!(
+                        "added `{}`: {}",
+                        child_pointer(pointer, key),
+                        right_value
+                    ));
+                }
+            }
+        },
+        | _ => {
+            if left != right {
+                differences.push(format!(
+                    "changed `{}`: {} -> {}",
+                    if pointer.is_empty() { "/" } else { pointer },
+                    left,
+                    right
+                ));
+            }
+        },
+    }
+}
+
+/// Appends the escaped key to the JSON pointer.
+fn child_pointer(
+    pointer: &str,
+    key: &str,
+) -> String {
+    format!(
+        "{}/{}",
+        pointer,
+        key.replace('~', "~0")
+            .replace('/', "~1")
+    )
 }
 
 /// A tool use request.
@@ -98,6 +401,112 @@ impl ToolUse {
             input,
         }
     }
+
+    /// Whether this tool use requests the tool with the name.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the tool.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolUse;
+    ///
+    /// let tool_use = ToolUse::new("id", "get_weather", serde_json::json!({}));
+    /// assert!(tool_use.is_named("get_weather"));
+    /// ```
+    pub fn is_named(
+        &self,
+        name: &str,
+    ) -> bool {
+        self.name == name
+    }
+
+    /// Whether this tool use requests the tool.
+    ///
+    /// ## Arguments
+    /// - `tool` - The tool.
+    pub fn is_for<T>(
+        &self,
+        tool: &T,
+    ) -> bool
+    where
+        T: Tool + ?Sized,
+    {
+        self.is_for_definition(&tool.definition())
+    }
+
+    /// Whether this tool use requests the tool of the definition.
+    ///
+    /// ## Arguments
+    /// - `definition` - The definition of the tool.
+    pub fn is_for_definition(
+        &self,
+        definition: &ToolDefinition,
+    ) -> bool {
+        self.is_named(&definition.name)
+    }
+
+    /// The value of the parameter in the input object.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the parameter.
+    pub fn get_param(
+        &self,
+        name: &str,
+    ) -> Option<&serde_json::Value> {
+        self.input.get(name)
+    }
+
+    /// Whether the input object has the parameter.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the parameter.
+    pub fn has_param(
+        &self,
+        name: &str,
+    ) -> bool {
+        self.get_param(name).is_some()
+    }
+
+    /// The number of the top-level parameters in the input object.
+    ///
+    /// It returns `0` if the input is not an object.
+    pub fn param_count(&self) -> usize {
+        self.input
+            .as_object()
+            .map_or(0, |object| object.len())
+    }
+
+    /// The names of the top-level parameters in the input object.
+    ///
+    /// It returns an empty list if the input is not an object.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolUse;
+    ///
+    /// let tool_use = ToolUse::new(
+    ///     "id",
+    ///     "get_weather",
+    ///     serde_json::json!({"location": "Tokyo", "unit": "celsius"}),
+    /// );
+    ///
+    /// assert!(tool_use.has_param("location"));
+    /// assert_eq!(tool_use.get_param("unit"), Some(&serde_json::json!("celsius")));
+    /// assert_eq!(tool_use.param_count(), 2);
+    /// assert_eq!(tool_use.param_names(), vec!["location", "unit"]);
+    /// ```
+    pub fn param_names(&self) -> Vec<&str> {
+        self.input
+            .as_object()
+            .map(|object| {
+                object
+                    .keys()
+                    .map(String::as_str)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 }
 
 /// A result of a tool.
@@ -109,7 +518,7 @@ pub struct ToolResult {
     pub tool_use_id: String,
     /// The result of the tool, as a string (e.g. "content": "65 degrees") or list of nested content blocks (e.g. "content": [{"type": "text", "text": "65 degrees"}]\). During beta, only the text type content blocks are supported for tool_result content.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub content: Option<TextContentBlock>,
+    pub content: Option<ToolResultContent>,
     /// Set to true if the tool execution resulted in an error.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_error: Option<bool>,
@@ -117,6 +526,12 @@ pub struct ToolResult {
 
 impl_display_for_serialize!(ToolResult);
 
+impl From<(&str, &str)> for ToolResult {
+    fn from((tool_use_id, text): (&str, &str)) -> Self {
+        Self::with_text_result(tool_use_id, text)
+    }
+}
+
 impl ToolResult {
     /// Creates a new `ToolResult` as a success.
     pub fn success<S, T>(
@@ -129,7 +544,9 @@ impl ToolResult {
     {
         Self {
             tool_use_id: tool_use_id.into(),
-            content: content.map(Into::into),
+            content: content.map(|content| {
+                ToolResultContent::SingleText(content.into())
+            }),
             is_error: None,
         }
     }
@@ -157,11 +574,137 @@ impl ToolResult {
     {
         Self {
             tool_use_id: tool_use_id.into(),
-            content: content.map(Into::into),
+            content: content.map(|content| {
+                ToolResultContent::SingleText(content.into())
+            }),
             is_error: Some(true),
         }
     }
 
+    /// Creates a new `ToolResult` as a success with a text content.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolResult;
+    ///
+    /// assert_eq!(
+    ///     ToolResult::with_text_result("tool_use_id", "65 degrees"),
+    ///     ToolResult::success("tool_use_id", Some("65 degrees")),
+    /// );
+    /// assert_eq!(
+    ///     ToolResult::from(("tool_use_id", "65 degrees")),
+    ///     ToolResult::success("tool_use_id", Some("65 degrees")),
+    /// );
+    /// ```
+    pub fn with_text_result<S, T>(
+        tool_use_id: S,
+        text: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self::success(tool_use_id, Some(text.into()))
+    }
+
+    /// Creates a new `ToolResult` as an error with a text content.
+    pub fn with_text_error<S, T>(
+        tool_use_id: S,
+        text: T,
+    ) -> Self
+    where
+        S: Into<String>,
+        T: Into<String>,
+    {
+        Self::error(tool_use_id, Some(text.into()))
+    }
+
+    /// Creates a new `ToolResult` as a success with a structured JSON content.
+    ///
+    /// The value is serialized into a pretty-printed JSON text.
+    ///
+    /// ## Arguments
+    /// - `tool_use_id` - The ID of the tool use.
+    /// - `value` - The structured result of the tool.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolResult;
+    ///
+    /// let value = serde_json::json!({"temperature": 65, "unit": "fahrenheit"});
+    /// let result = ToolResult::success_with_json("tool_use_id", value.clone());
+    /// assert_eq!(result.structured_content(), Some(value));
+    /// ```
+    pub fn success_with_json<S>(
+        tool_use_id: S,
+        value: serde_json::Value,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self::success(
+            tool_use_id,
+            Some(
+                serde_json::to_string_pretty(&value)
+                    .expect("serde_json::Value is always serializable"),
+            ),
+        )
+    }
+
+    /// Parses the text content of this result as a structured JSON.
+    ///
+    /// It returns `None` if there is no single text content or the text is not a valid JSON.
+    pub fn structured_content(&self) -> Option<serde_json::Value> {
+        serde_json::from_str(self.content_text()?).ok()
+    }
+
+    /// The text content of this result.
+    ///
+    /// It returns `None` if there is no content or the content has no text block or multiple text blocks.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::ToolResult;
+    ///
+    /// let result = ToolResult::success("tool_use_id", Some("65 degrees"));
+    /// assert_eq!(result.content_text(), Some("65 degrees"));
+    /// assert!(result.is_success());
+    ///
+    /// let result = ToolResult::error_without_content("tool_use_id");
+    /// assert_eq!(result.content_text(), None);
+    /// assert!(result.is_error());
+    /// ```
+    pub fn content_text(&self) -> Option<&str> {
+        match self.content.as_ref()? {
+            | ToolResultContent::SingleText(text) => Some(&text.text),
+            | ToolResultContent::MultipleBlocks(blocks) => {
+                let mut texts = blocks
+                    .iter()
+                    .filter_map(|block| match block {
+                        | ContentBlock::Text(text) => Some(&text.text),
+                        | _ => None,
+                    });
+                match (texts.next(), texts.next()) {
+                    | (Some(text), None) => Some(text),
+                    | _ => None,
+                }
+            },
+        }
+    }
+
+    /// Whether this result is an error.
+    ///
+    /// The result without `is_error` is not an error.
+    pub fn is_error(&self) -> bool {
+        self.is_error
+            .unwrap_or(false)
+    }
+
+    /// Whether this result is a success.
+    pub fn is_success(&self) -> bool {
+        !self.is_error()
+    }
+
     /// Creates a new `ToolResult` as an error without content.
     pub fn error_without_content<S>(tool_use_id: S) -> Self
     where
@@ -173,6 +716,180 @@ impl ToolResult {
             is_error: Some(true),
         }
     }
+
+    /// Appends a content block to the content of this result.
+    ///
+    /// A single text content is promoted to multiple content blocks.
+    ///
+    /// ## Arguments
+    /// - `block` - The content block to append.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ContentBlock, ToolResult, ToolResultContent};
+    ///
+    /// let mut tool_result = ToolResult::success("tool_use_id", Some("first"));
+    /// tool_result.append_content(ContentBlock::from("second"));
+    ///
+    /// assert_eq!(
+    ///     tool_result.content,
+    ///     Some(ToolResultContent::MultipleBlocks(vec![
+    ///         ContentBlock::from("first"),
+    ///         ContentBlock::from("second"),
+    ///     ]))
+    /// );
+    /// ```
+    pub fn append_content(
+        &mut self,
+        block: ContentBlock,
+    ) {
+        let mut blocks = match self.content.take() {
+            | Some(content) => content.into_blocks(),
+            | None => Vec::new(),
+        };
+        blocks.push(block);
+
+        self.content = Some(ToolResultContent::MultipleBlocks(blocks));
+    }
+
+    /// Merges another result for the same tool use into this result.
+    ///
+    /// The content blocks of `other` are appended after the ones of this result,
+    /// and the merged result is an error if either of them is an error.
+    ///
+    /// ## Arguments
+    /// - `other` - The other result to merge.
+    ///
+    /// ## Errors
+    /// It returns `ToolMergeError::ToolUseIdMismatch` if the tool use IDs are different.
+    pub fn merge(
+        mut self,
+        other: ToolResult,
+    ) -> Result<ToolResult, ToolMergeError> {
+        if self.tool_use_id != other.tool_use_id {
+            return Err(ToolMergeError::ToolUseIdMismatch {
+                expected: self.tool_use_id,
+                actual: other.tool_use_id,
+            });
+        }
+
+        if let Some(content) = other.content {
+            for block in content.into_blocks() {
+                self.append_content(block);
+            }
+        }
+
+        self.is_error = match (self.is_error, other.is_error) {
+            | (Some(is_error), Some(other_is_error)) => {
+                Some(is_error || other_is_error)
+            },
+            | (is_error, None) => is_error,
+            | (None, other_is_error) => other_is_error,
+        };
+
+        Ok(self)
+    }
+}
+
+/// The content of a tool result.
+///
+/// It is deserialized from a string (e.g. `"content": "65 degrees"`), a text content block (e.g. `"content": {"type": "text", "text": "65 degrees"}`) or a list of content blocks (e.g. `"content": [{"type": "text", "text": "65 degrees"}]`).
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum ToolResultContent {
+    /// The single text content block.
+    SingleText(TextContentBlock),
+    /// The multiple content blocks.
+    MultipleBlocks(Vec<ContentBlock>),
+}
+
+impl_display_for_serialize!(ToolResultContent);
+
+impl<'de> serde::Deserialize<'de> for ToolResultContent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct ToolResultContentVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ToolResultContentVisitor {
+            type Value = ToolResultContent;
+
+            fn expecting(
+                &self,
+                formatter: &mut std::fmt::Formatter,
+            ) -> std::fmt::Result {
+                formatter.write_str(
+                    "a string, a text content block or an array of content blocks",
+                )
+            }
+
+            fn visit_str<E>(
+                self,
+                value: &str,
+            ) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(ToolResultContent::SingleText(
+                    TextContentBlock::new(value),
+                ))
+            }
+
+            fn visit_map<A>(
+                self,
+                map: A,
+            ) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let text = serde::Deserialize::deserialize(
+                    serde::de::value::MapAccessDeserializer::new(map),
+                )?;
+                Ok(ToolResultContent::SingleText(text))
+            }
+
+            fn visit_seq<A>(
+                self,
+                mut seq: A,
+            ) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut blocks = Vec::new();
+                while let Some(block) = seq.next_element()? {
+                    blocks.push(block);
+                }
+                Ok(ToolResultContent::MultipleBlocks(blocks))
+            }
+        }
+
+        deserializer.deserialize_any(ToolResultContentVisitor)
+    }
+}
+
+impl From<TextContentBlock> for ToolResultContent {
+    fn from(text: TextContentBlock) -> Self {
+        Self::SingleText(text)
+    }
+}
+
+impl From<Vec<ContentBlock>> for ToolResultContent {
+    fn from(blocks: Vec<ContentBlock>) -> Self {
+        Self::MultipleBlocks(blocks)
+    }
+}
+
+impl ToolResultContent {
+    /// Converts into the content blocks.
+    pub fn into_blocks(self) -> Vec<ContentBlock> {
+        match self {
+            | ToolResultContent::SingleText(text) => {
+                vec![ContentBlock::Text(text)]
+            },
+            | ToolResultContent::MultipleBlocks(blocks) => blocks,
+        }
+    }
 }
 
 /// A list of tools that can be called by the assistant.
@@ -197,26 +914,425 @@ impl ToolList {
             .into()
     }
 
+    /// List of tool definitions to be set as the `tools` of the request.
+    ///
+    /// This is the same as `ToolList::definitions` and can be passed to `MessagesRequestBuilder::tools` as is.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{MessagesRequestBuilder, ToolList};
+    ///
+    /// # fn build(tool_list: ToolList) {
+    /// let request_body = MessagesRequestBuilder::default()
+    ///     .tools(tool_list.to_request_tools())
+    ///     .build();
+    /// # }
+    /// ```
+    pub fn to_request_tools(&self) -> Vec<ToolDefinition> {
+        self.definitions()
+    }
+
+    /// Appends the tool definitions of this list to the `tools` of the request.
+    ///
+    /// ## Arguments
+    /// - `request` - The request body to append the tool definitions to.
+    pub fn extend_definitions_into(
+        &self,
+        request: &mut MessagesRequestBody,
+    ) {
+        request
+            .tools
+            .get_or_insert_with(Vec::new)
+            .extend(
+                self.iter()
+                    .map(|tool| tool.definition()),
+            );
+    }
+
     /// Calls a tool in this list.
     pub fn call(
         &self,
         tool_use: ToolUse,
     ) -> Result<ToolResult, ToolCallError> {
-        let target_name = tool_use.name.clone();
+        let target_tool = self
+            .find(&tool_use.name)
+            .ok_or_else(|| {
+                ToolCallError::ToolNotFound(tool_use.name.clone())
+            })?;
 
+        target_tool.call(tool_use)
+    }
+
+    /// Calls the tools for all tool uses in the response in order.
+    ///
+    /// ## Arguments
+    /// - `response` - The response body with the tool uses.
+    ///
+    /// ## Returns
+    /// The results in the same order as the tool uses, which can be passed to `tool_result_message` when all succeed.
+    ///
+    /// ## NOTE
+    /// The tools are called sequentially because they are not required to be `Send` or `Sync`.
+    /// Use `AsyncToolList::call_all_parallel` with the `parallel-tools` feature to call tools in parallel.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{
+    ///     tool_result_message, MessagesResponseBody, ToolDefinition, ToolList, ToolResult, ToolUse,
+    /// };
+    ///
+    /// let mut tool_list = ToolList::new(vec![]);
+    /// tool_list.add_fn(
+    ///     ToolDefinition::new("echo", None::<String>, serde_json::json!({})),
+    ///     |tool_use: ToolUse| Ok(ToolResult::success(tool_use.id, Some(tool_use.input.to_string()))),
+    /// );
+    ///
+    /// let response = MessagesResponseBody {
+    ///     content: vec![
+    ///         ToolUse::new("first", "echo", serde_json::json!(1)).into(),
+    ///         ToolUse::new("second", "echo", serde_json::json!(2)).into(),
+    ///     ]
+    ///     .into(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let results = tool_list
+    ///     .call_all_requested(&response)
+    ///     .into_iter()
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// let message = tool_result_message(results);
+    /// ```
+    pub fn call_all_requested(
+        &self,
+        response: &MessagesResponseBody,
+    ) -> Vec<Result<ToolResult, ToolCallError>> {
+        response
+            .content
+            .iter_tool_uses()
+            .map(|tool_use| self.call(tool_use.clone()))
+            .collect()
+    }
+
+    /// Finds a tool by the name.
+    ///
+    /// ## Arguments
+    /// - `name` - The name of the tool.
+    pub fn find(
+        &self,
+        name: &str,
+    ) -> Option<&dyn Tool> {
+        self.iter()
+            .find(|tool| tool.definition().name == name)
+    }
+
+    /// Iterates over the tools in this list.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Tool> {
+        self.tools
+            .iter()
+            .map(|tool| tool.as_ref())
+    }
+
+    /// List of tool names.
+    ///
+    /// The names are owned because `Tool::definition` creates a new definition.
+    pub fn names(&self) -> Vec<String> {
+        self.iter()
+            .map(|tool| tool.definition().name)
+            .collect()
+    }
+
+    /// Whether this list has no tools.
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+
+    /// Adds a tool implemented by a closure.
+    ///
+    /// ## Arguments
+    /// - `definition` - The definition of the tool.
+    /// - `handler` - The function called with the tool use.
+    pub fn add_fn<F>(
+        &mut self,
+        definition: ToolDefinition,
+        handler: F,
+    ) where
+        F: ToolFn + 'static,
+    {
+        self.tools
+            .push(Box::new(ClosureTool::new(
+                definition, handler,
+            )));
+    }
+}
+
+/// The object safe version of `AsyncTool` to hold different tools in `AsyncToolList`.
+trait DynAsyncTool: Send + Sync {
+    fn definition(&self) -> ToolDefinition;
+    fn call(
+        &self,
+        tool_use: ToolUse,
+    ) -> ToolCallFuture<'_>;
+}
+
+impl<T> DynAsyncTool for T
+where
+    T: AsyncTool + Send + Sync,
+{
+    fn definition(&self) -> ToolDefinition {
+        AsyncTool::definition(self)
+    }
+
+    fn call(
+        &self,
+        tool_use: ToolUse,
+    ) -> ToolCallFuture<'_> {
+        Box::pin(AsyncTool::call(self, tool_use))
+    }
+}
+
+/// A list of asynchronous tools that can be called by the assistant.
+#[derive(Default)]
+pub struct AsyncToolList {
+    tools: Vec<Arc<dyn DynAsyncTool>>,
+}
+
+impl AsyncToolList {
+    /// Create a new empty asynchronous tool list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new asynchronous tool list from the tools of the same type.
+    ///
+    /// ## Arguments
+    /// - `tools` - The asynchronous tools.
+    pub fn from_tools<T>(tools: Vec<T>) -> Self
+    where
+        T: AsyncTool + Send + Sync + 'static,
+    {
+        Self {
+            tools: tools
+                .into_iter()
+                .map(|tool| Arc::new(tool) as Arc<dyn DynAsyncTool>)
+                .collect(),
+        }
+    }
+
+    /// Adds an asynchronous tool.
+    ///
+    /// ## Arguments
+    /// - `tool` - The asynchronous tool.
+    pub fn add<T>(
+        &mut self,
+        tool: T,
+    ) where
+        T: AsyncTool + Send + Sync + 'static,
+    {
+        self.tools
+            .push(Arc::new(tool));
+    }
+
+    /// Adds an asynchronous tool implemented by a closure.
+    ///
+    /// ## Arguments
+    /// - `definition` - The definition of the tool.
+    /// - `handler` - The asynchronous function called with the tool use.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{AsyncToolList, ToolDefinition, ToolResult, ToolUse};
+    ///
+    /// # async fn run() {
+    /// let mut tool_list = AsyncToolList::new();
+    /// tool_list.add_async_fn(
+    ///     ToolDefinition::new("echo", Some("Echoes the input."), serde_json::json!({})),
+    ///     |tool_use: ToolUse| async move {
+    ///         Ok(ToolResult::success(tool_use.id, Some(tool_use.input.to_string())))
+    ///     },
+    /// );
+    ///
+    /// let tool_result = tool_list
+    ///     .call(ToolUse::new("id", "echo", serde_json::json!({})))
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn add_async_fn<F, Fut>(
+        &mut self,
+        definition: ToolDefinition,
+        handler: F,
+    ) where
+        F: Fn(ToolUse) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<ToolResult, ToolCallError>>
+            + Send
+            + 'static,
+    {
+        self.add(AsyncClosureTool::new(
+            definition, handler,
+        ));
+    }
+
+    /// List of tool definitions.
+    pub fn definitions(&self) -> Vec<ToolDefinition> {
+        self.tools
+            .iter()
+            .map(|tool| tool.definition())
+            .collect()
+    }
+
+    /// Calls a tool in this list asynchronously.
+    pub async fn call(
+        &self,
+        tool_use: ToolUse,
+    ) -> Result<ToolResult, ToolCallError> {
         let target_tool = self
             .tools
             .iter()
-            .find(|tool| tool.definition().name == target_name)
-            .ok_or_else(|| ToolCallError::ToolNotFound(target_name))?;
+            .find(|tool| tool.definition().name == tool_use.name)
+            .ok_or_else(|| {
+                ToolCallError::ToolNotFound(tool_use.name.clone())
+            })?;
 
-        target_tool.call(tool_use)
+        target_tool
+            .call(tool_use)
+            .await
+    }
+
+    /// Calls the tools for all tool uses in the response sequentially in order.
+    ///
+    /// ## Arguments
+    /// - `response` - The response body with the tool uses.
+    ///
+    /// ## Returns
+    /// The results in the same order as the tool uses, which can be passed to `tool_result_message` when all succeed.
+    pub async fn call_all_requested(
+        &self,
+        response: &MessagesResponseBody,
+    ) -> Vec<Result<ToolResult, ToolCallError>> {
+        let mut results = Vec::new();
+        for tool_use in response
+            .content
+            .iter_tool_uses()
+        {
+            results.push(
+                self.call(tool_use.clone())
+                    .await,
+            );
+        }
+
+        results
+    }
+
+    /// Calls the tools for the tool uses in parallel by `tokio::task::spawn`.
+    ///
+    /// It must be called in the context of a `tokio` runtime.
+    ///
+    /// ## Arguments
+    /// - `tool_uses` - The tool uses to call.
+    ///
+    /// ## Returns
+    /// The results in the same order as the tool uses regardless of the completion order.
+    /// A panicked tool call results in `ToolCallError::ExecutionFailed` with the panic message.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{AsyncToolList, ToolDefinition, ToolResult, ToolUse};
+    ///
+    /// # async fn run() {
+    /// let mut tool_list = AsyncToolList::new();
+    /// tool_list.add_async_fn(
+    ///     ToolDefinition::new("echo", None::<String>, serde_json::json!({})),
+    ///     |tool_use: ToolUse| async move {
+    ///         Ok(ToolResult::success(tool_use.id, Some(tool_use.input.to_string())))
+    ///     },
+    /// );
+    ///
+    /// let results = tool_list
+    ///     .call_all_parallel(vec![
+    ///         ToolUse::new("first", "echo", serde_json::json!(1)),
+    ///         ToolUse::new("second", "echo", serde_json::json!(2)),
+    ///     ])
+    ///     .await;
+    /// # }
+    /// ```
+    #[cfg(feature = "parallel-tools")]
+    pub async fn call_all_parallel(
+        &self,
+        tool_uses: Vec<ToolUse>,
+    ) -> Vec<Result<ToolResult, ToolCallError>> {
+        // The tasks start on spawning and are joined in the order of the tool uses.
+        let handles = tool_uses
+            .into_iter()
+            .map(|tool_use| {
+                let tool = self
+                    .tools
+                    .iter()
+                    .find(|tool| tool.definition().name == tool_use.name)
+                    .ok_or_else(|| {
+                        ToolCallError::ToolNotFound(tool_use.name.clone())
+                    })?;
+                let tool = Arc::clone(tool);
+
+                Ok(tokio::task::spawn(async move {
+                    tool.call(tool_use).await
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(match handle {
+                | Ok(handle) => handle
+                    .await
+                    .unwrap_or_else(|error| {
+                        Err(join_error_to_tool_call_error(error))
+                    }),
+                | Err(error) => Err(error),
+            });
+        }
+
+        results
+    }
+
+    /// Whether this list has no tools.
+    pub fn is_empty(&self) -> bool {
+        self.tools.is_empty()
+    }
+}
+
+/// Converts the join error of a panicked or cancelled tool call task into a tool call error.
+#[cfg(feature = "parallel-tools")]
+fn join_error_to_tool_call_error(
+    error: tokio::task::JoinError
+) -> ToolCallError {
+    if !error.is_panic() {
+        return ToolCallError::ExecutionFailed(
+            "The task of the tool was cancelled".to_string(),
+        );
     }
+
+    let payload = error.into_panic();
+    let message = payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| {
+            payload
+                .downcast_ref::<String>()
+                .cloned()
+        })
+        .unwrap_or_default();
+
+    ToolCallError::ExecutionFailed(format!(
+        "The task of the tool panicked: {}",
+        message
+    ))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::messages::{ImageContentSource, ImageMediaType};
 
     #[test]
     fn default_tool_definition() {
@@ -229,6 +1345,92 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compare_tool_definition() {
+        let first = ToolDefinition::new(
+            "tool",
+            Some("first"),
+            serde_json::Value::Null,
+        );
+        let second = ToolDefinition::new(
+            "tool",
+            Some("second"),
+            serde_json::Value::Null,
+        );
+        let other = ToolDefinition::new(
+            "other_tool",
+            Some("first"),
+            serde_json::Value::Null,
+        );
+
+        assert!(first.same_name(&second));
+        assert!(!first.same_name(&other));
+        assert!(first == *"tool");
+        assert!(first == "tool");
+        assert!(first != "other_tool");
+
+        let set = std::collections::HashSet::from([
+            first.clone(),
+            first.clone(),
+            second,
+            other,
+        ]);
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&first));
+    }
+
+    #[test]
+    fn schema_diff() {
+        let definition = ToolDefinition::new(
+            "name",
+            None::<String>,
+            serde_json::json!({
+                "type": "object",
+                "properties": {
+                    "location": {"type": "string"},
+                    "unit": {"type": "string"},
+                },
+                "required": ["location"],
+            }),
+        );
+        let mut other = definition.clone();
+        other.description = Some("description".to_string());
+        assert!(definition.same_schema_as(&other));
+        assert_eq!(definition.schema_diff(&other), None);
+
+        other.input_schema = serde_json::json!({
+            "type": "object",
+            "properties": {
+                "location": {"type": "object"},
+                "a/b": {"type": "string"},
+            },
+            "required": ["location", "a/b"],
+        });
+        assert!(!definition.same_schema_as(&other));
+        assert_eq!(
+            definition
+                .schema_diff(&other)
+                .unwrap(),
+            [
+                "changed `/properties/location/type`: \"string\" -> \"object\"",
+                "removed `/properties/unit`: {\"type\":\"string\"}",
+                "added `/properties/a~1b`: {\"type\":\"string\"}",
+                "changed `/required`: [\"location\"] -> [\"location\",\"a/b\"]",
+            ]
+            .join("\n")
+        );
+
+        other.input_schema = serde_json::Value::Null;
+        assert_eq!(
+            definition
+                .schema_diff(&other)
+                .unwrap()
+                .lines()
+                .count(),
+            1
+        );
+    }
+
     #[test]
     fn display_tool_definition() {
         let tool = ToolDefinition {
@@ -386,6 +1588,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn tool_use_predicates() {
+        struct TestTool {}
+
+        impl Tool for TestTool {
+            fn definition(&self) -> ToolDefinition {
+                ToolDefinition::new(
+                    "test_tool",
+                    None::<String>,
+                    serde_json::Value::Null,
+                )
+            }
+
+            fn call(
+                &self,
+                tool_use: ToolUse,
+            ) -> Result<ToolResult, ToolCallError> {
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    None::<String>,
+                ))
+            }
+        }
+
+        let tool_use = ToolUse::new(
+            "id",
+            "test_tool",
+            serde_json::Value::Null,
+        );
+        assert!(tool_use.is_named("test_tool"));
+        assert!(!tool_use.is_named("other_tool"));
+        assert!(tool_use.is_for(&TestTool {}));
+        assert!(tool_use.is_for_definition(&TestTool {}.definition()));
+
+        let tool_use = ToolUse::new(
+            "id",
+            "other_tool",
+            serde_json::Value::Null,
+        );
+        assert!(!tool_use.is_for(&TestTool {}));
+        assert!(!tool_use.is_for_definition(&TestTool {}.definition()));
+    }
+
+    #[test]
+    fn tool_use_params() {
+        let tool_use = ToolUse::new(
+            "id",
+            "name",
+            serde_json::json!({"b": 1, "a": null, "c": {"nested": true}}),
+        );
+        assert!(tool_use.has_param("a"));
+        assert!(tool_use.has_param("c"));
+        assert!(!tool_use.has_param("nested"));
+        assert_eq!(
+            tool_use.get_param("b"),
+            Some(&serde_json::json!(1))
+        );
+        assert_eq!(tool_use.param_count(), 3);
+        assert_eq!(tool_use.param_names(), vec!["a", "b", "c"]);
+
+        for input in [serde_json::Value::Null, serde_json::json!([1, 2])] {
+            let tool_use = ToolUse::new("id", "name", input);
+            assert!(!tool_use.has_param("a"));
+            assert_eq!(tool_use.param_count(), 0);
+            assert!(tool_use
+                .param_names()
+                .is_empty());
+        }
+    }
+
     #[test]
     fn default_tool_result() {
         let tool_result = ToolResult::default();
@@ -400,7 +1672,7 @@ mod tests {
     fn display_tool_result() {
         let tool_result = ToolResult {
             tool_use_id: "id".to_string(),
-            content: Some(TextContentBlock::new("text")),
+            content: Some(TextContentBlock::new("text").into()),
             is_error: None,
         };
         assert_eq!(
@@ -410,7 +1682,7 @@ mod tests {
 
         let tool_result = ToolResult {
             tool_use_id: "id".to_string(),
-            content: Some(TextContentBlock::new("text")),
+            content: Some(TextContentBlock::new("text").into()),
             is_error: Some(true),
         };
         assert_eq!(
@@ -423,7 +1695,7 @@ mod tests {
     fn serialize_tool_result() {
         let tool_result = ToolResult {
             tool_use_id: "id".to_string(),
-            content: Some(TextContentBlock::new("text")),
+            content: Some(TextContentBlock::new("text").into()),
             is_error: None,
         };
         assert_eq!(
@@ -433,7 +1705,7 @@ mod tests {
 
         let tool_result = ToolResult {
             tool_use_id: "id".to_string(),
-            content: Some(TextContentBlock::new("text")),
+            content: Some(TextContentBlock::new("text").into()),
             is_error: Some(true),
         };
         assert_eq!(
@@ -446,7 +1718,7 @@ mod tests {
     fn deserialize_tool_result() {
         let tool_result = ToolResult {
             tool_use_id: "id".to_string(),
-            content: Some(TextContentBlock::new("text")),
+            content: Some(TextContentBlock::new("text").into()),
             is_error: None,
         };
         assert_eq!(
@@ -459,7 +1731,7 @@ mod tests {
 
         let tool_result = ToolResult {
             tool_use_id: "id".to_string(),
-            content: Some(TextContentBlock::new("text")),
+            content: Some(TextContentBlock::new("text").into()),
             is_error: Some(true),
         };
         assert_eq!(
@@ -480,7 +1752,7 @@ mod tests {
         assert_eq!(tool_result.tool_use_id, "id");
         assert_eq!(
             tool_result.content,
-            Some(TextContentBlock::new("text"))
+            Some(TextContentBlock::new("text").into())
         );
         assert_eq!(tool_result.is_error, None);
 
@@ -496,7 +1768,7 @@ mod tests {
         assert_eq!(tool_result.tool_use_id, "id");
         assert_eq!(
             tool_result.content,
-            Some(TextContentBlock::new("text"))
+            Some(TextContentBlock::new("text").into())
         );
         assert_eq!(tool_result.is_error, Some(true));
 
@@ -558,11 +1830,8 @@ mod tests {
             "test_tool_use_id"
         );
         assert_eq!(
-            tool_result
-                .content
-                .unwrap()
-                .text,
-            "1"
+            tool_result.content,
+            Some(TextContentBlock::new("1").into())
         );
 
         let tool_use = ToolUse {
@@ -572,6 +1841,571 @@ mod tests {
         };
 
         let tool_result = tool_list.call(tool_use);
-        assert!(tool_result.is_err())
+        assert!(tool_result.is_err());
+
+        assert!(!tool_list.is_empty());
+        assert_eq!(tool_list.names(), vec!["test_tool".to_string()]);
+        assert_eq!(tool_list.iter().count(), 1);
+        assert!(tool_list
+            .find("test_tool")
+            .is_some());
+        assert!(tool_list
+            .find("test_tool_incorrect")
+            .is_none());
+        assert!(ToolList::new(vec![]).is_empty());
+    }
+
+    #[test]
+    fn tool_list_request_tools() {
+        let definition = ToolDefinition::new(
+            "test_tool",
+            None::<String>,
+            serde_json::Value::Null,
+        );
+
+        let mut tool_list = ToolList::new(vec![]);
+        tool_list.add_fn(
+            definition.clone(),
+            |tool_use: ToolUse| {
+                Ok(ToolResult::success_without_content(
+                    tool_use.id,
+                ))
+            },
+        );
+        assert_eq!(
+            tool_list.to_request_tools(),
+            vec![definition.clone()]
+        );
+
+        let mut request = MessagesRequestBody::default();
+        tool_list.extend_definitions_into(&mut request);
+        assert_eq!(
+            request.tools,
+            Some(vec![definition.clone()])
+        );
+
+        tool_list.extend_definitions_into(&mut request);
+        assert_eq!(
+            request.tools,
+            Some(vec![definition.clone(), definition])
+        );
+    }
+
+    #[test]
+    fn closure_tool() {
+        let definition = ToolDefinition::new(
+            "double",
+            Some("Doubles the argument."),
+            serde_json::json!({}),
+        );
+
+        let mut tool_list = ToolList::new(vec![]);
+        tool_list.add_fn(
+            definition.clone(),
+            |tool_use: ToolUse| {
+                let arg = tool_use.input["arg"]
+                    .as_i64()
+                    .ok_or_else(|| {
+                        ToolCallError::ParameterNotFound("arg".to_string())
+                    })?;
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    Some((arg * 2).to_string()),
+                ))
+            },
+        );
+        assert_eq!(tool_list.definitions(), vec![definition]);
+
+        let tool_result = tool_list
+            .call(ToolUse::new(
+                "id",
+                "double",
+                serde_json::json!({"arg": 21}),
+            ))
+            .unwrap();
+        assert_eq!(
+            tool_result,
+            ToolResult::success("id", Some("42"))
+        );
+
+        assert!(tool_list
+            .call(ToolUse::new(
+                "id",
+                "double",
+                serde_json::json!({}),
+            ))
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn async_closure_tool() {
+        let definition = ToolDefinition::new(
+            "double",
+            Some("Doubles the argument."),
+            serde_json::json!({}),
+        );
+
+        let mut tool_list = AsyncToolList::new();
+        assert!(tool_list.is_empty());
+        tool_list.add_async_fn(
+            definition.clone(),
+            |tool_use: ToolUse| async move {
+                let arg = tool_use.input["arg"]
+                    .as_i64()
+                    .unwrap_or_default();
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    Some((arg * 2).to_string()),
+                ))
+            },
+        );
+        assert_eq!(tool_list.definitions(), vec![definition]);
+
+        let tool_result = tool_list
+            .call(ToolUse::new(
+                "id",
+                "double",
+                serde_json::json!({"arg": 21}),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            tool_result,
+            ToolResult::success("id", Some("42"))
+        );
+
+        assert!(tool_list
+            .call(ToolUse::new(
+                "id",
+                "unknown",
+                serde_json::json!({}),
+            ))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn async_tool_list_from_tools() {
+        let tools = ["first", "second"]
+            .into_iter()
+            .map(|name| {
+                AsyncClosureTool::new(
+                    ToolDefinition::new(
+                        name,
+                        None::<String>,
+                        serde_json::json!({}),
+                    ),
+                    move |tool_use: ToolUse| async move {
+                        Ok(ToolResult::success(tool_use.id, Some(name)))
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let tool_list = AsyncToolList::from_tools(tools);
+        assert_eq!(
+            tool_list
+                .definitions()
+                .into_iter()
+                .map(|definition| definition.name)
+                .collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+        assert_eq!(
+            tool_list
+                .call(ToolUse::new(
+                    "id",
+                    "second",
+                    serde_json::json!({}),
+                ))
+                .await
+                .unwrap(),
+            ToolResult::success("id", Some("second"))
+        );
+        assert!(AsyncToolList::from_tools(Vec::<AsyncClosureTool>::new())
+            .is_empty());
+    }
+
+    fn tool_use_response() -> MessagesResponseBody {
+        MessagesResponseBody {
+            content: vec![
+                ContentBlock::from("text"),
+                ToolUse::new("first", "echo", serde_json::json!(1)).into(),
+                ToolUse::new("second", "unknown", serde_json::json!(2)).into(),
+                ToolUse::new("third", "echo", serde_json::json!(3)).into(),
+            ]
+            .into(),
+            ..Default::default()
+        }
+    }
+
+    fn echo_definition() -> ToolDefinition {
+        ToolDefinition::new(
+            "echo",
+            None::<String>,
+            serde_json::json!({}),
+        )
+    }
+
+    #[test]
+    fn call_all_requested() {
+        let mut tool_list = ToolList::new(vec![]);
+        tool_list.add_fn(
+            echo_definition(),
+            |tool_use: ToolUse| {
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    Some(tool_use.input.to_string()),
+                ))
+            },
+        );
+
+        let results = tool_list.call_all_requested(&tool_use_response());
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &ToolResult::success("first", Some("1"))
+        );
+        assert!(matches!(
+            &results[1],
+            Err(ToolCallError::ToolNotFound(name)) if name == "unknown"
+        ));
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &ToolResult::success("third", Some("3"))
+        );
+
+        assert!(tool_list
+            .call_all_requested(&MessagesResponseBody::default())
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn async_call_all_requested() {
+        let mut tool_list = AsyncToolList::new();
+        tool_list.add_async_fn(
+            echo_definition(),
+            |tool_use: ToolUse| async move {
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    Some(tool_use.input.to_string()),
+                ))
+            },
+        );
+
+        let results = tool_list
+            .call_all_requested(&tool_use_response())
+            .await;
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &ToolResult::success("first", Some("1"))
+        );
+        assert!(results[1].is_err());
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &ToolResult::success("third", Some("3"))
+        );
+    }
+
+    #[cfg(feature = "parallel-tools")]
+    #[tokio::test]
+    async fn call_all_parallel() {
+        let mut tool_list = AsyncToolList::new();
+        tool_list.add_async_fn(
+            ToolDefinition::new(
+                "sleep",
+                None::<String>,
+                serde_json::json!({}),
+            ),
+            |tool_use: ToolUse| async move {
+                let millis = tool_use.input["millis"]
+                    .as_u64()
+                    .unwrap_or_default();
+                tokio::time::sleep(std::time::Duration::from_millis(millis))
+                    .await;
+                Ok(ToolResult::success(
+                    tool_use.id,
+                    Some(millis.to_string()),
+                ))
+            },
+        );
+        tool_list.add_async_fn(
+            ToolDefinition::new(
+                "panic",
+                None::<String>,
+                serde_json::json!({}),
+            ),
+            |_: ToolUse| async move { panic!("tool panicked") },
+        );
+
+        let results = tool_list
+            .call_all_parallel(vec![
+                ToolUse::new(
+                    "first",
+                    "sleep",
+                    serde_json::json!({"millis": 30}),
+                ),
+                ToolUse::new("second", "unknown", serde_json::json!({})),
+                ToolUse::new("third", "panic", serde_json::json!({})),
+                ToolUse::new(
+                    "fourth",
+                    "sleep",
+                    serde_json::json!({"millis": 0}),
+                ),
+            ])
+            .await;
+
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &ToolResult::success("first", Some("30"))
+        );
+        assert!(matches!(
+            &results[1],
+            Err(ToolCallError::ToolNotFound(name)) if name == "unknown"
+        ));
+        assert!(matches!(
+            &results[2],
+            Err(ToolCallError::ExecutionFailed(message))
+                if message == "The task of the tool panicked: tool panicked"
+        ));
+        assert_eq!(
+            results[3].as_ref().unwrap(),
+            &ToolResult::success("fourth", Some("0"))
+        );
+    }
+
+    #[test]
+    fn structured_tool_result() {
+        let value =
+            serde_json::json!({"key": [1, 2], "nested": {"flag": true}});
+        let result = ToolResult::success_with_json("id", value.clone());
+        assert_eq!(
+            result,
+            ToolResult::success(
+                "id",
+                Some(serde_json::to_string_pretty(&value).unwrap())
+            )
+        );
+        assert_eq!(result.is_error, None);
+        assert_eq!(result.structured_content(), Some(value.clone()));
+
+        let result = ToolResult {
+            tool_use_id: "id".to_string(),
+            content: Some(ToolResultContent::MultipleBlocks(vec![
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Png,
+                    "base64",
+                )),
+                ContentBlock::from(value.to_string()),
+            ])),
+            is_error: None,
+        };
+        assert_eq!(result.structured_content(), Some(value));
+
+        assert_eq!(
+            ToolResult::success("id", Some("not json")).structured_content(),
+            None
+        );
+        assert_eq!(
+            ToolResult::success_without_content("id").structured_content(),
+            None
+        );
+        assert_eq!(
+            ToolResult {
+                tool_use_id: "id".to_string(),
+                content: Some(ToolResultContent::MultipleBlocks(vec![
+                    ContentBlock::from("1"),
+                    ContentBlock::from("2"),
+                ])),
+                is_error: None,
+            }
+            .structured_content(),
+            None
+        );
+    }
+
+    #[test]
+    fn tool_result_predicates() {
+        let result = ToolResult::success("id", Some("text"));
+        assert!(result.is_success());
+        assert!(!result.is_error());
+        assert_eq!(result.content_text(), Some("text"));
+
+        let result = ToolResult::error("id", Some("error"));
+        assert!(result.is_error());
+        assert!(!result.is_success());
+        assert_eq!(result.content_text(), Some("error"));
+
+        let result = ToolResult {
+            tool_use_id: "id".to_string(),
+            content: Some(ToolResultContent::MultipleBlocks(vec![
+                ContentBlock::from(ImageContentSource::base64(
+                    ImageMediaType::Png,
+                    "base64",
+                )),
+                ContentBlock::from("text"),
+            ])),
+            is_error: Some(false),
+        };
+        assert!(result.is_success());
+        assert_eq!(result.content_text(), Some("text"));
+
+        let result = ToolResult {
+            tool_use_id: "id".to_string(),
+            content: Some(ToolResultContent::MultipleBlocks(vec![
+                ContentBlock::from("1"),
+                ContentBlock::from("2"),
+            ])),
+            is_error: None,
+        };
+        assert!(result.is_success());
+        assert_eq!(result.content_text(), None);
+
+        assert_eq!(
+            ToolResult::success_without_content("id").content_text(),
+            None
+        );
+    }
+
+    #[test]
+    fn text_tool_result() {
+        assert_eq!(
+            ToolResult::with_text_result("id", "result"),
+            ToolResult {
+                tool_use_id: "id".to_string(),
+                content: Some(TextContentBlock::new("result").into()),
+                is_error: None,
+            }
+        );
+        assert_eq!(
+            ToolResult::with_text_error("id", "error".to_string()),
+            ToolResult {
+                tool_use_id: "id".to_string(),
+                content: Some(TextContentBlock::new("error").into()),
+                is_error: Some(true),
+            }
+        );
+        assert_eq!(
+            ToolResult::from(("id", "result")),
+            ToolResult::with_text_result("id", "result")
+        );
+    }
+
+    #[test]
+    fn serialize_tool_result_content() {
+        let tool_result = ToolResult {
+            tool_use_id: "id".to_string(),
+            content: Some(
+                vec![
+                    ContentBlock::from("text"),
+                    ContentBlock::from("text"),
+                ]
+                .into(),
+            ),
+            is_error: None,
+        };
+        let json = r#"{"tool_use_id":"id","content":[{"type":"text","text":"text"},{"type":"text","text":"text"}]}"#;
+
+        assert_eq!(
+            serde_json::to_string(&tool_result).unwrap(),
+            json
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolResult>(json).unwrap(),
+            tool_result
+        );
+    }
+
+    #[test]
+    fn deserialize_tool_result_content() {
+        let text = ToolResultContent::SingleText(TextContentBlock::new("text"));
+        assert_eq!(
+            serde_json::from_str::<ToolResultContent>(r#""text""#).unwrap(),
+            text
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolResultContent>(
+                r#"{"type":"text","text":"text"}"#
+            )
+            .unwrap(),
+            text
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolResultContent>(
+                r#"[{"type":"text","text":"text"}]"#
+            )
+            .unwrap(),
+            ToolResultContent::MultipleBlocks(vec![ContentBlock::from(
+                "text"
+            )])
+        );
+        assert!(serde_json::from_str::<ToolResultContent>("1").is_err());
+
+        assert_eq!(
+            serde_json::from_str::<ToolResult>(
+                r#"{"tool_use_id":"id","content":"text"}"#
+            )
+            .unwrap(),
+            ToolResult::success("id", Some("text"))
+        );
+    }
+
+    #[test]
+    fn append_content() {
+        let mut tool_result = ToolResult::success_without_content("id");
+        tool_result.append_content(ContentBlock::from("first"));
+        assert_eq!(
+            tool_result.content,
+            Some(ToolResultContent::MultipleBlocks(vec![
+                ContentBlock::from("first")
+            ]))
+        );
+
+        let mut tool_result = ToolResult::success("id", Some("first"));
+        tool_result.append_content(ContentBlock::from("second"));
+        assert_eq!(
+            tool_result.content,
+            Some(ToolResultContent::MultipleBlocks(vec![
+                ContentBlock::from("first"),
+                ContentBlock::from("second"),
+            ]))
+        );
+    }
+
+    #[test]
+    fn merge_tool_result() {
+        let merged = ToolResult::success("id", Some("first"))
+            .merge(ToolResult::error("id", Some("second")))
+            .unwrap();
+        assert_eq!(merged.tool_use_id, "id");
+        assert_eq!(
+            merged.content,
+            Some(ToolResultContent::MultipleBlocks(vec![
+                ContentBlock::from("first"),
+                ContentBlock::from("second"),
+            ]))
+        );
+        assert_eq!(merged.is_error, Some(true));
+
+        let merged = ToolResult::success("id", Some("first"))
+            .merge(ToolResult::success_without_content("id"))
+            .unwrap();
+        assert_eq!(
+            merged.content,
+            Some(TextContentBlock::new("first").into())
+        );
+        assert_eq!(merged.is_error, None);
+
+        assert_eq!(
+            ToolResult::success_without_content("id")
+                .merge(ToolResult::success_without_content("other"))
+                .unwrap_err(),
+            ToolMergeError::ToolUseIdMismatch {
+                expected: "id".to_string(),
+                actual: "other".to_string(),
+            }
+        );
     }
 }
@@ -0,0 +1,147 @@
+use std::fmt::Display;
+
+/// How the model should use the provided tools.
+///
+/// See also [the tool use guide](https://docs.anthropic.com/en/docs/build-with-claude/tool-use#forcing-tool-use).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub enum ToolChoice {
+    /// The model will automatically decide whether to use tools.
+    #[default]
+    Auto,
+    /// The model will use any available tools.
+    Any,
+    /// The model will use the specified tool by the name.
+    Tool(String),
+}
+
+impl Display for ToolChoice {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self {
+            | ToolChoice::Auto => write!(f, "auto"),
+            | ToolChoice::Any => write!(f, "any"),
+            | ToolChoice::Tool(name) => write!(f, "tool({})", name),
+        }
+    }
+}
+
+/// The serialized representation of `ToolChoice`.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ToolChoiceObject {
+    #[serde(rename = "type")]
+    _type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl serde::Serialize for ToolChoice {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let object = match self {
+            | ToolChoice::Auto => ToolChoiceObject {
+                _type: "auto".to_string(),
+                name: None,
+            },
+            | ToolChoice::Any => ToolChoiceObject {
+                _type: "any".to_string(),
+                name: None,
+            },
+            | ToolChoice::Tool(name) => ToolChoiceObject {
+                _type: "tool".to_string(),
+                name: Some(name.clone()),
+            },
+        };
+
+        object.serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for ToolChoice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let object = ToolChoiceObject::deserialize(deserializer)?;
+
+        match (object._type.as_str(), object.name) {
+            | ("auto", _) => Ok(ToolChoice::Auto),
+            | ("any", _) => Ok(ToolChoice::Any),
+            | ("tool", Some(name)) => Ok(ToolChoice::Tool(name)),
+            | ("tool", None) => Err(serde::de::Error::missing_field("name")),
+            | (_type, _) => Err(serde::de::Error::unknown_variant(
+                _type,
+                &["auto", "any", "tool"],
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default() {
+        assert_eq!(ToolChoice::default(), ToolChoice::Auto);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ToolChoice::Auto.to_string(), "auto");
+        assert_eq!(ToolChoice::Any.to_string(), "any");
+        assert_eq!(
+            ToolChoice::Tool("get_weather".to_string()).to_string(),
+            "tool(get_weather)"
+        );
+    }
+
+    #[test]
+    fn serialize() {
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Auto).unwrap(),
+            r#"{"type":"auto"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Any).unwrap(),
+            r#"{"type":"any"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&ToolChoice::Tool(
+                "get_weather".to_string()
+            ))
+            .unwrap(),
+            r#"{"type":"tool","name":"get_weather"}"#
+        );
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(
+            serde_json::from_str::<ToolChoice>(r#"{"type":"auto"}"#).unwrap(),
+            ToolChoice::Auto
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolChoice>(r#"{"type":"any"}"#).unwrap(),
+            ToolChoice::Any
+        );
+        assert_eq!(
+            serde_json::from_str::<ToolChoice>(
+                r#"{"type":"tool","name":"get_weather"}"#
+            )
+            .unwrap(),
+            ToolChoice::Tool("get_weather".to_string())
+        );
+        assert!(serde_json::from_str::<ToolChoice>(r#"{"type":"tool"}"#)
+            .is_err());
+        assert!(
+            serde_json::from_str::<ToolChoice>(r#"{"type":"none"}"#).is_err()
+        );
+    }
+}
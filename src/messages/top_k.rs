@@ -46,6 +46,11 @@ impl TopK {
             value,
         }
     }
+
+    /// The value of the top_k.
+    pub fn as_u32(&self) -> u32 {
+        self.value
+    }
 }
 
 #[cfg(test)]
@@ -63,6 +68,12 @@ mod tests {
         assert_eq!(TopK::default().value, 50);
     }
 
+    #[test]
+    fn as_u32() {
+        let top_k = TopK::new(50);
+        assert_eq!(top_k.as_u32(), 50);
+    }
+
     #[test]
     fn display() {
         let top_k = TopK::new(50);
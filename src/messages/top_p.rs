@@ -1,5 +1,8 @@
 use crate::ValidationError;
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+use std::ops::{Add, Mul};
 
 /// Use nucleus sampling.
 ///
@@ -11,11 +14,10 @@ use std::fmt::Display;
     Clone,
     Copy,
     PartialEq,
-    PartialOrd,
     serde::Serialize,
     serde::Deserialize,
 )]
-#[serde(transparent)]
+#[serde(try_from = "f32", into = "f32")]
 pub struct TopP {
     value: f32,
 }
@@ -46,7 +48,7 @@ impl TopP {
     /// ## Errors
     /// It returns a validation error if the value is not in range: `[0.0, 1.0]`.
     pub fn new(value: f32) -> Result<Self, ValidationError<f32>> {
-        if value < 0.0 || value > 1.0 {
+        if !(0.0..=1.0).contains(&value) {
             return Err(ValidationError {
                 _type: "TopP".to_string(),
                 expected: "The top_p must be in range: [0.0, 1.0].".to_string(),
@@ -58,6 +60,87 @@ impl TopP {
             value,
         })
     }
+
+    /// The value of the top_p.
+    pub fn as_f32(&self) -> f32 {
+        self.value
+    }
+}
+
+// NaN is rejected by the validation including deserialization, so the value is always comparable.
+impl Eq for TopP {}
+
+impl Hash for TopP {
+    fn hash<H: Hasher>(
+        &self,
+        state: &mut H,
+    ) {
+        // Normalizes -0.0 to 0.0 to be consistent with `PartialEq`.
+        let value = if self.value == 0.0 {
+            0.0f32
+        } else {
+            self.value
+        };
+        value.to_bits().hash(state);
+    }
+}
+
+impl PartialOrd for TopP {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TopP {
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering {
+        self.value
+            .partial_cmp(&other.value)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl TryFrom<f32> for TopP {
+    type Error = ValidationError<f32>;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<TopP> for f32 {
+    fn from(value: TopP) -> Self {
+        value.value
+    }
+}
+
+impl Mul<f32> for TopP {
+    /// `None` if the result is out of range.
+    type Output = Option<TopP>;
+
+    fn mul(
+        self,
+        rhs: f32,
+    ) -> Self::Output {
+        Self::new(self.value * rhs).ok()
+    }
+}
+
+impl Add<TopP> for TopP {
+    /// `None` if the result is out of range.
+    type Output = Option<TopP>;
+
+    fn add(
+        self,
+        rhs: TopP,
+    ) -> Self::Output {
+        Self::new(self.value + rhs.value).ok()
+    }
 }
 
 #[cfg(test)]
@@ -77,6 +160,61 @@ mod tests {
         assert!(TopP::new(0.5).is_ok());
         assert!(TopP::new(1.0).is_ok());
         assert!(TopP::new(1.1).is_err());
+        assert!(TopP::new(f32::NAN).is_err());
+        assert!(TopP::new(f32::INFINITY).is_err());
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashMap;
+
+        fn hash_of(value: TopP) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let zero = TopP::new(0.0).unwrap();
+        let negative_zero = TopP::new(-0.0).unwrap();
+        assert_eq!(zero, negative_zero);
+        assert_eq!(hash_of(zero), hash_of(negative_zero));
+
+        let one = TopP::new(1.0).unwrap();
+        let below_one = TopP::new(1.0 - f32::EPSILON).unwrap();
+        assert_ne!(one, below_one);
+        assert_ne!(hash_of(one), hash_of(below_one));
+
+        let mut map = HashMap::new();
+        map.insert(zero, "zero");
+        map.insert(one, "one");
+        assert_eq!(map.get(&negative_zero), Some(&"zero"));
+        assert_eq!(map.get(&TopP::new(1.0).unwrap()), Some(&"one"));
+        assert_eq!(map.get(&below_one), None);
+    }
+
+    #[test]
+    fn ord() {
+        use std::collections::BTreeMap;
+
+        let zero = TopP::new(0.0).unwrap();
+        let half = TopP::new(0.5).unwrap();
+        let one = TopP::new(1.0).unwrap();
+        assert!(zero < half && half < one);
+        assert_eq!(
+            zero.cmp(&TopP::new(-0.0).unwrap()),
+            Ordering::Equal
+        );
+
+        let map = BTreeMap::from([
+            (one, "one"),
+            (zero, "zero"),
+            (half, "half"),
+        ]);
+        assert_eq!(
+            map.into_values().collect::<Vec<_>>(),
+            vec!["zero", "half", "one"]
+        );
     }
 
     #[test]
@@ -84,6 +222,12 @@ mod tests {
         assert_eq!(TopP::default().value, 1.0);
     }
 
+    #[test]
+    fn as_f32() {
+        let top_p = TopP::new(0.5).unwrap();
+        assert_eq!(top_p.as_f32(), 0.5);
+    }
+
     #[test]
     fn display() {
         let top_p = TopP::new(1.0);
@@ -93,6 +237,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn arithmetic() {
+        let half = TopP::try_from(0.5).unwrap();
+        assert_eq!(half * 0.5, Some(TopP::new(0.25).unwrap()));
+        assert_eq!(half * 3.0, None);
+        assert_eq!(half * -1.0, None);
+        assert_eq!(half + half, Some(TopP::new(1.0).unwrap()));
+        assert_eq!(half + TopP::default(), None);
+        assert!(TopP::try_from(1.5).is_err());
+    }
+
     #[test]
     fn serialize() {
         let top_p = TopP::new(1.0);
@@ -109,5 +264,7 @@ mod tests {
             serde_json::from_str::<TopP>("1.0").unwrap(),
             top_p.unwrap()
         );
+        assert!(serde_json::from_str::<TopP>("1.5").is_err());
+        assert!(serde_json::from_str::<TopP>("-0.1").is_err());
     }
 }
@@ -1,4 +1,5 @@
 use crate::macros::impl_display_for_serialize;
+use crate::messages::ClaudeModel;
 
 /// Billing and rate-limit usage.
 ///
@@ -23,10 +24,198 @@ pub struct Usage {
     pub input_tokens: u32,
     /// The number of output tokens which were used.
     pub output_tokens: u32,
+    /// The number of input tokens used to create the cache entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_creation_input_tokens: Option<u32>,
+    /// The number of input tokens read from the cache.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_input_tokens: Option<u32>,
 }
 
 impl_display_for_serialize!(Usage);
 
+impl Usage {
+    /// The number of input tokens billed at the full input price or higher.
+    ///
+    /// It is the sum of `input_tokens` and `cache_creation_input_tokens`.
+    pub fn effective_input_tokens(&self) -> u32 {
+        self.input_tokens
+            + self
+                .cache_creation_input_tokens
+                .unwrap_or(0)
+    }
+
+    /// The number of input tokens read from the cache at the discounted price.
+    pub fn cache_savings_tokens(&self) -> u32 {
+        self.cache_read_input_tokens
+            .unwrap_or(0)
+    }
+
+    /// Estimates the cost in US dollars with the cache prices of the model.
+    ///
+    /// The cache write costs 1.25 times the input price and the cache read costs 0.1 times the input price.
+    /// This estimation is based on the public pricing, see [pricing](https://www.anthropic.com/pricing#anthropic-api).
+    ///
+    /// ## Arguments
+    /// - `model` - The model used to generate the response.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::{ClaudeModel, Usage};
+    ///
+    /// let usage = Usage {
+    ///     input_tokens: 1_000_000,
+    ///     output_tokens: 0,
+    ///     cache_creation_input_tokens: None,
+    ///     cache_read_input_tokens: Some(1_000_000),
+    /// };
+    /// let cost = usage
+    ///     .estimated_cost_with_caching(ClaudeModel::Claude3Haiku20240307)
+    ///     .unwrap();
+    /// assert!((cost - 0.275).abs() < 1e-9);
+    /// ```
+    pub fn estimated_cost_with_caching(
+        &self,
+        model: ClaudeModel,
+    ) -> Option<f64> {
+        let (input_price, output_price) = price_per_million_tokens(model)?;
+        let cache_creation_input_tokens = self
+            .cache_creation_input_tokens
+            .unwrap_or(0);
+
+        let cost = self.input_tokens as f64 * input_price
+            + cache_creation_input_tokens as f64 * input_price * 1.25
+            + self.cache_savings_tokens() as f64 * input_price * 0.1
+            + self.output_tokens as f64 * output_price;
+
+        Some(cost / 1_000_000.0)
+    }
+
+    /// Subtracts the other usage from this usage field by field, saturating at zero.
+    ///
+    /// It is useful to get the incremental usage of a call from the total usages before and after the call.
+    /// The cache fields are `None` only if both are `None`, otherwise `None` is treated as zero.
+    ///
+    /// ## Arguments
+    /// - `other` - The usage to subtract.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::messages::Usage;
+    ///
+    /// let before = Usage {
+    ///     input_tokens: 10,
+    ///     output_tokens: 20,
+    ///     ..Default::default()
+    /// };
+    /// let after = Usage {
+    ///     input_tokens: 15,
+    ///     output_tokens: 30,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(
+    ///     after.saturating_sub(&before),
+    ///     Usage {
+    ///         input_tokens: 5,
+    ///         output_tokens: 10,
+    ///         ..Default::default()
+    ///     }
+    /// );
+    /// assert_eq!(before.saturating_sub(&after), Usage::default());
+    /// ```
+    pub fn saturating_sub(
+        &self,
+        other: &Usage,
+    ) -> Usage {
+        let sub = |a: u32, b: u32| Some(a.saturating_sub(b));
+
+        Usage {
+            input_tokens: self
+                .input_tokens
+                .saturating_sub(other.input_tokens),
+            output_tokens: self
+                .output_tokens
+                .saturating_sub(other.output_tokens),
+            cache_creation_input_tokens: sub_optional_tokens(
+                self.cache_creation_input_tokens,
+                other.cache_creation_input_tokens,
+                sub,
+            )
+            .unwrap_or_default(),
+            cache_read_input_tokens: sub_optional_tokens(
+                self.cache_read_input_tokens,
+                other.cache_read_input_tokens,
+                sub,
+            )
+            .unwrap_or_default(),
+        }
+    }
+
+    /// Subtracts the other usage from this usage field by field.
+    ///
+    /// The cache fields are `None` only if both are `None`, otherwise `None` is treated as zero.
+    /// It returns `None` if any field underflows.
+    ///
+    /// ## Arguments
+    /// - `other` - The usage to subtract.
+    pub fn checked_sub(
+        &self,
+        other: &Usage,
+    ) -> Option<Usage> {
+        let sub = |a: u32, b: u32| a.checked_sub(b);
+
+        Some(Usage {
+            input_tokens: self
+                .input_tokens
+                .checked_sub(other.input_tokens)?,
+            output_tokens: self
+                .output_tokens
+                .checked_sub(other.output_tokens)?,
+            cache_creation_input_tokens: sub_optional_tokens(
+                self.cache_creation_input_tokens,
+                other.cache_creation_input_tokens,
+                sub,
+            )?,
+            cache_read_input_tokens: sub_optional_tokens(
+                self.cache_read_input_tokens,
+                other.cache_read_input_tokens,
+                sub,
+            )?,
+        })
+    }
+}
+
+/// Subtracts the optional token counts treating `None` as zero unless both are `None`.
+///
+/// It returns `None` if the subtraction fails.
+fn sub_optional_tokens(
+    tokens: Option<u32>,
+    other: Option<u32>,
+    sub: impl Fn(u32, u32) -> Option<u32>,
+) -> Option<Option<u32>> {
+    match (tokens, other) {
+        | (None, None) => Some(None),
+        | (tokens, other) => sub(
+            tokens.unwrap_or(0),
+            other.unwrap_or(0),
+        )
+        .map(Some),
+    }
+}
+
+/// The input and output prices in US dollars per million tokens of the model.
+#[allow(deprecated)]
+fn price_per_million_tokens(model: ClaudeModel) -> Option<(f64, f64)> {
+    match model {
+        | ClaudeModel::Claude3Opus20240229 => Some((15.0, 75.0)),
+        | ClaudeModel::Claude3Sonnet20240229 => Some((3.0, 15.0)),
+        | ClaudeModel::Claude3Haiku20240307 => Some((0.25, 1.25)),
+        | ClaudeModel::Claude35Sonnet20240620 => Some((3.0, 15.0)),
+        | ClaudeModel::Claude35Sonnet20241022 => Some((3.0, 15.0)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,6 +225,7 @@ mod tests {
         let usage = Usage {
             input_tokens: 1,
             output_tokens: 2,
+            ..Default::default()
         };
         assert_eq!(
             serde_json::to_string(&usage).unwrap(),
@@ -48,6 +238,7 @@ mod tests {
         let usage = Usage {
             input_tokens: 1,
             output_tokens: 2,
+            ..Default::default()
         };
         assert_eq!(
             serde_json::from_str::<Usage>(
@@ -57,4 +248,124 @@ mod tests {
             usage
         );
     }
+
+    #[test]
+    fn deserialize_with_cache() {
+        assert_eq!(
+            serde_json::from_str::<Usage>(
+                r#"{"input_tokens":1,"output_tokens":2,"cache_creation_input_tokens":3,"cache_read_input_tokens":4}"#
+            )
+            .unwrap(),
+            Usage {
+                input_tokens: 1,
+                output_tokens: 2,
+                cache_creation_input_tokens: Some(3),
+                cache_read_input_tokens: Some(4),
+            }
+        );
+    }
+
+    #[test]
+    fn estimated_cost_with_caching() {
+        let usage = Usage {
+            input_tokens: 1_000_000,
+            output_tokens: 1_000_000,
+            cache_creation_input_tokens: Some(1_000_000),
+            cache_read_input_tokens: Some(1_000_000),
+        };
+        assert_eq!(usage.effective_input_tokens(), 2_000_000);
+        assert_eq!(usage.cache_savings_tokens(), 1_000_000);
+
+        let cost = usage
+            .estimated_cost_with_caching(ClaudeModel::Claude35Sonnet20241022)
+            .unwrap();
+        assert!((cost - (3.0 + 3.75 + 0.3 + 15.0)).abs() < 1e-9);
+
+        let usage = Usage {
+            input_tokens: 1,
+            output_tokens: 2,
+            ..Default::default()
+        };
+        assert_eq!(usage.effective_input_tokens(), 1);
+        assert_eq!(usage.cache_savings_tokens(), 0);
+    }
+
+    #[test]
+    fn saturating_sub() {
+        let before = Usage {
+            input_tokens: 10,
+            output_tokens: 20,
+            cache_creation_input_tokens: Some(5),
+            cache_read_input_tokens: None,
+        };
+        let after = Usage {
+            input_tokens: 15,
+            output_tokens: 30,
+            cache_creation_input_tokens: Some(5),
+            cache_read_input_tokens: Some(8),
+        };
+
+        assert_eq!(
+            after.saturating_sub(&before),
+            Usage {
+                input_tokens: 5,
+                output_tokens: 10,
+                cache_creation_input_tokens: Some(0),
+                cache_read_input_tokens: Some(8),
+            }
+        );
+        assert_eq!(
+            before.saturating_sub(&after),
+            Usage {
+                input_tokens: 0,
+                output_tokens: 0,
+                cache_creation_input_tokens: Some(0),
+                cache_read_input_tokens: Some(0),
+            }
+        );
+        assert_eq!(
+            Usage::default().saturating_sub(&Usage::default()),
+            Usage::default()
+        );
+    }
+
+    #[test]
+    fn checked_sub() {
+        let before = Usage {
+            input_tokens: 10,
+            output_tokens: 20,
+            cache_creation_input_tokens: Some(5),
+            cache_read_input_tokens: None,
+        };
+        let after = Usage {
+            input_tokens: 15,
+            output_tokens: 30,
+            cache_creation_input_tokens: Some(5),
+            cache_read_input_tokens: Some(8),
+        };
+
+        assert_eq!(
+            after.checked_sub(&before),
+            Some(Usage {
+                input_tokens: 5,
+                output_tokens: 10,
+                cache_creation_input_tokens: Some(0),
+                cache_read_input_tokens: Some(8),
+            })
+        );
+        assert_eq!(before.checked_sub(&after), None);
+
+        let cache_read_only = Usage {
+            cache_read_input_tokens: Some(1),
+            ..Default::default()
+        };
+        assert_eq!(
+            Usage::default().checked_sub(&cache_read_only),
+            None
+        );
+        assert_eq!(
+            Usage::default().checked_sub(&Usage::default()),
+            Some(Usage::default())
+        );
+    }
 }
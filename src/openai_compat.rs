@@ -0,0 +1,371 @@
+//! Minimal conversion between the OpenAI Chat Completions format and the Anthropic Messages API format.
+//!
+//! This module is available with the `openai-compat` feature.
+//!
+//! ## Limitations
+//! - Only text content of messages is supported.
+//! - The `system` role messages are concatenated into a single `SystemPrompt` regardless of their positions.
+//! - Roles other than `system`, `user` and `assistant` (e.g. `tool`) are not supported.
+//! - The model name is mapped on a best-effort basis from similar OpenAI models.
+//! - The ranges of `temperature` and `top_p` are not converted and validated as the Anthropic API.
+//! - Tool use blocks in the response are dropped and only text blocks are concatenated.
+
+use crate::messages::{
+    ClaudeModel, MaxTokens, Message, MessagesRequestBody,
+    MessagesResponseBody, Role, StopReason, StopSequence, SystemPrompt,
+    Temperature, TopP,
+};
+use crate::OpenAiConversionError;
+
+/// A message of the OpenAI Chat Completions API.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct OpenAiChatMessage {
+    /// The role of the message: `system`, `user` or `assistant`.
+    pub role: String,
+    /// The text content of the message.
+    pub content: String,
+}
+
+/// A request body of the OpenAI Chat Completions API.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct OpenAiChatRequest {
+    /// The model name.
+    pub model: String,
+    /// The messages of the conversation.
+    pub messages: Vec<OpenAiChatMessage>,
+    /// The maximum number of tokens to generate.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    /// The sampling temperature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// The nucleus sampling probability.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// The stop sequences.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+}
+
+/// A choice of the OpenAI Chat Completions API response.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct OpenAiChatChoice {
+    /// The index of the choice.
+    pub index: u32,
+    /// The generated message.
+    pub message: OpenAiChatMessage,
+    /// The reason that the generation stopped.
+    pub finish_reason: Option<String>,
+}
+
+/// A usage of the OpenAI Chat Completions API response.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct OpenAiUsage {
+    /// The number of tokens in the prompt.
+    pub prompt_tokens: u32,
+    /// The number of tokens in the completion.
+    pub completion_tokens: u32,
+    /// The total number of tokens.
+    pub total_tokens: u32,
+}
+
+/// A response body of the OpenAI Chat Completions API.
+#[derive(
+    Debug, Clone, PartialEq, Default, serde::Serialize, serde::Deserialize,
+)]
+pub struct OpenAiChatResponse {
+    /// The ID of the response.
+    pub id: String,
+    /// The object type. It is always `chat.completion`.
+    pub object: String,
+    /// The model name.
+    pub model: String,
+    /// The choices of the response.
+    pub choices: Vec<OpenAiChatChoice>,
+    /// The usage of the response.
+    pub usage: OpenAiUsage,
+}
+
+impl TryFrom<OpenAiChatRequest> for MessagesRequestBody {
+    type Error = OpenAiConversionError;
+
+    fn try_from(request: OpenAiChatRequest) -> Result<Self, Self::Error> {
+        let model = map_model(&request.model)?;
+
+        let mut system_prompts = Vec::new();
+        let mut messages = Vec::new();
+        for message in request.messages {
+            match message.role.as_str() {
+                | "system" => system_prompts.push(message.content),
+                | "user" => messages.push(Message::new(
+                    Role::User,
+                    message.content,
+                )),
+                | "assistant" => messages.push(Message::new(
+                    Role::Assistant,
+                    message.content,
+                )),
+                | _ => {
+                    return Err(OpenAiConversionError::UnsupportedRole(
+                        message.role,
+                    ))
+                },
+            }
+        }
+
+        let system = if system_prompts.is_empty() {
+            None
+        } else {
+            Some(SystemPrompt::new(
+                system_prompts.join("\n\n"),
+            ))
+        };
+
+        let max_tokens = match request.max_tokens {
+            | Some(max_tokens) => MaxTokens::new(max_tokens, model)?,
+            | None => MaxTokens::from_model(model),
+        };
+
+        Ok(MessagesRequestBody {
+            model,
+            messages,
+            system,
+            max_tokens,
+            stop_sequences: request.stop.map(|stop| {
+                stop.into_iter()
+                    .map(StopSequence::new)
+                    .collect()
+            }),
+            temperature: request
+                .temperature
+                .map(Temperature::new)
+                .transpose()?,
+            top_p: request
+                .top_p
+                .map(TopP::new)
+                .transpose()?,
+            ..Default::default()
+        })
+    }
+}
+
+impl From<MessagesResponseBody> for OpenAiChatResponse {
+    fn from(response: MessagesResponseBody) -> Self {
+        let content = response
+            .content
+            .into_partition()
+            .texts
+            .into_iter()
+            .map(|text| text.text)
+            .collect::<Vec<_>>()
+            .join("");
+
+        let finish_reason = response
+            .stop_reason
+            .map(|stop_reason| match stop_reason {
+                | StopReason::EndTurn | StopReason::StopSequence => "stop",
+                | StopReason::MaxTokens => "length",
+                | StopReason::ToolUse => "tool_calls",
+            })
+            .map(str::to_string);
+
+        Self {
+            id: response.id,
+            object: "chat.completion".to_string(),
+            model: response.model.to_string(),
+            choices: vec![OpenAiChatChoice {
+                index: 0,
+                message: OpenAiChatMessage {
+                    role: "assistant".to_string(),
+                    content,
+                },
+                finish_reason,
+            }],
+            usage: OpenAiUsage {
+                prompt_tokens: response.usage.input_tokens,
+                completion_tokens: response.usage.output_tokens,
+                total_tokens: response
+                    .usage
+                    .input_tokens
+                    .saturating_add(response.usage.output_tokens),
+            },
+        }
+    }
+}
+
+/// Maps the model name into a Claude model on a best-effort basis.
+///
+/// Claude model names are used as is.
+fn map_model(model: &str) -> Result<ClaudeModel, OpenAiConversionError> {
+    if let Ok(model) = serde_json::from_value::<ClaudeModel>(
+        serde_json::Value::String(model.to_string()),
+    ) {
+        return Ok(model);
+    }
+
+    if model.starts_with("gpt-4o-mini") || model.starts_with("gpt-3.5") {
+        Ok(ClaudeModel::Claude3Haiku20240307)
+    } else if model.starts_with("gpt-4") {
+        Ok(ClaudeModel::Claude35Sonnet20241022)
+    } else {
+        Err(OpenAiConversionError::UnsupportedModel(
+            model.to_string(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{Content, ContentBlock, ToolUse, Usage};
+
+    #[test]
+    fn try_from_request() {
+        let request = OpenAiChatRequest {
+            model: "gpt-4o".to_string(),
+            messages: vec![
+                OpenAiChatMessage {
+                    role: "system".to_string(),
+                    content: "system-prompt".to_string(),
+                },
+                OpenAiChatMessage {
+                    role: "user".to_string(),
+                    content: "user-message".to_string(),
+                },
+                OpenAiChatMessage {
+                    role: "assistant".to_string(),
+                    content: "assistant-message".to_string(),
+                },
+            ],
+            max_tokens: Some(1024),
+            temperature: Some(0.5),
+            top_p: None,
+            stop: Some(vec!["stop".to_string()]),
+        };
+
+        let model = ClaudeModel::Claude35Sonnet20241022;
+        assert_eq!(
+            MessagesRequestBody::try_from(request).unwrap(),
+            MessagesRequestBody {
+                model,
+                messages: vec![
+                    Message::user("user-message"),
+                    Message::assistant("assistant-message"),
+                ],
+                system: Some(SystemPrompt::new("system-prompt")),
+                max_tokens: MaxTokens::new(1024, model).unwrap(),
+                stop_sequences: Some(vec![StopSequence::new("stop")]),
+                temperature: Some(Temperature::new(0.5).unwrap()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_request_error() {
+        let request = OpenAiChatRequest {
+            model: "unknown-model".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            MessagesRequestBody::try_from(request),
+            Err(OpenAiConversionError::UnsupportedModel(_))
+        ));
+
+        let request = OpenAiChatRequest {
+            model: "claude-3-haiku-20240307".to_string(),
+            messages: vec![OpenAiChatMessage {
+                role: "tool".to_string(),
+                content: "tool-result".to_string(),
+            }],
+            ..Default::default()
+        };
+        assert!(matches!(
+            MessagesRequestBody::try_from(request),
+            Err(OpenAiConversionError::UnsupportedRole(_))
+        ));
+
+        let request = OpenAiChatRequest {
+            model: "gpt-3.5-turbo".to_string(),
+            temperature: Some(1.5),
+            ..Default::default()
+        };
+        assert!(matches!(
+            MessagesRequestBody::try_from(request),
+            Err(OpenAiConversionError::FloatValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn from_response() {
+        let response = MessagesResponseBody {
+            id: "id".to_string(),
+            content: Content::MultipleBlocks(vec![
+                ContentBlock::from("Hello, "),
+                ContentBlock::from(ToolUse::new(
+                    "id",
+                    "name",
+                    serde_json::Value::Null,
+                )),
+                ContentBlock::from("world!"),
+            ]),
+            model: ClaudeModel::Claude3Haiku20240307,
+            stop_reason: Some(StopReason::MaxTokens),
+            usage: Usage {
+                input_tokens: 10,
+                output_tokens: 20,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            OpenAiChatResponse::from(response),
+            OpenAiChatResponse {
+                id: "id".to_string(),
+                object: "chat.completion".to_string(),
+                model: "claude-3-haiku-20240307".to_string(),
+                choices: vec![OpenAiChatChoice {
+                    index: 0,
+                    message: OpenAiChatMessage {
+                        role: "assistant".to_string(),
+                        content: "Hello, world!".to_string(),
+                    },
+                    finish_reason: Some("length".to_string()),
+                }],
+                usage: OpenAiUsage {
+                    prompt_tokens: 10,
+                    completion_tokens: 20,
+                    total_tokens: 30,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn from_response_saturating_total_tokens() {
+        let response = MessagesResponseBody {
+            usage: Usage {
+                input_tokens: u32::MAX,
+                output_tokens: 1,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert_eq!(
+            OpenAiChatResponse::from(response)
+                .usage
+                .total_tokens,
+            u32::MAX
+        );
+    }
+}
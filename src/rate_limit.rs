@@ -0,0 +1,123 @@
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use crate::RateLimitError;
+
+/// The client-side rate limiter based on the token bucket algorithm.
+///
+/// The bucket holds at most `requests_per_minute` tokens and is refilled continuously at `requests_per_minute / 60` tokens per second.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    /// The number of requests allowed per minute.
+    requests_per_minute: u32,
+    /// The token bucket.
+    bucket: Mutex<TokenBucket>,
+}
+
+/// The state of the token bucket.
+#[derive(Debug)]
+struct TokenBucket {
+    /// The number of available tokens.
+    tokens: f64,
+    /// The last time when the bucket was refilled.
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter with a full bucket.
+    ///
+    /// ## Arguments
+    /// - `requests_per_minute` - The number of requests allowed per minute.
+    pub(crate) fn new(requests_per_minute: u32) -> Self {
+        Self {
+            requests_per_minute,
+            bucket: Mutex::new(TokenBucket {
+                tokens: requests_per_minute as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a token is available and consumes it.
+    ///
+    /// ## Errors
+    /// It returns `RateLimitError::ClientSideThrottled` if the limiter never refills, i.e. `requests_per_minute` is `0`.
+    pub(crate) async fn acquire(&self) -> Result<(), RateLimitError> {
+        if self.requests_per_minute == 0 {
+            return Err(RateLimitError::ClientSideThrottled);
+        }
+
+        let capacity = self.requests_per_minute as f64;
+        let tokens_per_second = capacity / 60.0;
+
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().await;
+
+                // Refill the bucket by the elapsed time.
+                let now = Instant::now();
+                let elapsed = now
+                    .duration_since(bucket.last_refill)
+                    .as_secs_f64();
+                bucket.tokens =
+                    (bucket.tokens + elapsed * tokens_per_second).min(capacity);
+                bucket.last_refill = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return Ok(());
+                }
+
+                Duration::from_secs_f64(
+                    (1.0 - bucket.tokens) / tokens_per_second,
+                )
+            };
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_within_capacity() {
+        let limiter = RateLimiter::new(3);
+        let start = Instant::now();
+
+        for _ in 0..3 {
+            limiter.acquire().await.unwrap();
+        }
+
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_waits_for_refill() {
+        let limiter = RateLimiter::new(60);
+        let start = Instant::now();
+
+        for _ in 0..60 {
+            limiter.acquire().await.unwrap();
+        }
+        limiter.acquire().await.unwrap();
+
+        // One token is refilled per second for 60 requests per minute.
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn acquire_with_zero_limit() {
+        let limiter = RateLimiter::new(0);
+
+        assert!(matches!(
+            limiter.acquire().await,
+            Err(RateLimitError::ClientSideThrottled)
+        ));
+    }
+}
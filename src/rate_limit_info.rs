@@ -0,0 +1,297 @@
+use std::time::{Duration, SystemTime};
+
+use reqwest::header::HeaderMap;
+
+/// The rate limit information of the API server returned in the `anthropic-ratelimit-*` and `retry-after` response headers.
+///
+/// Each field is `None` if the header is missing or cannot be parsed.
+///
+/// See also [response headers](https://docs.anthropic.com/en/api/rate-limits#response-headers).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct RateLimitInfo {
+    /// The maximum number of requests allowed within the rate limit window.
+    pub requests_limit: Option<u32>,
+    /// The number of requests remaining before being rate limited.
+    pub requests_remaining: Option<u32>,
+    /// The time when the request rate limit will be fully replenished.
+    pub requests_reset: Option<SystemTime>,
+    /// The maximum number of tokens allowed within the rate limit window.
+    pub tokens_limit: Option<u32>,
+    /// The number of tokens remaining before being rate limited.
+    pub tokens_remaining: Option<u32>,
+    /// The time when the token rate limit will be fully replenished.
+    pub tokens_reset: Option<SystemTime>,
+    /// The duration to wait until retrying the request.
+    pub retry_after: Option<Duration>,
+}
+
+impl RateLimitInfo {
+    /// Parses the rate limit information from the response headers.
+    ///
+    /// ## Arguments
+    /// - `headers` - The response headers.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use clust::reqwest::header::{HeaderMap, HeaderValue};
+    /// use clust::RateLimitInfo;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert(
+    ///     "anthropic-ratelimit-requests-remaining",
+    ///     HeaderValue::from_static("49"),
+    /// );
+    ///
+    /// let info = RateLimitInfo::from_headers(&headers);
+    /// assert_eq!(info.requests_remaining, Some(49));
+    /// assert_eq!(info.requests_limit, None);
+    /// ```
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            requests_limit: parse_header(
+                headers,
+                "anthropic-ratelimit-requests-limit",
+                |value| value.parse().ok(),
+            ),
+            requests_remaining: parse_header(
+                headers,
+                "anthropic-ratelimit-requests-remaining",
+                |value| value.parse().ok(),
+            ),
+            requests_reset: parse_header(
+                headers,
+                "anthropic-ratelimit-requests-reset",
+                parse_rfc3339,
+            ),
+            tokens_limit: parse_header(
+                headers,
+                "anthropic-ratelimit-tokens-limit",
+                |value| value.parse().ok(),
+            ),
+            tokens_remaining: parse_header(
+                headers,
+                "anthropic-ratelimit-tokens-remaining",
+                |value| value.parse().ok(),
+            ),
+            tokens_reset: parse_header(
+                headers,
+                "anthropic-ratelimit-tokens-reset",
+                parse_rfc3339,
+            ),
+            retry_after: parse_header(headers, "retry-after", |value| {
+                value
+                    .parse()
+                    .ok()
+                    .map(Duration::from_secs)
+            }),
+        }
+    }
+}
+
+/// Parses the header value by the name.
+fn parse_header<T>(
+    headers: &HeaderMap,
+    name: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Option<T> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse(value.trim()))
+}
+
+/// Parses the RFC 3339 date-time, e.g. `2024-10-16T12:34:56Z`.
+fn parse_rfc3339(value: &str) -> Option<SystemTime> {
+    let bytes = value.as_bytes();
+    if bytes.len() < 20
+        || bytes[4] != b'-'
+        || bytes[7] != b'-'
+        || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':'
+        || bytes[16] != b':'
+    {
+        return None;
+    }
+
+    let year = parse_digits(&value[0..4])?;
+    let month = parse_digits(&value[5..7])?;
+    let day = parse_digits(&value[8..10])?;
+    let hour = parse_digits(&value[11..13])?;
+    let minute = parse_digits(&value[14..16])?;
+    let second = parse_digits(&value[17..19])?;
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 60
+    {
+        return None;
+    }
+
+    // Fractional seconds are truncated to nanoseconds.
+    let mut rest = &value[19..];
+    let mut nanos = 0;
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let length = fraction
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if length == 0 {
+            return None;
+        }
+
+        nanos = fraction
+            .bytes()
+            .take(length.min(9))
+            .chain(std::iter::repeat(b'0'))
+            .take(9)
+            .fold(0, |nanos, digit| nanos * 10 + (digit - b'0') as u32);
+        rest = &fraction[length..];
+    }
+
+    let offset = match rest {
+        | "Z" | "z" => 0,
+        | _ => {
+            let sign = match rest.as_bytes().first()? {
+                | b'+' => 1,
+                | b'-' => -1,
+                | _ => return None,
+            };
+            if rest.len() != 6 || rest.as_bytes()[3] != b':' {
+                return None;
+            }
+
+            sign * (parse_digits(&rest[1..3])? * 3600
+                + parse_digits(&rest[4..6])? * 60)
+        },
+    };
+
+    let seconds = days_from_civil(year, month, day) * 86400
+        + hour * 3600
+        + minute * 60
+        + second
+        - offset;
+
+    Some(
+        SystemTime::UNIX_EPOCH
+            + Duration::new(u64::try_from(seconds).ok()?, nanos),
+    )
+}
+
+/// Parses the ASCII digits.
+fn parse_digits(value: &str) -> Option<i64> {
+    if value
+        .bytes()
+        .all(|byte| byte.is_ascii_digit())
+    {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// The number of days since 1970-01-01 of the date in the proleptic Gregorian calendar.
+fn days_from_civil(
+    year: i64,
+    month: i64,
+    day: i64,
+) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day
+            - 1;
+    let day_of_era =
+        year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn from_headers() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "anthropic-ratelimit-requests-limit",
+            HeaderValue::from_static("50"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-requests-remaining",
+            HeaderValue::from_static("49"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-requests-reset",
+            HeaderValue::from_static("2024-10-16T12:34:56Z"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-tokens-limit",
+            HeaderValue::from_static("40000"),
+        );
+        headers.insert(
+            "anthropic-ratelimit-tokens-remaining",
+            HeaderValue::from_static("invalid"),
+        );
+        headers.insert(
+            "retry-after",
+            HeaderValue::from_static("30"),
+        );
+
+        assert_eq!(
+            RateLimitInfo::from_headers(&headers),
+            RateLimitInfo {
+                requests_limit: Some(50),
+                requests_remaining: Some(49),
+                requests_reset: Some(
+                    SystemTime::UNIX_EPOCH + Duration::from_secs(1729082096)
+                ),
+                tokens_limit: Some(40000),
+                tokens_remaining: None,
+                tokens_reset: None,
+                retry_after: Some(Duration::from_secs(30)),
+            }
+        );
+
+        assert_eq!(
+            RateLimitInfo::from_headers(&HeaderMap::new()),
+            RateLimitInfo::default()
+        );
+    }
+
+    #[test]
+    fn rfc3339() {
+        assert_eq!(
+            parse_rfc3339("1970-01-01T00:00:00Z"),
+            Some(SystemTime::UNIX_EPOCH)
+        );
+        assert_eq!(
+            parse_rfc3339("2000-02-29T00:00:00Z"),
+            Some(SystemTime::UNIX_EPOCH + Duration::from_secs(951782400))
+        );
+        assert_eq!(
+            parse_rfc3339("2024-10-16T21:34:56.5+09:00"),
+            Some(
+                SystemTime::UNIX_EPOCH
+                    + Duration::from_millis(1729082096500)
+            )
+        );
+
+        assert_eq!(parse_rfc3339("2024-10-16"), None);
+        assert_eq!(
+            parse_rfc3339("2024-13-16T12:34:56Z"),
+            None
+        );
+        assert_eq!(
+            parse_rfc3339("2024-10-16T12:34:56"),
+            None
+        );
+        assert_eq!(
+            parse_rfc3339("2024-10-16T12:34:56.Z"),
+            None
+        );
+    }
+}
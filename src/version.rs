@@ -1,9 +1,21 @@
+use crate::{VersionFromEnvError, VersionParseError};
+use std::cmp::Ordering;
 use std::fmt::Display;
+use std::str::FromStr;
 
 /// The API version.
 ///
 /// See also [the API reference](https://docs.anthropic.com/claude/reference/versions).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Versions are ordered by their release dates.
+///
+/// ## Example
+/// ```rust
+/// use clust::Version;
+///
+/// assert!(Version::V2023_06_01 > Version::V2023_01_01);
+/// assert_eq!(Version::latest(), Version::V2023_06_01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Version {
     /// 2023-01-01
     V2023_01_01,
@@ -33,6 +45,71 @@ impl Display for Version {
     }
 }
 
+impl PartialOrd for Version {
+    fn partial_cmp(
+        &self,
+        other: &Self,
+    ) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(
+        &self,
+        other: &Self,
+    ) -> Ordering {
+        self.ordinal()
+            .cmp(&other.ordinal())
+    }
+}
+
+impl FromStr for Version {
+    type Err = VersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            | "2023-01-01" => Ok(Version::V2023_01_01),
+            | "2023-06-01" => Ok(Version::V2023_06_01),
+            | _ => Err(VersionParseError {
+                value: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl Version {
+    /// Loads the API version from the environment variable: `ANTHROPIC_VERSION`.
+    ///
+    /// ## Errors
+    /// - `VersionFromEnvError::Missing` if the environment variable is not set.
+    /// - `VersionFromEnvError::Invalid` if the value is not a supported API version.
+    pub fn from_env() -> Result<Self, VersionFromEnvError> {
+        let value = std::env::var("ANTHROPIC_VERSION")
+            .map_err(|_| VersionFromEnvError::Missing)?;
+
+        Self::from_str(&value).map_err(|source| {
+            VersionFromEnvError::Invalid {
+                value,
+                source,
+            }
+        })
+    }
+
+    /// The numeric representation of the release date as `YYYYMMDD`.
+    pub fn ordinal(&self) -> u32 {
+        match self {
+            | Version::V2023_01_01 => 20230101,
+            | Version::V2023_06_01 => 20230601,
+        }
+    }
+
+    /// The most recent known version.
+    pub fn latest() -> Version {
+        Version::V2023_06_01
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +130,39 @@ mod tests {
             "2023-06-01",
         );
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn ordinal() {
+        assert_eq!(Version::V2023_01_01.ordinal(), 20230101);
+        assert_eq!(Version::V2023_06_01.ordinal(), 20230601);
+    }
+
+    #[test]
+    fn ordering() {
+        assert!(Version::V2023_01_01 < Version::V2023_06_01);
+        assert!(Version::V2023_06_01 >= Version::V2023_06_01);
+    }
+
+    #[test]
+    fn latest() {
+        assert_eq!(Version::latest(), Version::V2023_06_01);
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            Version::from_str("2023-01-01"),
+            Ok(Version::V2023_01_01)
+        );
+        assert_eq!(
+            Version::from_str("2023-06-01"),
+            Ok(Version::V2023_06_01)
+        );
+        assert_eq!(
+            Version::from_str("2024-01-01"),
+            Err(VersionParseError {
+                value: "2024-01-01".to_string(),
+            })
+        );
+    }
+}